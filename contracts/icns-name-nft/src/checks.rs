@@ -1,6 +1,12 @@
-use cosmwasm_std::{Addr, Deps};
+use cosmwasm_std::{Addr, Deps, StdResult, Storage};
+use unicode_normalization::UnicodeNormalization;
 
-use crate::{error::ContractError, state::CONFIG};
+use crate::{
+    error::ContractError,
+    msg::{NameViolation, Profile},
+    state::{NameCharset, NamePolicy, CONFIG, SKELETONS},
+    ICNSNameNFTContract,
+};
 
 // check_admin checks if the sender is an admin, if not returns error
 pub fn check_admin(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
@@ -28,6 +34,35 @@ pub fn is_admin(deps: Deps, sender: &Addr) -> Result<bool, ContractError> {
     Ok(false)
 }
 
+// is_guardian checks if the given sender is a guardian, if not returns false
+pub fn is_guardian(deps: Deps, sender: &Addr) -> Result<bool, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    Ok(config.guardians.contains(sender))
+}
+
+// check_not_paused checks if the contract is paused, if so returns error
+pub fn check_not_paused(deps: Deps) -> Result<(), ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if config.paused {
+        return Err(ContractError::Paused {});
+    }
+
+    Ok(())
+}
+
+// check_minter checks if the sender is the minter, if not returns error
+pub fn check_minter(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
+    let minter = ICNSNameNFTContract::default().minter.load(deps.storage)?;
+
+    if minter != *sender {
+        return Err(cw721_base::ContractError::Unauthorized {}.into());
+    }
+
+    Ok(())
+}
+
 // is_transferrable checks if the contract is transferrable, if not returns false
 pub fn is_transferrable(deps: Deps) -> Result<bool, ContractError> {
     let config = CONFIG.load(deps.storage)?;
@@ -39,12 +74,123 @@ pub fn is_transferrable(deps: Deps) -> Result<bool, ContractError> {
     Ok(true)
 }
 
-// validate_name returns error if the name contains a dot.
-// This is to prevent the name containing a dot, which is used to separate the name and the bech32 prefix.
-pub fn validate_name(name: &str) -> Result<(), ContractError> {
+// validate_name returns error if the name does not satisfy the name policy,
+// or if the name is confusable with a name already minted.
+// A name containing a dot is always invalid, since a dot separates the name and the bech32 prefix.
+pub fn validate_name(deps: Deps, name: &str) -> Result<(), ContractError> {
+    match name_violation(deps, name)? {
+        None => Ok(()),
+        Some(NameViolation::ContainsDot) => Err(ContractError::InvalidName {}),
+        Some(violation) => Err(ContractError::NameNotAllowed { violation }),
+    }
+}
+
+// name_violation returns the reason why the name can not be minted, if any.
+pub fn name_violation(deps: Deps, name: &str) -> StdResult<Option<NameViolation>> {
+    let config = CONFIG.load(deps.storage)?;
+
+    if let Some(violation) = policy_violation(&config.name_policy, name) {
+        return Ok(Some(violation));
+    }
+
+    Ok(confusable_name(deps.storage, name)?.map(|name| NameViolation::Confusable { name }))
+}
+
+// policy_violation returns the first violation of the name policy found in the name, if any.
+// The name must be normalized regardless of the policy.
+pub fn policy_violation(policy: &NamePolicy, name: &str) -> Option<NameViolation> {
     if name.contains('.') {
-        return Err(ContractError::InvalidName {});
+        return Some(NameViolation::ContainsDot);
+    }
+
+    let normalized = normalize_name(name);
+    if normalized != name {
+        return Some(NameViolation::NotNormalized { normalized });
+    }
+
+    let length = name.chars().count();
+    if length < policy.min_length as usize {
+        return Some(NameViolation::TooShort {
+            min_length: policy.min_length,
+        });
+    }
+    if length > policy.max_length as usize {
+        return Some(NameViolation::TooLong {
+            max_length: policy.max_length,
+        });
+    }
+
+    let is_allowed = |c: char| match &policy.charset {
+        NameCharset::Any => !c.is_whitespace() && !c.is_control(),
+        NameCharset::LowercaseAlphanumeric => {
+            c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'
+        }
+        NameCharset::Custom { chars } => chars.contains(c),
+    };
+    if let Some(c) = name.chars().find(|c| !is_allowed(*c)) {
+        return Some(NameViolation::InvalidCharacter {
+            character: c.to_string(),
+        });
+    }
+
+    if !policy.allow_leading_hyphen && name.starts_with('-') {
+        return Some(NameViolation::LeadingHyphen);
+    }
+    if !policy.allow_trailing_hyphen && name.ends_with('-') {
+        return Some(NameViolation::TrailingHyphen);
+    }
+
+    None
+}
+
+// maximum length of the avatar uri of the profile
+const MAX_AVATAR_URI_LENGTH: usize = 256;
+// maximum length of the description of the profile
+const MAX_DESCRIPTION_LENGTH: usize = 512;
+
+// validate_profile returns error if the profile can not be set for the name.
+// The display name must be normalized to the name, so that only its casing can differ.
+pub fn validate_profile(name: &str, profile: &Profile) -> Result<(), ContractError> {
+    if let Some(display_name) = &profile.display_name {
+        if normalize_name(display_name) != name {
+            return Err(ContractError::InvalidProfile {
+                msg: format!("display name `{display_name}` does not match name `{name}`"),
+            });
+        }
+    }
+    if let Some(avatar_uri) = &profile.avatar_uri {
+        if avatar_uri.len() > MAX_AVATAR_URI_LENGTH {
+            return Err(ContractError::InvalidProfile {
+                msg: format!("avatar uri must be at most {MAX_AVATAR_URI_LENGTH} bytes long"),
+            });
+        }
+    }
+    if let Some(description) = &profile.description {
+        if description.len() > MAX_DESCRIPTION_LENGTH {
+            return Err(ContractError::InvalidProfile {
+                msg: format!("description must be at most {MAX_DESCRIPTION_LENGTH} bytes long"),
+            });
+        }
     }
 
     Ok(())
 }
+
+// normalize_name returns the lowercased NFC form of the name.
+pub fn normalize_name(name: &str) -> String {
+    name.to_lowercase().nfc().collect()
+}
+
+// name_skeleton returns the skeleton of the name as defined in UTS #39.
+// Names having the same skeleton are visually confusable.
+pub fn name_skeleton(name: &str) -> String {
+    unicode_security::skeleton(&normalize_name(name)).collect()
+}
+
+// confusable_name returns the minted name having the same skeleton as the given name, if any.
+// The name itself is not considered confusable, so that it can be minted again once expired.
+pub fn confusable_name(storage: &dyn Storage, name: &str) -> StdResult<Option<String>> {
+    Ok(SKELETONS
+        .may_load(storage, &name_skeleton(name))?
+        .filter(|existing| existing != name))
+}
@@ -1,6 +1,8 @@
 use cosmwasm_std::StdError;
 use thiserror::Error;
 
+use crate::msg::NameViolation;
+
 #[derive(Error, Debug, PartialEq)]
 pub enum ContractError {
     #[error("{0}")]
@@ -12,6 +14,24 @@ pub enum ContractError {
     #[error("Invalid name")]
     InvalidName {},
 
+    #[error("Name is not allowed: {violation}")]
+    NameNotAllowed { violation: NameViolation },
+
+    #[error("Invalid name policy: {msg}")]
+    InvalidNamePolicy { msg: String },
+
+    #[error("Invalid profile: {msg}")]
+    InvalidProfile { msg: String },
+
     #[error("Transfer of Nft is not allowed")]
     TransferNotAllowed {},
+
+    #[error("Contract is paused")]
+    Paused {},
+
+    #[error("Hook {hook} is already registered")]
+    HookAlreadyRegistered { hook: String },
+
+    #[error("Hook {hook} is not registered")]
+    HookNotRegistered { hook: String },
 }
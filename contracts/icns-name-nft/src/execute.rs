@@ -1,9 +1,12 @@
-use cosmwasm_std::{DepsMut, Response, StdResult};
+use cosmwasm_std::{
+    to_binary, Addr, Deps, DepsMut, Env, Order, Response, StdResult, SubMsg, Timestamp, WasmMsg,
+};
 
 use crate::{
-    checks::check_admin,
+    checks::{check_admin, is_guardian, name_skeleton, validate_profile},
     error::ContractError,
-    state::{Config, CONFIG},
+    msg::{HookExecuteMsg, NameOwnerChangedHook, Profile},
+    state::{Config, NameCharset, NamePolicy, CONFIG, SKELETONS},
     ICNSNameNFTContract,
 };
 
@@ -47,6 +50,176 @@ pub fn remove_admin(admin: &str, deps: DepsMut) -> Result<Response, ContractErro
         .add_attribute("admin", admin))
 }
 
+// add_guardian adds a new guardian to the contract.
+// Only admins can add new guardians.
+pub fn add_guardian(guardian: &str, deps: DepsMut) -> Result<Response, ContractError> {
+    let guardian_addr = deps.api.addr_validate(guardian)?;
+    // check that guardian does not already exist
+    if is_guardian(deps.as_ref(), &guardian_addr)? {
+        return Err(cw721_base::ContractError::Unauthorized {}.into());
+    }
+
+    CONFIG.update(deps.storage, |config| -> StdResult<_> {
+        let mut guardians = config.guardians;
+        guardians.push(guardian_addr);
+        Ok(Config {
+            guardians,
+            ..config
+        })
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "add_guardian")
+        .add_attribute("guardian", guardian))
+}
+
+// remove_guardian removes a guardian from the contract.
+// Only admins can remove guardians.
+pub fn remove_guardian(guardian: &str, deps: DepsMut) -> Result<Response, ContractError> {
+    let guardian_addr = deps.api.addr_validate(guardian)?;
+    // check that guardian exists
+    if !is_guardian(deps.as_ref(), &guardian_addr)? {
+        return Err(cw721_base::ContractError::Unauthorized {}.into());
+    }
+
+    CONFIG.update(deps.storage, |config| -> StdResult<_> {
+        let mut guardians = config.guardians;
+        guardians.retain(|x| x != &guardian_addr);
+        Ok(Config {
+            guardians,
+            ..config
+        })
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "remove_guardian")
+        .add_attribute("guardian", guardian))
+}
+
+// add_hook adds a new contract to be notified when a name changes owner.
+// Only admins can add new hooks.
+pub fn add_hook(hook: &str, deps: DepsMut) -> Result<Response, ContractError> {
+    let hook_addr = deps.api.addr_validate(hook)?;
+    let config = CONFIG.load(deps.storage)?;
+    // check that hook does not already exist
+    if config.hooks.contains(&hook_addr) {
+        return Err(ContractError::HookAlreadyRegistered {
+            hook: hook.to_string(),
+        });
+    }
+
+    let mut hooks = config.hooks;
+    hooks.push(hook_addr);
+    CONFIG.save(deps.storage, &Config { hooks, ..config })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "add_hook")
+        .add_attribute("hook", hook))
+}
+
+// remove_hook removes a contract from the contracts notified when a name changes owner.
+// Only admins can remove hooks.
+pub fn remove_hook(hook: &str, deps: DepsMut) -> Result<Response, ContractError> {
+    let hook_addr = deps.api.addr_validate(hook)?;
+    let config = CONFIG.load(deps.storage)?;
+    // check that hook exists
+    if !config.hooks.contains(&hook_addr) {
+        return Err(ContractError::HookNotRegistered {
+            hook: hook.to_string(),
+        });
+    }
+
+    let mut hooks = config.hooks;
+    hooks.retain(|x| x != &hook_addr);
+    CONFIG.save(deps.storage, &Config { hooks, ..config })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "remove_hook")
+        .add_attribute("hook", hook))
+}
+
+// name_owner_changed_hooks returns the submessages notifying every hook that the name changes owner.
+// A failing hook reverts the whole transaction, so a broken hook must be removed by admins.
+pub fn name_owner_changed_hooks(
+    deps: Deps,
+    name: &str,
+    old_owner: Option<Addr>,
+    new_owner: &str,
+) -> StdResult<Vec<SubMsg>> {
+    let msg = to_binary(&HookExecuteMsg::NameOwnerChangedHook(
+        NameOwnerChangedHook {
+            name: name.to_string(),
+            old_owner: old_owner.map(String::from),
+            new_owner: new_owner.to_string(),
+        },
+    ))?;
+
+    Ok(CONFIG
+        .load(deps.storage)?
+        .hooks
+        .into_iter()
+        .map(|hook| {
+            SubMsg::new(WasmMsg::Execute {
+                contract_addr: hook.to_string(),
+                msg: msg.clone(),
+                funds: vec![],
+            })
+        })
+        .collect())
+}
+
+// name_owner returns the current owner of the name, if minted.
+pub fn name_owner(deps: Deps, token_id: &str) -> StdResult<Option<Addr>> {
+    let name_nft = ICNSNameNFTContract::default();
+
+    Ok(name_nft
+        .tokens
+        .may_load(deps.storage, token_id)?
+        .map(|token| token.owner))
+}
+
+// set_paused pauses or unpauses the contract.
+// While paused, minting and transfers are not allowed.
+pub fn set_paused(paused: bool, deps: DepsMut) -> Result<Response, ContractError> {
+    CONFIG.update(deps.storage, |config| -> StdResult<_> {
+        Ok(Config { paused, ..config })
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_paused")
+        .add_attribute("paused", paused.to_string()))
+}
+
+// set_name_policy sets the policy that names must satisfy to be minted.
+pub fn set_name_policy(policy: NamePolicy, deps: DepsMut) -> Result<Response, ContractError> {
+    if policy.min_length == 0 {
+        return Err(ContractError::InvalidNamePolicy {
+            msg: "min_length must be at least 1".to_string(),
+        });
+    }
+    if policy.min_length > policy.max_length {
+        return Err(ContractError::InvalidNamePolicy {
+            msg: "min_length must not be greater than max_length".to_string(),
+        });
+    }
+    if let NameCharset::Custom { chars } = &policy.charset {
+        if chars.is_empty() || chars.contains('.') {
+            return Err(ContractError::InvalidNamePolicy {
+                msg: "custom charset must not be empty nor contain a dot".to_string(),
+            });
+        }
+    }
+
+    CONFIG.update(deps.storage, |config| -> StdResult<_> {
+        Ok(Config {
+            name_policy: policy,
+            ..config
+        })
+    })?;
+
+    Ok(Response::new().add_attribute("method", "set_name_policy"))
+}
+
 // set_transferrable chgnes the transferrable configuration.
 // Upon being true, the contract will allow icns nft transfers.
 pub fn set_transferrable(transferrable: bool, deps: DepsMut) -> Result<Response, ContractError> {
@@ -73,3 +246,100 @@ pub fn set_minter_address(minter_address: &str, deps: DepsMut) -> Result<Respons
         .add_attribute("method", "set_minter_address")
         .add_attribute("minter_address", minter_address))
 }
+
+// update_metadata replaces the profile of the given name.
+// Only the owner of the name can update its profile.
+pub fn update_metadata(
+    token_id: &str,
+    profile: Profile,
+    deps: DepsMut,
+    sender: &Addr,
+) -> Result<Response, ContractError> {
+    let name_nft = ICNSNameNFTContract::default();
+
+    let mut token = name_nft.tokens.load(deps.storage, token_id)?;
+    if token.owner != *sender {
+        return Err(cw721_base::ContractError::Unauthorized {}.into());
+    }
+
+    validate_profile(token_id, &profile)?;
+
+    token.extension.profile = profile;
+    name_nft.tokens.save(deps.storage, token_id, &token)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "update_metadata")
+        .add_attribute("token_id", token_id))
+}
+
+// set_expiry sets the expiry of the given name.
+// Only minter can set expiry, the registrar sets it upon name renewal.
+pub fn set_expiry(
+    token_id: &str,
+    expiry: Option<Timestamp>,
+    deps: DepsMut,
+) -> Result<Response, ContractError> {
+    let name_nft = ICNSNameNFTContract::default();
+
+    name_nft
+        .tokens
+        .update(deps.storage, token_id, |token| -> StdResult<_> {
+            let mut token = token
+                .ok_or_else(|| cosmwasm_std::StdError::not_found("cw721_base::state::TokenInfo"))?;
+            token.extension.expiry = expiry;
+            Ok(token)
+        })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_expiry")
+        .add_attribute("token_id", token_id)
+        .add_attribute(
+            "expiry",
+            expiry
+                .map(|expiry| expiry.to_string())
+                .unwrap_or_else(|| "never".to_string()),
+        ))
+}
+
+// release_expired_name removes the name if it has already expired,
+// so that it can be minted again.
+pub fn release_expired_name(token_id: &str, deps: DepsMut, env: &Env) -> StdResult<()> {
+    let name_nft = ICNSNameNFTContract::default();
+
+    let is_expired = name_nft
+        .tokens
+        .may_load(deps.storage, token_id)?
+        .and_then(|token| token.extension.expiry)
+        .map(|expiry| expiry <= env.block.time)
+        .unwrap_or(false);
+
+    if is_expired {
+        name_nft.tokens.remove(deps.storage, token_id)?;
+        name_nft.decrement_tokens(deps.storage)?;
+    }
+
+    Ok(())
+}
+
+// index_skeleton records the skeleton of the name being minted.
+pub fn index_skeleton(name: &str, deps: DepsMut) -> StdResult<()> {
+    SKELETONS.save(deps.storage, &name_skeleton(name), &name.to_string())
+}
+
+// index_minted_skeletons records the skeletons of all minted names.
+// Among the names already minted with the same skeleton, the first one is recorded.
+pub fn index_minted_skeletons(deps: DepsMut) -> StdResult<()> {
+    let names = ICNSNameNFTContract::default()
+        .tokens
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for name in names {
+        let skeleton = name_skeleton(&name);
+        if !SKELETONS.has(deps.storage, &skeleton) {
+            SKELETONS.save(deps.storage, &skeleton, &name)?;
+        }
+    }
+
+    Ok(())
+}
@@ -22,12 +22,23 @@ pub type ICNSNameNFTContract<'a> = Cw721Contract<'a, Metadata, Empty, ICNSNameEx
 
 pub mod entry {
     use super::*;
-    use crate::checks::{check_admin, is_admin as check_is_admin, is_transferrable, validate_name};
+    use crate::checks::{
+        check_admin, check_minter, check_not_paused, is_admin as check_is_admin,
+        is_guardian as check_is_guardian, is_transferrable, validate_name, validate_profile,
+    };
     use crate::error::ContractError;
-    use crate::execute::{add_admin, remove_admin, set_minter_address, set_transferrable};
+    use crate::execute::{
+        add_admin, add_guardian, add_hook, index_minted_skeletons, index_skeleton, name_owner,
+        name_owner_changed_hooks, release_expired_name, remove_admin, remove_guardian, remove_hook,
+        set_expiry, set_minter_address, set_name_policy, set_paused, set_transferrable,
+        update_metadata,
+    };
     use crate::msg::{ExecuteMsg, MigrateMsg};
-    use crate::query::{admin, is_admin, transferrable};
-    use crate::state::{Config, CONFIG};
+    use crate::query::{
+        admin, guardians, hooks, is_admin, is_guardian, name_policy, normalized_name, paused,
+        transferrable, validate_name as query_validate_name,
+    };
+    use crate::state::{Config, NamePolicy, CONFIG};
 
     use cosmwasm_std::entry_point;
     use cosmwasm_std::{to_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
@@ -51,6 +62,10 @@ pub mod entry {
         let config = Config {
             admins: admin_addrs,
             transferrable: msg.transferrable,
+            guardians: vec![],
+            paused: false,
+            hooks: vec![],
+            name_policy: NamePolicy::default(),
         };
 
         CONFIG.save(deps.storage, &config)?;
@@ -73,7 +88,7 @@ pub mod entry {
 
     #[entry_point]
     pub fn execute(
-        deps: DepsMut,
+        mut deps: DepsMut,
         env: Env,
         info: MessageInfo,
         msg: ExecuteMsg,
@@ -85,12 +100,35 @@ pub mod entry {
             // or when the msg sender is an admin.
             msg @ CW721BaseExecuteMsg::TransferNft { .. }
             | msg @ CW721BaseExecuteMsg::SendNft { .. } => {
+                check_not_paused(deps.as_ref())?;
+
                 let is_admin = check_is_admin(deps.as_ref(), &info.sender)?;
 
                 let is_transferable = is_transferrable(deps.as_ref())?;
 
                 if is_admin || is_transferable {
-                    name_nft.execute(deps, env, info, msg).map_err(Into::into)
+                    // notify hooks that the name changes owner
+                    let (token_id, new_owner) = match &msg {
+                        CW721BaseExecuteMsg::TransferNft {
+                            recipient,
+                            token_id,
+                        } => (token_id, recipient),
+                        CW721BaseExecuteMsg::SendNft {
+                            contract, token_id, ..
+                        } => (token_id, contract),
+                        _ => unreachable!("only transfers are matched"),
+                    };
+                    let hooks = name_owner_changed_hooks(
+                        deps.as_ref(),
+                        token_id,
+                        name_owner(deps.as_ref(), token_id)?,
+                        new_owner,
+                    )?;
+
+                    name_nft
+                        .execute(deps, env, info, msg)
+                        .map(|res| res.add_submessages(hooks))
+                        .map_err(Into::into)
                 } else {
                     Err(ContractError::TransferNotAllowed {})
                 }
@@ -105,13 +143,29 @@ pub mod entry {
             }
 
             // minting is allowed as is
-            msg @ CW721BaseExecuteMsg::Mint(_) => {
+            CW721BaseExecuteMsg::Mint(mut m) => {
+                check_not_paused(deps.as_ref())?;
+
                 // validate name
-                if let CW721BaseExecuteMsg::Mint(m) = &msg {
-                    validate_name(&m.token_id)?;
-                };
+                validate_name(deps.as_ref(), &m.token_id)?;
+                validate_profile(&m.token_id, &m.extension.profile)?;
+                index_skeleton(&m.token_id, deps.branch())?;
 
-                name_nft.execute(deps, env, info, msg).map_err(Into::into)
+                // expired name can be minted again, and its previous owner is the old owner
+                let old_owner = name_owner(deps.as_ref(), &m.token_id)?;
+                release_expired_name(&m.token_id, deps.branch(), &env)?;
+
+                // notify hooks that the name changes owner
+                let hooks =
+                    name_owner_changed_hooks(deps.as_ref(), &m.token_id, old_owner, &m.owner)?;
+
+                // mint time is always recorded by the contract
+                m.extension.minted_at = Some(env.block.time);
+
+                name_nft
+                    .execute(deps, env, info, CW721BaseExecuteMsg::Mint(m))
+                    .map(|res| res.add_submessages(hooks))
+                    .map_err(Into::into)
             }
 
             // buring is disabled
@@ -137,6 +191,40 @@ pub mod entry {
                     check_admin(deps.as_ref(), &info.sender)?;
                     set_minter_address(&minter_address, deps)
                 }
+                msg::ICNSNameExecuteMsg::SetExpiry { token_id, expiry } => {
+                    check_minter(deps.as_ref(), &info.sender)?;
+                    set_expiry(&token_id, expiry, deps)
+                }
+                msg::ICNSNameExecuteMsg::AddGuardian { guardian_address } => {
+                    check_admin(deps.as_ref(), &info.sender)?;
+                    add_guardian(&guardian_address, deps)
+                }
+                msg::ICNSNameExecuteMsg::RemoveGuardian { guardian_address } => {
+                    check_admin(deps.as_ref(), &info.sender)?;
+                    remove_guardian(&guardian_address, deps)
+                }
+                msg::ICNSNameExecuteMsg::AddHook { hook_address } => {
+                    check_admin(deps.as_ref(), &info.sender)?;
+                    add_hook(&hook_address, deps)
+                }
+                msg::ICNSNameExecuteMsg::RemoveHook { hook_address } => {
+                    check_admin(deps.as_ref(), &info.sender)?;
+                    remove_hook(&hook_address, deps)
+                }
+                msg::ICNSNameExecuteMsg::SetNamePolicy { policy } => {
+                    check_admin(deps.as_ref(), &info.sender)?;
+                    set_name_policy(policy, deps)
+                }
+                msg::ICNSNameExecuteMsg::UpdateMetadata { token_id, profile } => {
+                    update_metadata(&token_id, profile, deps, &info.sender)
+                }
+                msg::ICNSNameExecuteMsg::SetPaused { paused } => {
+                    // guardians can only pause the contract
+                    if !(paused && check_is_guardian(deps.as_ref(), &info.sender)?) {
+                        check_admin(deps.as_ref(), &info.sender)?;
+                    }
+                    set_paused(paused, deps)
+                }
             },
         }
     }
@@ -149,14 +237,23 @@ pub mod entry {
             QueryMsg::Admin {} => to_binary(&admin(deps)?),
             QueryMsg::Transferrable {} => to_binary(&transferrable(deps)?),
             QueryMsg::IsAdmin { address } => to_binary(&is_admin(deps, address)?),
+            QueryMsg::Guardians {} => to_binary(&guardians(deps)?),
+            QueryMsg::IsGuardian { address } => to_binary(&is_guardian(deps, address)?),
+            QueryMsg::Paused {} => to_binary(&paused(deps)?),
+            QueryMsg::Hooks {} => to_binary(&hooks(deps)?),
+            QueryMsg::NamePolicy {} => to_binary(&name_policy(deps)?),
+            QueryMsg::ValidateName { name } => to_binary(&query_validate_name(deps, name)?),
+            QueryMsg::NormalizedName { name } => to_binary(&normalized_name(deps, name)?),
             // TODO : add query for config
             _ => name_nft.query(deps, env, msg.into()),
         }
     }
 
     #[entry_point]
-    pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-        // No state migrations performed, just returned a Response
+    pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+        // index skeletons of the names minted before confusable names were blocked
+        index_minted_skeletons(deps)?;
+
         Ok(Response::default())
     }
 }
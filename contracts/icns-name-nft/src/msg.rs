@@ -1,11 +1,14 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{CustomMsg, Empty};
+use cosmwasm_std::{CustomMsg, Empty, Timestamp};
 use cw721::{
     ApprovalResponse, ApprovalsResponse, ContractInfoResponse, NumTokensResponse,
     OperatorsResponse, OwnerOfResponse, TokensResponse,
 };
 use cw721_base::msg::QueryMsg as Cw721QueryMsg;
 use cw721_base::MinterResponse;
+use thiserror::Error;
+
+use crate::state::NamePolicy;
 
 #[cw_serde]
 pub struct InstantiateMsg {
@@ -20,10 +23,41 @@ pub struct InstantiateMsg {
 
 pub type ExecuteMsg = cw721_base::ExecuteMsg<Metadata, ICNSNameExecuteMsg>;
 
-/// Metadata is optional, and contains a String of the referral name.
+/// Metadata contains the referral name, the expiry and the claim details of the name,
+/// along with the profile editable by the owner.
 #[cw_serde]
+#[derive(Default)]
 pub struct Metadata {
     pub referral: Option<String>,
+    /// Time at which the name registration expires.
+    /// `None` means the name never expires.
+    pub expiry: Option<Timestamp>,
+    /// Time at which the name was minted. This is set by the contract upon minting.
+    pub minted_at: Option<Timestamp>,
+    /// Address that originally claimed the name, which may differ from the owner it was claimed for.
+    pub original_claimer: Option<String>,
+    /// Identity verified upon claiming the name, if any.
+    pub identity: Option<IdentityRef>,
+    /// Profile of the name, editable by the owner via `UpdateMetadata`.
+    #[serde(default)]
+    pub profile: Profile,
+}
+
+/// IdentityRef refers to the identity of the identity provider used to claim the name.
+#[cw_serde]
+pub struct IdentityRef {
+    pub provider: String,
+    pub subject_id: String,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct Profile {
+    /// Name with its casing preserved, e.g. `Alice` for `alice`.
+    /// It must be normalized to the name itself.
+    pub display_name: Option<String>,
+    pub avatar_uri: Option<String>,
+    pub description: Option<String>,
 }
 
 #[cw_serde]
@@ -37,6 +71,44 @@ pub enum ICNSNameExecuteMsg {
     /// SetMinter sets the minter address to the given address.
     /// Minter would be set to the registrar address by default.
     SetMinter { minter_address: String },
+    /// SetExpiry sets the expiry of the given name.
+    /// Only the minter can set expiry, this is used by the registrar upon renewal.
+    SetExpiry {
+        token_id: String,
+        expiry: Option<Timestamp>,
+    },
+    /// AddGuardian adds guardian to the list of guardians.
+    AddGuardian { guardian_address: String },
+    /// RemoveGuardian removes guardian from the list of guardians.
+    RemoveGuardian { guardian_address: String },
+    /// SetPaused pauses or unpauses minting and transfers.
+    /// Admins can pause and unpause, while guardians can only pause.
+    SetPaused { paused: bool },
+    /// AddHook adds contract to the list of contracts notified when a name changes owner.
+    AddHook { hook_address: String },
+    /// RemoveHook removes contract from the list of contracts notified when a name changes owner.
+    RemoveHook { hook_address: String },
+    /// SetNamePolicy sets the policy that names must satisfy to be minted.
+    /// Names already minted are not affected.
+    SetNamePolicy { policy: NamePolicy },
+    /// UpdateMetadata replaces the profile of the name.
+    /// Only the owner of the name can update its profile.
+    UpdateMetadata { token_id: String, profile: Profile },
+}
+
+/// NameOwnerChangedHook is sent to the hook contracts after a name is minted, transferred or sent.
+#[cw_serde]
+pub struct NameOwnerChangedHook {
+    pub name: String,
+    /// previous owner of the name, `None` if the name is newly minted
+    pub old_owner: Option<String>,
+    pub new_owner: String,
+}
+
+/// HookExecuteMsg is the execute msg that hook contracts are expected to handle.
+#[cw_serde]
+pub enum HookExecuteMsg {
+    NameOwnerChangedHook(NameOwnerChangedHook),
 }
 
 impl CustomMsg for ICNSNameExecuteMsg {}
@@ -53,6 +125,31 @@ pub enum QueryMsg {
     #[returns(TransferrableResponse)]
     Transferrable {},
 
+    #[returns(GuardiansResponse)]
+    Guardians {},
+
+    #[returns(IsGuardianResponse)]
+    IsGuardian { address: String },
+
+    #[returns(PausedResponse)]
+    Paused {},
+
+    #[returns(HooksResponse)]
+    Hooks {},
+
+    #[returns(NamePolicyResponse)]
+    NamePolicy {},
+
+    /// ValidateName checks the name against the name policy,
+    /// and returns the violation if the name can not be minted.
+    #[returns(ValidateNameResponse)]
+    ValidateName { name: String },
+
+    /// NormalizedName returns the normalized form and the skeleton of the name,
+    /// along with the minted name confusable with it.
+    #[returns(NormalizedNameResponse)]
+    NormalizedName { name: String },
+
     #[returns(OwnerOfResponse)]
     OwnerOf {
         token_id: String,
@@ -168,6 +265,76 @@ pub struct TransferrableResponse {
     pub transferrable: bool,
 }
 
+#[cw_serde]
+pub struct GuardiansResponse {
+    pub guardians: Vec<String>,
+}
+
+#[cw_serde]
+pub struct IsGuardianResponse {
+    pub is_guardian: bool,
+}
+
+#[cw_serde]
+pub struct PausedResponse {
+    pub paused: bool,
+}
+
+#[cw_serde]
+pub struct HooksResponse {
+    pub hooks: Vec<String>,
+}
+
+#[cw_serde]
+pub struct NamePolicyResponse {
+    pub policy: NamePolicy,
+}
+
+#[cw_serde]
+pub struct ValidateNameResponse {
+    /// `None` if the name satisfies the name policy.
+    pub violation: Option<NameViolation>,
+}
+
+/// NameViolation describes why the name does not satisfy the name policy.
+#[cw_serde]
+#[derive(Error)]
+pub enum NameViolation {
+    #[error("name must not contain a dot")]
+    ContainsDot,
+
+    #[error("name must be at least {min_length} characters long")]
+    TooShort { min_length: u32 },
+
+    #[error("name must be at most {max_length} characters long")]
+    TooLong { max_length: u32 },
+
+    #[error("name must not contain `{character}`")]
+    InvalidCharacter { character: String },
+
+    #[error("name must not start with a hyphen")]
+    LeadingHyphen,
+
+    #[error("name must not end with a hyphen")]
+    TrailingHyphen,
+
+    #[error("name must be normalized as `{normalized}`")]
+    NotNormalized { normalized: String },
+
+    #[error("name is confusable with `{name}`")]
+    Confusable { name: String },
+}
+
+#[cw_serde]
+pub struct NormalizedNameResponse {
+    /// lowercased NFC form of the name
+    pub normalized: String,
+    /// skeleton of the name as defined in UTS #39. Names having the same skeleton are confusable.
+    pub skeleton: String,
+    /// minted name confusable with the name, if any
+    pub confusable_with: Option<String>,
+}
+
 #[cw_serde]
 pub struct MigrateMsg {}
 
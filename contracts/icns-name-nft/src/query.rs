@@ -1,5 +1,10 @@
 use crate::{
-    msg::{AdminResponse, IsAdminResponse, TransferrableResponse},
+    checks::{confusable_name, name_skeleton, name_violation, normalize_name},
+    msg::{
+        AdminResponse, GuardiansResponse, HooksResponse, IsAdminResponse, IsGuardianResponse,
+        NamePolicyResponse, NormalizedNameResponse, PausedResponse, TransferrableResponse,
+        ValidateNameResponse,
+    },
     state::CONFIG,
 };
 use cosmwasm_std::{Deps, StdResult};
@@ -30,6 +35,64 @@ pub fn is_admin(deps: Deps, addr: String) -> StdResult<IsAdminResponse> {
     Ok(IsAdminResponse { is_admin })
 }
 
+pub fn guardians(deps: Deps) -> StdResult<GuardiansResponse> {
+    let guardians = CONFIG
+        .load(deps.storage)?
+        .guardians
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+    Ok(GuardiansResponse { guardians })
+}
+
+pub fn is_guardian(deps: Deps, addr: String) -> StdResult<IsGuardianResponse> {
+    let guardians = CONFIG.load(deps.storage)?.guardians;
+
+    Ok(IsGuardianResponse {
+        is_guardian: guardians.iter().any(|guardian| guardian == &addr),
+    })
+}
+
+pub fn hooks(deps: Deps) -> StdResult<HooksResponse> {
+    let hooks = CONFIG
+        .load(deps.storage)?
+        .hooks
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+    Ok(HooksResponse { hooks })
+}
+
+pub fn name_policy(deps: Deps) -> StdResult<NamePolicyResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(NamePolicyResponse {
+        policy: config.name_policy,
+    })
+}
+
+pub fn validate_name(deps: Deps, name: String) -> StdResult<ValidateNameResponse> {
+    Ok(ValidateNameResponse {
+        violation: name_violation(deps, &name)?,
+    })
+}
+
+pub fn normalized_name(deps: Deps, name: String) -> StdResult<NormalizedNameResponse> {
+    Ok(NormalizedNameResponse {
+        normalized: normalize_name(&name),
+        skeleton: name_skeleton(&name),
+        confusable_with: confusable_name(deps.storage, &normalize_name(&name))?,
+    })
+}
+
+pub fn paused(deps: Deps) -> StdResult<PausedResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(PausedResponse {
+        paused: config.paused,
+    })
+}
+
 pub fn transferrable(deps: Deps) -> StdResult<TransferrableResponse> {
     let config = CONFIG.load(deps.storage)?;
     Ok(TransferrableResponse {
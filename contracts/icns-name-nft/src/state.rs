@@ -1,6 +1,6 @@
 use cosmwasm_schema::cw_serde;
 use cosmwasm_std::Addr;
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 
 #[cw_serde]
 pub struct Config {
@@ -9,6 +9,57 @@ pub struct Config {
     // transferrable flag. If true nfts minted can be transferred to other accounts.
     // If false, transferred are not allowed.
     pub transferrable: bool,
+    // list of guardians. Guardians can pause the contract, but can not unpause it.
+    #[serde(default)]
+    pub guardians: Vec<Addr>,
+    // paused flag. If true, minting and transfers are not allowed.
+    #[serde(default)]
+    pub paused: bool,
+    // list of contracts notified with `NameOwnerChangedHook` whenever a name changes owner.
+    #[serde(default)]
+    pub hooks: Vec<Addr>,
+    // policy that names must satisfy to be minted.
+    #[serde(default)]
+    pub name_policy: NamePolicy,
+}
+
+#[cw_serde]
+pub struct NamePolicy {
+    // characters allowed in the name. A dot is never allowed regardless of the charset.
+    pub charset: NameCharset,
+    // minimum number of characters in the name.
+    pub min_length: u32,
+    // maximum number of characters in the name.
+    pub max_length: u32,
+    // if true, the name can start with a hyphen.
+    pub allow_leading_hyphen: bool,
+    // if true, the name can end with a hyphen.
+    pub allow_trailing_hyphen: bool,
+}
+
+impl Default for NamePolicy {
+    fn default() -> Self {
+        NamePolicy {
+            charset: NameCharset::Any,
+            min_length: 1,
+            max_length: 64,
+            allow_leading_hyphen: false,
+            allow_trailing_hyphen: false,
+        }
+    }
+}
+
+#[cw_serde]
+pub enum NameCharset {
+    // any character except whitespaces and control characters.
+    Any,
+    // lowercase ascii letters, digits and hyphens.
+    LowercaseAlphanumeric,
+    // only the given characters.
+    Custom { chars: String },
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
+
+// SKELETONS maps the skeleton of each minted name to the name, to block minting confusable names.
+pub const SKELETONS: Map<&str, String> = Map::new("skeletons");
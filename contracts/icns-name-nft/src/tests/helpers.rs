@@ -18,6 +18,7 @@ mod reciever {
     use cw721::Cw721ReceiveMsg;
 
     use super::*;
+    use crate::msg::NameOwnerChangedHook;
 
     #[entry_point]
     // #[allow(dead_code)]
@@ -33,6 +34,7 @@ mod reciever {
     #[cw_serde]
     pub enum ExecuteMsg {
         ReceiveNft(Cw721ReceiveMsg),
+        NameOwnerChangedHook(NameOwnerChangedHook),
     }
 
     #[entry_point]
@@ -40,9 +42,19 @@ mod reciever {
         _deps: DepsMut,
         _env: Env,
         _info: MessageInfo,
-        _msg: ExecuteMsg,
+        msg: ExecuteMsg,
     ) -> StdResult<Response> {
-        Ok(Response::default())
+        match msg {
+            ExecuteMsg::ReceiveNft(_) => Ok(Response::default()),
+            // expose the hook as attributes to be asserted in tests
+            ExecuteMsg::NameOwnerChangedHook(hook) => Ok(Response::new()
+                .add_attribute("name", hook.name)
+                .add_attribute(
+                    "old_owner",
+                    hook.old_owner.unwrap_or_else(|| "none".to_string()),
+                )
+                .add_attribute("new_owner", hook.new_owner)),
+        }
     }
 
     #[entry_point]
@@ -60,6 +72,7 @@ pub fn mock_reciever_contract() -> Box<dyn Contract<Empty>> {
 
 pub struct TestEnv {
     pub app: BasicApp,
+    #[allow(dead_code)]
     pub code_id: u64,
     pub contract_addr: Addr,
     pub admins: Vec<Addr>,
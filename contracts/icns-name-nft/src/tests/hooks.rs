@@ -0,0 +1,179 @@
+#![cfg(test)]
+
+use crate::{
+    error::ContractError,
+    msg::{ExecuteMsg, HooksResponse, ICNSNameExecuteMsg, Metadata},
+    tests::helpers::{mock_reciever_contract, TestEnv, TestEnvBuilder},
+    QueryMsg,
+};
+
+use cosmwasm_std::{to_binary, Addr, Event};
+use cw721_base::MintMsg;
+use cw_multi_test::{AppResponse, BasicApp, Executor};
+
+#[test]
+fn hooks_are_notified_when_name_changes_owner() {
+    let TestEnv {
+        mut app,
+        admins,
+        contract_addr,
+        registrar,
+        ..
+    } = TestEnvBuilder::default().with_transferrable(true).build();
+
+    let bob = Addr::unchecked("bob");
+    let alice = Addr::unchecked("alice");
+
+    let reciever_code_id = app.store_code(mock_reciever_contract());
+    let hook = app
+        .instantiate_contract(reciever_code_id, admins[0].clone(), &(), &[], "hook", None)
+        .unwrap();
+
+    let execute = |app: &mut BasicApp, sender: &Addr, msg: ICNSNameExecuteMsg| {
+        app.execute_contract(
+            sender.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Extension { msg },
+            &[],
+        )
+    };
+
+    let mint = |app: &mut BasicApp, name: &str, owner: &Addr| {
+        app.execute_contract(
+            registrar.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Mint(MintMsg {
+                token_id: name.to_string(),
+                owner: owner.to_string(),
+                token_uri: None,
+                extension: Metadata::default(),
+            }),
+            &[],
+        )
+        .unwrap()
+    };
+
+    let hooks = |app: &BasicApp| {
+        let HooksResponse { hooks } = app
+            .wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::Hooks {})
+            .unwrap();
+        hooks
+    };
+
+    let notified = |res: &AppResponse, name: &str, old_owner: &str, new_owner: &str| {
+        res.has_event(
+            &Event::new("wasm")
+                .add_attribute("_contract_addr", hook.to_string())
+                .add_attribute("name", name)
+                .add_attribute("old_owner", old_owner)
+                .add_attribute("new_owner", new_owner),
+        )
+    };
+
+    // only admin can add hook
+    let err = execute(
+        &mut app,
+        &bob,
+        ICNSNameExecuteMsg::AddHook {
+            hook_address: hook.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &cw721_base::ContractError::Unauthorized {}.into()
+    );
+
+    // hooks are not notified before being added
+    let res = mint(&mut app, "carol", &bob);
+    assert!(!notified(&res, "carol", "none", bob.as_str()));
+
+    execute(
+        &mut app,
+        &admins[0],
+        ICNSNameExecuteMsg::AddHook {
+            hook_address: hook.to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(hooks(&app), vec![hook.to_string()]);
+
+    // adding the same hook should fail
+    let err = execute(
+        &mut app,
+        &admins[0],
+        ICNSNameExecuteMsg::AddHook {
+            hook_address: hook.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::HookAlreadyRegistered {
+            hook: hook.to_string()
+        }
+    );
+
+    // mint notifies without old owner
+    let res = mint(&mut app, "bob", &bob);
+    assert!(notified(&res, "bob", "none", bob.as_str()));
+
+    // transfer notifies
+    let res = app
+        .execute_contract(
+            bob.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::TransferNft {
+                recipient: alice.to_string(),
+                token_id: "bob".to_string(),
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(notified(&res, "bob", bob.as_str(), alice.as_str()));
+
+    // send notifies
+    let res = app
+        .execute_contract(
+            alice.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::SendNft {
+                contract: hook.to_string(),
+                token_id: "bob".to_string(),
+                msg: to_binary("").unwrap(),
+            },
+            &[],
+        )
+        .unwrap();
+    assert!(notified(&res, "bob", alice.as_str(), hook.as_str()));
+
+    // removed hook is no longer notified
+    execute(
+        &mut app,
+        &admins[0],
+        ICNSNameExecuteMsg::RemoveHook {
+            hook_address: hook.to_string(),
+        },
+    )
+    .unwrap();
+    assert_eq!(hooks(&app), Vec::<String>::new());
+    let res = mint(&mut app, "alice", &alice);
+    assert!(!notified(&res, "alice", "none", alice.as_str()));
+
+    // removing a hook not registered should fail
+    let err = execute(
+        &mut app,
+        &admins[0],
+        ICNSNameExecuteMsg::RemoveHook {
+            hook_address: hook.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::HookNotRegistered {
+            hook: hook.to_string()
+        }
+    );
+}
@@ -0,0 +1,142 @@
+#![cfg(test)]
+
+use crate::{
+    error::ContractError,
+    msg::{ExecuteMsg, ICNSNameExecuteMsg, IdentityRef, Metadata, NftInfoResponse, Profile},
+    tests::helpers::{TestEnv, TestEnvBuilder},
+    QueryMsg,
+};
+
+use cosmwasm_std::Addr;
+use cw721_base::MintMsg;
+use cw_multi_test::{BasicApp, Executor};
+
+#[test]
+fn owner_can_update_profile_of_name() {
+    let TestEnv {
+        mut app,
+        contract_addr,
+        registrar,
+        ..
+    } = TestEnvBuilder::default().build();
+
+    let bob = Addr::unchecked("bob");
+    let alice = Addr::unchecked("alice");
+    let identity = IdentityRef {
+        provider: "twitter".to_string(),
+        subject_id: "1234".to_string(),
+    };
+
+    app.execute_contract(
+        registrar,
+        contract_addr.clone(),
+        &ExecuteMsg::Mint(MintMsg {
+            token_id: "bob".to_string(),
+            owner: bob.to_string(),
+            token_uri: None,
+            extension: Metadata {
+                original_claimer: Some(bob.to_string()),
+                identity: Some(identity.clone()),
+                ..Default::default()
+            },
+        }),
+        &[],
+    )
+    .unwrap();
+
+    let update_metadata = |app: &mut BasicApp, sender: &Addr, profile: Profile| {
+        app.execute_contract(
+            sender.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: ICNSNameExecuteMsg::UpdateMetadata {
+                    token_id: "bob".to_string(),
+                    profile,
+                },
+            },
+            &[],
+        )
+    };
+
+    let metadata = |app: &BasicApp| {
+        let NftInfoResponse { extension, .. } = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr.clone(),
+                &QueryMsg::NftInfo {
+                    token_id: "bob".to_string(),
+                },
+            )
+            .unwrap();
+        extension
+    };
+
+    // mint time is recorded by the contract
+    let minted = Metadata {
+        minted_at: Some(app.block_info().time),
+        original_claimer: Some(bob.to_string()),
+        identity: Some(identity),
+        ..Default::default()
+    };
+    assert_eq!(metadata(&app), minted);
+
+    let profile = Profile {
+        display_name: Some("Bob".to_string()),
+        avatar_uri: Some("ipfs://avatar".to_string()),
+        description: Some("hello".to_string()),
+    };
+
+    // only owner can update profile
+    let err = update_metadata(&mut app, &alice, profile.clone()).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &cw721_base::ContractError::Unauthorized {}.into()
+    );
+
+    // display name must match the name
+    let err = update_metadata(
+        &mut app,
+        &bob,
+        Profile {
+            display_name: Some("B0b".to_string()),
+            ..profile.clone()
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidProfile {
+            msg: "display name `B0b` does not match name `bob`".to_string()
+        }
+    );
+
+    // description can not be too long
+    let err = update_metadata(
+        &mut app,
+        &bob,
+        Profile {
+            description: Some("a".repeat(513)),
+            ..profile.clone()
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidProfile {
+            msg: "description must be at most 512 bytes long".to_string()
+        }
+    );
+
+    update_metadata(&mut app, &bob, profile.clone()).unwrap();
+    assert_eq!(
+        metadata(&app),
+        Metadata {
+            profile: profile.clone(),
+            ..minted.clone()
+        }
+    );
+
+    // profile can be cleared
+    update_metadata(&mut app, &bob, Profile::default()).unwrap();
+    assert_eq!(metadata(&app), minted);
+}
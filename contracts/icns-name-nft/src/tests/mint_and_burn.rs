@@ -7,8 +7,8 @@ use crate::{
     QueryMsg,
 };
 
-use cosmwasm_std::{Addr, StdError, StdResult};
-use cw721::OwnerOfResponse;
+use cosmwasm_std::{Addr, StdError, StdResult, Timestamp};
+use cw721::{NumTokensResponse, OwnerOfResponse};
 use cw721_base::MintMsg;
 use cw_multi_test::{BasicApp, Executor};
 
@@ -45,7 +45,10 @@ fn can_not_mint_until_minter_is_set() {
                 token_id: name,
                 owner,
                 token_uri: None,
-                extension: Metadata { referral: None },
+                extension: Metadata {
+                    referral: None,
+                    ..Default::default()
+                },
             }),
             &[],
         )
@@ -137,7 +140,10 @@ fn can_not_name_with_dot() {
                 token_id: name,
                 owner,
                 token_uri: None,
-                extension: Metadata { referral: None },
+                extension: Metadata {
+                    referral: None,
+                    ..Default::default()
+                },
             }),
             &[],
         )
@@ -201,7 +207,10 @@ fn only_registrar_can_mint() {
                 token_id: name,
                 owner,
                 token_uri: None,
-                extension: Metadata { referral: None },
+                extension: Metadata {
+                    referral: None,
+                    ..Default::default()
+                },
             }),
             &[],
         )
@@ -283,3 +292,85 @@ fn burning_is_not_allowed() {
         &cw721_base::ContractError::Unauthorized {}.into()
     );
 }
+
+#[test]
+fn expired_name_can_be_minted_again() {
+    let TestEnv {
+        mut app,
+        admins,
+        contract_addr,
+        registrar,
+        ..
+    } = TestEnvBuilder::default().with_transferrable(false).build();
+
+    let owner = |app: &BasicApp, name: String| -> StdResult<_> {
+        let OwnerOfResponse { owner, .. } = app.wrap().query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::OwnerOf {
+                token_id: name,
+                include_expired: None,
+            },
+        )?;
+
+        Ok(owner)
+    };
+
+    let mint = |app: &mut BasicApp, name: String, owner: String, expiry: Option<Timestamp>| {
+        app.execute_contract(
+            registrar.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Mint(MintMsg {
+                token_id: name,
+                owner,
+                token_uri: None,
+                extension: Metadata {
+                    referral: None,
+                    expiry,
+                    ..Default::default()
+                },
+            }),
+            &[],
+        )
+    };
+
+    let name = "bob";
+    let expiry = app.block_info().time.plus_seconds(100);
+    mint(&mut app, name.to_string(), "bob".to_string(), Some(expiry)).unwrap();
+
+    // only minter can set expiry
+    let err = app
+        .execute_contract(
+            admins[0].clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: ICNSNameExecuteMsg::SetExpiry {
+                    token_id: name.to_string(),
+                    expiry: None,
+                },
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &cw721_base::ContractError::Unauthorized {}.into()
+    );
+
+    // unexpired name can not be minted again
+    let err = mint(&mut app, name.to_string(), "alice".to_string(), None).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &cw721_base::ContractError::Claimed {}.into()
+    );
+
+    // expired name can be minted again
+    app.update_block(|block| block.time = expiry);
+    mint(&mut app, name.to_string(), "alice".to_string(), None).unwrap();
+    assert_eq!(owner(&app, name.to_string()).unwrap(), "alice");
+
+    let NumTokensResponse { count } = app
+        .wrap()
+        .query_wasm_smart(contract_addr, &QueryMsg::NumTokens {})
+        .unwrap();
+    assert_eq!(count, 1);
+}
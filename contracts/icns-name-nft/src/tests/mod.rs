@@ -3,5 +3,9 @@
 pub(crate) mod helpers;
 
 mod admin;
+mod hooks;
+mod metadata;
 mod mint_and_burn;
+mod name_policy;
+mod pause;
 mod transferability;
@@ -0,0 +1,255 @@
+#![cfg(test)]
+
+use crate::{
+    error::ContractError,
+    msg::{
+        ExecuteMsg, ICNSNameExecuteMsg, Metadata, NamePolicyResponse, NameViolation,
+        NormalizedNameResponse, ValidateNameResponse,
+    },
+    state::{NameCharset, NamePolicy},
+    tests::helpers::{TestEnv, TestEnvBuilder},
+    QueryMsg,
+};
+
+use cosmwasm_std::Addr;
+use cw721_base::MintMsg;
+use cw_multi_test::{BasicApp, Executor};
+
+#[test]
+fn names_are_validated_against_name_policy() {
+    let TestEnv {
+        mut app,
+        admins,
+        contract_addr,
+        registrar,
+        ..
+    } = TestEnvBuilder::default().build();
+
+    let bob = Addr::unchecked("bob");
+
+    let set_name_policy = |app: &mut BasicApp, sender: &Addr, policy: NamePolicy| {
+        app.execute_contract(
+            sender.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Extension {
+                msg: ICNSNameExecuteMsg::SetNamePolicy { policy },
+            },
+            &[],
+        )
+    };
+
+    let mint = |app: &mut BasicApp, name: &str| {
+        app.execute_contract(
+            registrar.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Mint(MintMsg {
+                token_id: name.to_string(),
+                owner: bob.to_string(),
+                token_uri: None,
+                extension: Metadata::default(),
+            }),
+            &[],
+        )
+    };
+
+    let violation = |app: &BasicApp, name: &str| {
+        let ValidateNameResponse { violation } = app
+            .wrap()
+            .query_wasm_smart(
+                contract_addr.clone(),
+                &QueryMsg::ValidateName {
+                    name: name.to_string(),
+                },
+            )
+            .unwrap();
+        violation
+    };
+
+    // default policy rejects whitespaces, control characters and overly long names
+    assert_eq!(violation(&app, "bob"), None);
+    assert_eq!(
+        violation(&app, "bob dylan"),
+        Some(NameViolation::InvalidCharacter {
+            character: " ".to_string()
+        })
+    );
+    assert_eq!(
+        violation(&app, "bob\u{0}"),
+        Some(NameViolation::InvalidCharacter {
+            character: "\u{0}".to_string()
+        })
+    );
+    assert_eq!(
+        violation(&app, &"b".repeat(65)),
+        Some(NameViolation::TooLong { max_length: 64 })
+    );
+    assert_eq!(
+        violation(&app, ""),
+        Some(NameViolation::TooShort { min_length: 1 })
+    );
+    assert_eq!(violation(&app, "-bob"), Some(NameViolation::LeadingHyphen));
+    assert_eq!(
+        violation(&app, "bob.cosmos"),
+        Some(NameViolation::ContainsDot)
+    );
+
+    let policy = NamePolicy {
+        charset: NameCharset::LowercaseAlphanumeric,
+        min_length: 3,
+        max_length: 10,
+        allow_leading_hyphen: false,
+        allow_trailing_hyphen: true,
+    };
+
+    // only admin can set name policy
+    let err = set_name_policy(&mut app, &bob, policy.clone()).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &cw721_base::ContractError::Unauthorized {}.into()
+    );
+
+    // policy must be satisfiable
+    let err = set_name_policy(
+        &mut app,
+        &admins[0],
+        NamePolicy {
+            min_length: 11,
+            ..policy.clone()
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidNamePolicy {
+            msg: "min_length must not be greater than max_length".to_string()
+        }
+    );
+
+    set_name_policy(&mut app, &admins[0], policy.clone()).unwrap();
+    let NamePolicyResponse { policy: stored } = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::NamePolicy {})
+        .unwrap();
+    assert_eq!(stored, policy);
+
+    assert_eq!(
+        violation(&app, "bob_"),
+        Some(NameViolation::InvalidCharacter {
+            character: "_".to_string()
+        })
+    );
+    assert_eq!(
+        violation(&app, "bo"),
+        Some(NameViolation::TooShort { min_length: 3 })
+    );
+    assert_eq!(
+        violation(&app, "bob-dylan-jr"),
+        Some(NameViolation::TooLong { max_length: 10 })
+    );
+    assert_eq!(violation(&app, "bob-"), None);
+
+    // mint enforces the name policy
+    let err = mint(&mut app, "bob_").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NameNotAllowed {
+            violation: NameViolation::InvalidCharacter {
+                character: "_".to_string()
+            }
+        }
+    );
+    let err = mint(&mut app, "bob.dylan").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidName {}
+    );
+    mint(&mut app, "bob-").unwrap();
+}
+
+#[test]
+fn names_must_be_normalized_and_not_confusable() {
+    let TestEnv {
+        mut app,
+        contract_addr,
+        registrar,
+        ..
+    } = TestEnvBuilder::default().build();
+
+    let mint = |app: &mut BasicApp, name: &str| {
+        app.execute_contract(
+            registrar.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Mint(MintMsg {
+                token_id: name.to_string(),
+                owner: "bob".to_string(),
+                token_uri: None,
+                extension: Metadata::default(),
+            }),
+            &[],
+        )
+    };
+
+    let normalized_name = |app: &BasicApp, name: &str| -> NormalizedNameResponse {
+        app.wrap()
+            .query_wasm_smart(
+                contract_addr.clone(),
+                &QueryMsg::NormalizedName {
+                    name: name.to_string(),
+                },
+            )
+            .unwrap()
+    };
+
+    // uppercase and decomposed names must be normalized before minting
+    let err = mint(&mut app, "Alice").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NameNotAllowed {
+            violation: NameViolation::NotNormalized {
+                normalized: "alice".to_string()
+            }
+        }
+    );
+    let err = mint(&mut app, "jose\u{301}").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NameNotAllowed {
+            violation: NameViolation::NotNormalized {
+                normalized: "jos\u{e9}".to_string()
+            }
+        }
+    );
+
+    assert_eq!(
+        normalized_name(&app, "\u{430}lice"),
+        NormalizedNameResponse {
+            normalized: "\u{430}lice".to_string(),
+            skeleton: "alice".to_string(),
+            confusable_with: None,
+        }
+    );
+
+    mint(&mut app, "alice").unwrap();
+
+    // name with cyrillic `а` is confusable with the minted name
+    assert_eq!(
+        normalized_name(&app, "\u{410}lice"),
+        NormalizedNameResponse {
+            normalized: "\u{430}lice".to_string(),
+            skeleton: "alice".to_string(),
+            confusable_with: Some("alice".to_string()),
+        }
+    );
+    let err = mint(&mut app, "\u{430}lice").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NameNotAllowed {
+            violation: NameViolation::Confusable {
+                name: "alice".to_string()
+            }
+        }
+    );
+
+    // names having different skeletons can be minted
+    mint(&mut app, "alicia").unwrap();
+}
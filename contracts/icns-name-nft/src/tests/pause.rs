@@ -0,0 +1,192 @@
+#![cfg(test)]
+
+use crate::{
+    error::ContractError,
+    msg::{ExecuteMsg, GuardiansResponse, ICNSNameExecuteMsg, Metadata, PausedResponse},
+    tests::helpers::{TestEnv, TestEnvBuilder},
+    QueryMsg,
+};
+
+use cosmwasm_std::{Addr, StdResult};
+use cw721::OwnerOfResponse;
+use cw721_base::MintMsg;
+use cw_multi_test::{BasicApp, Executor};
+
+#[test]
+fn guardian_can_pause_but_only_admin_can_unpause() {
+    let TestEnv {
+        mut app,
+        admins,
+        contract_addr,
+        registrar,
+        ..
+    } = TestEnvBuilder::default().with_transferrable(true).build();
+
+    let guardian = Addr::unchecked("guardian");
+    let bob = Addr::unchecked("bob");
+    let alice = Addr::unchecked("alice");
+
+    let execute = |app: &mut BasicApp, sender: &Addr, msg: ICNSNameExecuteMsg| {
+        app.execute_contract(
+            sender.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Extension { msg },
+            &[],
+        )
+    };
+
+    let mint = |app: &mut BasicApp, name: &str, owner: &Addr| {
+        app.execute_contract(
+            registrar.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::Mint(MintMsg {
+                token_id: name.to_string(),
+                owner: owner.to_string(),
+                token_uri: None,
+                extension: Metadata::default(),
+            }),
+            &[],
+        )
+    };
+
+    let transfer = |app: &mut BasicApp, sender: &Addr, name: &str, recipient: &Addr| {
+        app.execute_contract(
+            sender.clone(),
+            contract_addr.clone(),
+            &ExecuteMsg::TransferNft {
+                recipient: recipient.to_string(),
+                token_id: name.to_string(),
+            },
+            &[],
+        )
+    };
+
+    let paused = |app: &BasicApp| {
+        let PausedResponse { paused } = app
+            .wrap()
+            .query_wasm_smart(contract_addr.clone(), &QueryMsg::Paused {})
+            .unwrap();
+        paused
+    };
+
+    let owner = |app: &BasicApp, name: &str| -> StdResult<String> {
+        let OwnerOfResponse { owner, .. } = app.wrap().query_wasm_smart(
+            contract_addr.clone(),
+            &QueryMsg::OwnerOf {
+                token_id: name.to_string(),
+                include_expired: None,
+            },
+        )?;
+        Ok(owner)
+    };
+
+    // only admin can add guardian
+    let err = execute(
+        &mut app,
+        &guardian,
+        ICNSNameExecuteMsg::AddGuardian {
+            guardian_address: guardian.to_string(),
+        },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &cw721_base::ContractError::Unauthorized {}.into()
+    );
+    execute(
+        &mut app,
+        &admins[0],
+        ICNSNameExecuteMsg::AddGuardian {
+            guardian_address: guardian.to_string(),
+        },
+    )
+    .unwrap();
+    let GuardiansResponse { guardians } = app
+        .wrap()
+        .query_wasm_smart(contract_addr.clone(), &QueryMsg::Guardians {})
+        .unwrap();
+    assert_eq!(guardians, vec![guardian.to_string()]);
+
+    mint(&mut app, "bob", &bob).unwrap();
+
+    // non guardian can not pause
+    let err = execute(
+        &mut app,
+        &bob,
+        ICNSNameExecuteMsg::SetPaused { paused: true },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &cw721_base::ContractError::Unauthorized {}.into()
+    );
+
+    // guardian can pause
+    execute(
+        &mut app,
+        &guardian,
+        ICNSNameExecuteMsg::SetPaused { paused: true },
+    )
+    .unwrap();
+    assert!(paused(&app));
+
+    // minting and transfers are blocked while paused
+    let err = mint(&mut app, "alice", &alice).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Paused {}
+    );
+    let err = transfer(&mut app, &bob, "bob", &alice).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Paused {}
+    );
+
+    // queries keep working
+    assert_eq!(owner(&app, "bob").unwrap(), bob.to_string());
+
+    // guardian can not unpause
+    let err = execute(
+        &mut app,
+        &guardian,
+        ICNSNameExecuteMsg::SetPaused { paused: false },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &cw721_base::ContractError::Unauthorized {}.into()
+    );
+
+    // admin can unpause
+    execute(
+        &mut app,
+        &admins[0],
+        ICNSNameExecuteMsg::SetPaused { paused: false },
+    )
+    .unwrap();
+    assert!(!paused(&app));
+
+    mint(&mut app, "alice", &alice).unwrap();
+    transfer(&mut app, &bob, "bob", &alice).unwrap();
+    assert_eq!(owner(&app, "bob").unwrap(), alice.to_string());
+
+    // removed guardian can no longer pause
+    execute(
+        &mut app,
+        &admins[0],
+        ICNSNameExecuteMsg::RemoveGuardian {
+            guardian_address: guardian.to_string(),
+        },
+    )
+    .unwrap();
+    let err = execute(
+        &mut app,
+        &guardian,
+        ICNSNameExecuteMsg::SetPaused { paused: true },
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &cw721_base::ContractError::Unauthorized {}.into()
+    );
+}
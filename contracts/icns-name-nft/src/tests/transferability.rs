@@ -39,7 +39,10 @@ mod non_transferrable {
                 token_id: name.to_string(),
                 owner: name_owner.to_string(),
                 token_uri: None,
-                extension: Metadata { referral: None },
+                extension: Metadata {
+                    referral: None,
+                    ..Default::default()
+                },
             }),
             &[],
         )
@@ -85,7 +88,10 @@ mod non_transferrable {
                 token_id: name.to_string(),
                 owner: name_owner.to_string(),
                 token_uri: None,
-                extension: Metadata { referral: None },
+                extension: Metadata {
+                    referral: None,
+                    ..Default::default()
+                },
             }),
             &[],
         )
@@ -131,7 +137,10 @@ mod non_transferrable {
                 token_id: name.to_string(),
                 owner: registrar.to_string(),
                 token_uri: None,
-                extension: Metadata { referral: None },
+                extension: Metadata {
+                    referral: None,
+                    ..Default::default()
+                },
             }),
             &[],
         )
@@ -176,7 +185,10 @@ mod non_transferrable {
                 token_id: admin.to_string(),
                 owner: admin.to_string(),
                 token_uri: None,
-                extension: Metadata { referral: None },
+                extension: Metadata {
+                    referral: None,
+                    ..Default::default()
+                },
             }),
             &[],
         )
@@ -246,7 +258,10 @@ mod transferrable {
                 token_id: name.to_string(),
                 owner: name_owner.to_string(),
                 token_uri: None,
-                extension: Metadata { referral: None },
+                extension: Metadata {
+                    referral: None,
+                    ..Default::default()
+                },
             }),
             &[],
         )
@@ -301,7 +316,10 @@ mod transferrable {
                 token_id: name.to_string(),
                 owner: name_owner.to_string(),
                 token_uri: None,
-                extension: Metadata { referral: None },
+                extension: Metadata {
+                    referral: None,
+                    ..Default::default()
+                },
             }),
             &[],
         )
@@ -0,0 +1,355 @@
+use cosmwasm_std::{
+    Addr, BankMsg, Binary, Coin, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult,
+    Uint128,
+};
+use cw_utils::must_pay;
+use icns_name_nft::msg::Metadata;
+use sha2::Digest;
+
+use crate::{
+    checks::{check_admin, check_name_available, check_valid_name},
+    contract::{mint_name_msg, refund_msg},
+    msg::{AuctionResponse, BidResponse},
+    state::{Auction, RevealedBid, SealedBid, AUCTIONS, SEALED_BIDS},
+    treasury::collect_fee,
+    ContractError,
+};
+
+// sealed_bid returns the sealed bid of the bidder on the name with the given amount and salt.
+// sealed_bid = sha256(name || 0x00 || bidder || 0x00 || amount || 0x00 || salt)
+pub fn sealed_bid(name: &str, bidder: &Addr, amount: Uint128, salt: &[u8]) -> Vec<u8> {
+    sha2::Sha256::new()
+        .chain_update(name.as_bytes())
+        .chain_update([0])
+        .chain_update(bidder.as_bytes())
+        .chain_update([0])
+        .chain_update(amount.to_string().as_bytes())
+        .chain_update([0])
+        .chain_update(salt)
+        .finalize()
+        .to_vec()
+}
+
+// execute_start_auction starts sealed-bid auction for the name.
+// The name must not be claimed, unless its registration has expired.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_start_auction(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    denom: String,
+    min_bid: Uint128,
+    bid_duration: u64,
+    reveal_duration: u64,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    if AUCTIONS.has(deps.storage, &name) {
+        return Err(ContractError::NameInAuction { name });
+    }
+
+    check_name_available(deps.as_ref(), &env, &name)?;
+    // name must be mintable, otherwise the auction could never be settled
+    check_valid_name(deps.as_ref(), &name)?;
+
+    if bid_duration == 0 || reveal_duration == 0 {
+        return Err(ContractError::InvalidAuction {
+            msg: "bid and reveal durations must be greater than 0".to_string(),
+        });
+    }
+
+    let bid_end = env.block.time.plus_seconds(bid_duration);
+    let reveal_end = bid_end.plus_seconds(reveal_duration);
+    AUCTIONS.save(
+        deps.storage,
+        &name,
+        &Auction {
+            denom,
+            min_bid,
+            bid_end,
+            reveal_end,
+            highest_bid: None,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "start_auction")
+        .add_attribute("name", name)
+        .add_attribute("bid_end", bid_end.to_string())
+        .add_attribute("reveal_end", reveal_end.to_string()))
+}
+
+// execute_place_bid places sealed bid of the sender with the deposit sent along.
+pub fn execute_place_bid(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    sealed_bid: Binary,
+) -> Result<Response, ContractError> {
+    let auction = load_auction(deps.as_ref(), &name)?;
+
+    if env.block.time >= auction.bid_end {
+        return Err(ContractError::BiddingEnded { name });
+    }
+
+    if SEALED_BIDS.has(deps.storage, (&name, &info.sender)) {
+        return Err(ContractError::BidAlreadyPlaced { name });
+    }
+
+    let deposit = must_pay(&info, &auction.denom)
+        .map_err(|e| ContractError::InvalidBid { msg: e.to_string() })?;
+
+    SEALED_BIDS.save(
+        deps.storage,
+        (&name, &info.sender),
+        &SealedBid {
+            sealed_bid,
+            deposit,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "place_bid")
+        .add_attribute("name", name)
+        .add_attribute("bidder", info.sender))
+}
+
+// execute_reveal_bid reveals sealed bid of the sender.
+// If the bid becomes the highest bid, the deposit exceeding the bid amount and
+// the deposit of the previous highest bid are refunded.
+// Otherwise, the whole deposit is refunded.
+pub fn execute_reveal_bid(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    amount: Uint128,
+    salt: Binary,
+) -> Result<Response, ContractError> {
+    let mut auction = load_auction(deps.as_ref(), &name)?;
+
+    if env.block.time < auction.bid_end || env.block.time >= auction.reveal_end {
+        return Err(ContractError::NotRevealPeriod { name });
+    }
+
+    let bid = SEALED_BIDS
+        .may_load(deps.storage, (&name, &info.sender))?
+        .ok_or_else(|| ContractError::BidNotFound { name: name.clone() })?;
+
+    if bid.sealed_bid.as_slice() != sealed_bid(&name, &info.sender, amount, &salt) {
+        return Err(ContractError::InvalidBid {
+            msg: "revealed bid does not match the sealed bid".to_string(),
+        });
+    }
+
+    if amount > bid.deposit {
+        return Err(ContractError::InvalidBid {
+            msg: format!("bid amount {} exceeds deposit {}", amount, bid.deposit),
+        });
+    }
+
+    let is_highest = amount >= auction.min_bid
+        && auction
+            .highest_bid
+            .as_ref()
+            .map(|highest_bid| amount > highest_bid.amount)
+            .unwrap_or(true);
+
+    let mut refunds = vec![];
+    if is_highest {
+        // outbid bid is refunded in full
+        if let Some(outbid) = auction.highest_bid.take() {
+            let outbid_deposit = SEALED_BIDS.load(deps.storage, (&name, &outbid.bidder))?;
+            SEALED_BIDS.remove(deps.storage, (&name, &outbid.bidder));
+            refunds.push((outbid.bidder, outbid_deposit.deposit));
+        }
+
+        // keep only the bid amount as deposit
+        SEALED_BIDS.save(
+            deps.storage,
+            (&name, &info.sender),
+            &SealedBid {
+                deposit: amount,
+                ..bid.clone()
+            },
+        )?;
+        refunds.push((info.sender.clone(), bid.deposit - amount));
+        auction.highest_bid = Some(RevealedBid {
+            bidder: info.sender.clone(),
+            amount,
+        });
+        AUCTIONS.save(deps.storage, &name, &auction)?;
+    } else {
+        SEALED_BIDS.remove(deps.storage, (&name, &info.sender));
+        refunds.push((info.sender.clone(), bid.deposit));
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "reveal_bid")
+        .add_attribute("name", name)
+        .add_attribute("bidder", info.sender)
+        .add_attribute("amount", amount)
+        .add_attribute("highest", is_highest.to_string())
+        .add_messages(
+            refunds
+                .into_iter()
+                .filter_map(|(bidder, amount)| {
+                    refund_msg(&bidder, coins_of(amount, &auction.denom))
+                })
+                .collect::<Vec<BankMsg>>(),
+        ))
+}
+
+// execute_settle_auction mints the name to the highest bidder after reveal ends.
+// Deposits of the unrevealed bids are refunded. Anyone can settle the auction.
+pub fn execute_settle_auction(
+    deps: DepsMut,
+    env: Env,
+    name: String,
+) -> Result<Response, ContractError> {
+    let auction = load_auction(deps.as_ref(), &name)?;
+
+    if env.block.time < auction.reveal_end {
+        return Err(ContractError::AuctionNotEnded { name });
+    }
+
+    let winner = auction.highest_bid.as_ref().map(|bid| bid.bidder.clone());
+    let bids = SEALED_BIDS
+        .prefix(&name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut refund_msgs = vec![];
+    for (bidder, bid) in bids {
+        SEALED_BIDS.remove(deps.storage, (&name, &bidder));
+        if Some(&bidder) != winner.as_ref() {
+            refund_msgs.extend(refund_msg(&bidder, coins_of(bid.deposit, &auction.denom)));
+        }
+    }
+    AUCTIONS.remove(deps.storage, &name);
+
+    let mut res = Response::new()
+        .add_attribute("method", "settle_auction")
+        .add_attribute("name", &name)
+        .add_messages(refund_msgs);
+
+    // winning bid is kept as the fee for the name
+    if let Some(highest_bid) = auction.highest_bid {
+        let fee_msgs = collect_fee(
+            deps.storage,
+            &env,
+            &Coin {
+                denom: auction.denom,
+                amount: highest_bid.amount,
+            },
+        )?;
+        res = res
+            .add_attribute("winner", &highest_bid.bidder)
+            .add_message(mint_name_msg(
+                deps,
+                &env,
+                &name,
+                &highest_bid.bidder,
+                &highest_bid.bidder,
+                Metadata::default(),
+            )?)
+            .add_messages(fee_msgs);
+    }
+
+    Ok(res)
+}
+
+// execute_withdraw_bid refunds the deposit of the sender's bid after reveal ends,
+// unless it is the highest bid. Deposits of the other bids do not depend on settling the auction.
+pub fn execute_withdraw_bid(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, ContractError> {
+    let auction = load_auction(deps.as_ref(), &name)?;
+
+    if env.block.time < auction.reveal_end {
+        return Err(ContractError::BidNotWithdrawable { name });
+    }
+
+    if auction.highest_bid.map(|bid| bid.bidder).as_ref() == Some(&info.sender) {
+        return Err(ContractError::HighestBidNotWithdrawable { name });
+    }
+
+    let bid = SEALED_BIDS
+        .may_load(deps.storage, (&name, &info.sender))?
+        .ok_or_else(|| ContractError::BidNotFound { name: name.clone() })?;
+    SEALED_BIDS.remove(deps.storage, (&name, &info.sender));
+
+    Ok(Response::new()
+        .add_attribute("method", "withdraw_bid")
+        .add_attribute("name", name)
+        .add_attribute("bidder", &info.sender)
+        .add_messages(refund_msg(
+            &info.sender,
+            coins_of(bid.deposit, &auction.denom),
+        )))
+}
+
+// execute_cancel_auction removes the auction and refunds the deposits of all bids.
+// Only admin can cancel an auction.
+pub fn execute_cancel_auction(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    let auction = load_auction(deps.as_ref(), &name)?;
+    let bids = SEALED_BIDS
+        .prefix(&name)
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut refund_msgs = vec![];
+    for (bidder, bid) in bids {
+        SEALED_BIDS.remove(deps.storage, (&name, &bidder));
+        refund_msgs.extend(refund_msg(&bidder, coins_of(bid.deposit, &auction.denom)));
+    }
+    AUCTIONS.remove(deps.storage, &name);
+
+    Ok(Response::new()
+        .add_attribute("method", "cancel_auction")
+        .add_attribute("name", name)
+        .add_messages(refund_msgs))
+}
+
+pub fn query_auction(deps: Deps, name: String) -> StdResult<AuctionResponse> {
+    Ok(AuctionResponse {
+        auction: AUCTIONS.may_load(deps.storage, &name)?,
+    })
+}
+
+pub fn query_bid(deps: Deps, name: String, bidder: String) -> StdResult<BidResponse> {
+    let bidder = deps.api.addr_validate(&bidder)?;
+    Ok(BidResponse {
+        bid: SEALED_BIDS.may_load(deps.storage, (&name, &bidder))?,
+    })
+}
+
+fn load_auction(deps: Deps, name: &str) -> Result<Auction, ContractError> {
+    AUCTIONS
+        .may_load(deps.storage, name)?
+        .ok_or_else(|| ContractError::AuctionNotFound {
+            name: name.to_string(),
+        })
+}
+
+fn coins_of(amount: Uint128, denom: &str) -> Vec<Coin> {
+    if amount.is_zero() {
+        return vec![];
+    }
+
+    vec![Coin {
+        denom: denom.to_string(),
+        amount,
+    }]
+}
@@ -1,10 +1,23 @@
 use cosmwasm_std::{
     from_slice, to_binary, Addr, Binary, Coin, Decimal, Deps, Env, MessageInfo, QueryRequest,
-    WasmQuery,
+    StdResult, WasmQuery,
 };
 
-use crate::{msg::VerifyingMsg, state::CONFIG, state::UNIQUE_TWITTER_ID, ContractError};
-use icns_name_nft::msg::{AdminResponse, NftInfoResponse, QueryMsg as NameNFTQueryMsg};
+use crate::{
+    msg::{ClaimCheck, ClaimCheckFailure, Verification, VerifyingMsg},
+    rate_limit::check_claim_rate_limit,
+    state::{
+        verifier_key_type, verifier_weight, KeyType, AUCTIONS, COMMITMENTS, CONFIG, IDENTITIES,
+        IDENTITY_PROVIDERS, NAME_EXPIRY, NAME_FEE, RESERVED_NAMES, USED_NONCES,
+    },
+    ContractError,
+};
+use cw721::OwnerOfResponse;
+use cw_utils::Expiration;
+use icns_name_nft::msg::{
+    AdminResponse, IsGuardianResponse, NftInfoResponse, QueryMsg as NameNFTQueryMsg,
+    ValidateNameResponse,
+};
 use itertools::Itertools;
 use sha2::Digest;
 
@@ -24,6 +37,30 @@ pub fn is_admin(deps: Deps, address: &Addr) -> Result<bool, ContractError> {
     Ok(true)
 }
 
+// is_guardian checks if the sender is a guardian, who can pause the contract.
+// guardian information is queried from the name nft contract.
+pub fn is_guardian(deps: Deps, address: &Addr) -> Result<bool, ContractError> {
+    let IsGuardianResponse { is_guardian } =
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: CONFIG.load(deps.storage)?.name_nft.to_string(),
+            msg: to_binary(&NameNFTQueryMsg::IsGuardian {
+                address: address.to_string(),
+            })?,
+        }))?;
+
+    Ok(is_guardian)
+}
+
+// check_not_paused checks if the contract is paused.
+// returns error if the contract is paused.
+pub fn check_not_paused(deps: Deps) -> Result<(), ContractError> {
+    if CONFIG.load(deps.storage)?.paused {
+        return Err(ContractError::Paused {});
+    }
+
+    Ok(())
+}
+
 // check_admin checks if the sender is an admin.
 // returns error if the sender is not an admin.
 pub fn check_admin(deps: Deps, sender: &Addr) -> Result<(), ContractError> {
@@ -52,19 +89,175 @@ pub fn check_existing_icns_name(deps: Deps, name: &str) -> Result<(), ContractEr
     Ok(())
 }
 
-// check_fee checks if the fee is correct.
-// Returns error if given funds do not match the fee set in the configuration.
-// Note that this method would not error if config.fee is None.
-pub fn check_fee(deps: Deps, funds: &[Coin]) -> Result<(), ContractError> {
+// check_name_available checks if the name can be minted.
+// returns error if the name is already registered and its registration has not expired.
+pub fn check_name_available(deps: Deps, env: &Env, name: &str) -> Result<(), ContractError> {
+    let expired = NAME_EXPIRY
+        .may_load(deps.storage, name.to_string())?
+        .map(|expiry| expiry <= env.block.time)
+        .unwrap_or(false);
+    if check_existing_icns_name(deps, name).is_ok() && !expired {
+        return Err(ContractError::NameAlreadyClaimed {
+            name: name.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+// check_valid_name checks if the name can be minted as name nft.
+// returns error if the name contains a dot, which separates the name and the bech32 prefix,
+// or if the name does not satisfy the name policy of the name nft contract.
+pub fn check_valid_name(deps: Deps, name: &str) -> Result<(), ContractError> {
+    if name.contains('.') {
+        return Err(ContractError::InvalidName {
+            name: name.to_string(),
+        });
+    }
+
+    let ValidateNameResponse { violation } =
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: CONFIG.load(deps.storage)?.name_nft.to_string(),
+            msg: to_binary(&NameNFTQueryMsg::ValidateName {
+                name: name.to_string(),
+            })?,
+        }))?;
+
+    match violation {
+        Some(violation) => Err(ContractError::NameNotAllowed {
+            name: name.to_string(),
+            violation,
+        }),
+        None => Ok(()),
+    }
+}
+
+// name_owner returns the current owner of the name.
+// owner information is queried from the name nft contract.
+pub fn name_owner(deps: Deps, name: &str) -> StdResult<Addr> {
+    let OwnerOfResponse { owner, .. } =
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: CONFIG.load(deps.storage)?.name_nft.to_string(),
+            msg: to_binary(&NameNFTQueryMsg::OwnerOf {
+                token_id: name.to_string(),
+                include_expired: None,
+            })?,
+        }))?;
+
+    Ok(Addr::unchecked(owner))
+}
+
+// claim_commitment returns the commitment of claiming the name by the claimer with the secret.
+// commitment = sha256(name || 0x00 || claimer || 0x00 || secret)
+pub fn claim_commitment(name: &str, claimer: &Addr, secret: &[u8]) -> Vec<u8> {
+    sha2::Sha256::new()
+        .chain_update(name.as_bytes())
+        .chain_update([0])
+        .chain_update(claimer.as_bytes())
+        .chain_update([0])
+        .chain_update(secret)
+        .finalize()
+        .to_vec()
+}
+
+// check_commitment checks if the claim has been committed by the claimer and can be revealed now.
+// returns the commitment if the check passes, or None if commitment is not required.
+pub fn check_commitment(
+    deps: Deps,
+    env: &Env,
+    claimer: &Addr,
+    name: &str,
+    secret: Option<&Binary>,
+) -> Result<Option<Vec<u8>>, ContractError> {
+    let period = match CONFIG.load(deps.storage)?.commitment_period {
+        Some(period) => period,
+        None => return Ok(None),
+    };
+
+    let secret = secret.ok_or(ContractError::CommitmentRequired {})?;
+    let commitment = claim_commitment(name, claimer, secret);
+    let committed_height = COMMITMENTS
+        .may_load(deps.storage, (claimer, &commitment))?
+        .ok_or_else(|| ContractError::CommitmentNotFound {
+            name: name.to_string(),
+        })?;
+
+    let reveal_height = committed_height + period.min_blocks;
+    if env.block.height < reveal_height {
+        return Err(ContractError::CommitmentNotRevealable { reveal_height });
+    }
+
+    let expired_height = committed_height + period.max_blocks;
+    if env.block.height > expired_height {
+        return Err(ContractError::CommitmentExpired { expired_height });
+    }
+
+    Ok(Some(commitment))
+}
+
+// name_fee returns the accepted fees for claiming the given name.
+// Fees set for the name takes precedence over length tier fees,
+// which takes precedence over the default fees.
+pub fn name_fee(deps: Deps, name: &str) -> StdResult<Vec<Coin>> {
+    if let Some(fees) = NAME_FEE.may_load(deps.storage, name.to_string())? {
+        return Ok(fees);
+    }
+
     let config = CONFIG.load(deps.storage)?;
+    Ok(config.length_tier_fees(name).unwrap_or(config.fees))
+}
 
-    if let Some(fee) = config.fee {
-        if funds != [fee.to_owned()] {
-            return Err(ContractError::InvalidFee { fee_required: fee });
-        }
+// check_fee checks if the fee is correct.
+// To pass the check, given funds must cover one of the accepted fees of the name
+// multiplied by the given number of years.
+// Returns the fee to be paid and the rest of the funds to be refunded.
+// Note that this method would not error if the name has no fee.
+pub fn check_fee(
+    deps: Deps,
+    name: &str,
+    funds: &[Coin],
+    years: u64,
+) -> Result<(Option<Coin>, Vec<Coin>), ContractError> {
+    let fees = name_fee(deps, name)?
+        .into_iter()
+        .map(|fee| -> StdResult<_> {
+            Ok(Coin {
+                amount: fee.amount.checked_mul(years.into())?,
+                ..fee
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    if fees.is_empty() {
+        return Ok((None, funds.to_vec()));
     }
 
-    Ok(())
+    let fee = fees
+        .iter()
+        .find(|fee| {
+            funds
+                .iter()
+                .any(|fund| fund.denom == fee.denom && fund.amount >= fee.amount)
+        })
+        .ok_or_else(|| ContractError::InvalidFee {
+            fee_required: fees.clone(),
+        })?;
+
+    // return the overpaid amount and all other funds
+    let refund = funds
+        .iter()
+        .map(|fund| Coin {
+            amount: if fund.denom == fee.denom {
+                fund.amount - fee.amount
+            } else {
+                fund.amount
+            },
+            denom: fund.denom.clone(),
+        })
+        .filter(|fund| !fund.amount.is_zero())
+        .collect();
+
+    Ok((Some(fee.clone()), refund))
 }
 
 // check_verfying_msg checks if the given verifying message is valid.
@@ -73,14 +266,18 @@ pub fn check_fee(deps: Deps, funds: &[Coin]) -> Result<(), ContractError> {
 // 2. given claimer in verifying msg must match info.sender
 // 3. given contract address in verifying msg must match the current contract address
 // 4. given chain id in verifying msg must match the current chain id
-// 5. given unique twitter id in verifying msg must not have registered yet
+// 5. given identity of the provider in verifying msg must not have registered yet
+// 6. verifying msg must not have expired, and its expiry must be set
+// 7. given nonce in verifying msg must not have used yet
+// returns the parsed verifying message when it passes the check.
 pub fn check_verfying_msg(
     deps: Deps,
     env: &Env,
     info: &MessageInfo,
+    owner: Option<&Addr>,
     name: &str,
     verifying_msg: &str,
-) -> Result<(), ContractError> {
+) -> Result<VerifyingMsg, ContractError> {
     let verifying_msg: VerifyingMsg = from_slice(verifying_msg.as_bytes())?;
     if verifying_msg.name != name {
         return Err(ContractError::InvalidVerifyingMessage {
@@ -98,6 +295,15 @@ pub fn check_verfying_msg(
             ),
         });
     }
+    if verifying_msg.owner.as_deref() != owner.map(Addr::as_str) {
+        return Err(ContractError::InvalidVerifyingMessage {
+            msg: format!(
+                "owner mismatched: expected `{}` but got `{}`",
+                owner.map(Addr::as_str).unwrap_or("none"),
+                verifying_msg.owner.as_deref().unwrap_or("none")
+            ),
+        });
+    }
     if verifying_msg.contract_address != env.contract.address {
         return Err(ContractError::InvalidVerifyingMessage {
             msg: format!(
@@ -115,16 +321,40 @@ pub fn check_verfying_msg(
         });
     }
 
-    // check if unique twitter id is not stored
-    if UNIQUE_TWITTER_ID
-        .may_load(deps.storage, verifying_msg.unique_twitter_id.clone())?
-        .is_some()
-    {
-        return Err(ContractError::DuplicatedTwitterId {
-            msg: format!(
-                "unique twitter id `{}` is already used",
-                verifying_msg.unique_twitter_id
-            ),
+    // check if identity is not stored
+    check_identity_not_bound(deps, &verifying_msg)?;
+
+    // verifications are only valid until the expiry set by the verifiers
+    if matches!(verifying_msg.expiry, Expiration::Never {}) {
+        return Err(ContractError::InvalidVerifyingMessage {
+            msg: "expiry must be set".to_string(),
+        });
+    }
+    if verifying_msg.expiry.is_expired(&env.block) {
+        return Err(ContractError::VerifyingMessageExpired {
+            expiry: verifying_msg.expiry,
+        });
+    }
+
+    // check if nonce is not used
+    if USED_NONCES.has(deps.storage, &verifying_msg.nonce) {
+        return Err(ContractError::NonceAlreadyUsed {
+            nonce: verifying_msg.nonce,
+        });
+    }
+
+    Ok(verifying_msg)
+}
+
+// check_identity_not_bound checks that the identity of the verifying msg is not bound to a name yet.
+fn check_identity_not_bound(deps: Deps, verifying_msg: &VerifyingMsg) -> Result<(), ContractError> {
+    if IDENTITIES.has(
+        deps.storage,
+        (&verifying_msg.provider, &verifying_msg.subject_id),
+    ) {
+        return Err(ContractError::DuplicatedIdentity {
+            provider: verifying_msg.provider.clone(),
+            id: verifying_msg.subject_id.clone(),
         });
     }
 
@@ -132,20 +362,25 @@ pub fn check_verfying_msg(
 }
 
 // check_verification_pass_threshold checks if the given verifications pass the threshold.
-// Errors when the given verifications are not valid, or did not pass the threshold set in the configuration.
+// Errors when the given verifications are not valid, or did not pass the threshold set for the identity provider.
 pub fn check_verification_pass_threshold(
     deps: Deps,
+    provider: &str,
     msg: &str,
     // (public_key, signature)
     verifications: &[(Vec<u8>, Vec<u8>)],
 ) -> Result<(), ContractError> {
-    let config = CONFIG.load(deps.storage)?;
+    let identity_provider = IDENTITY_PROVIDERS
+        .may_load(deps.storage, provider)?
+        .ok_or_else(|| ContractError::UnknownIdentityProvider {
+            provider: provider.to_string(),
+        })?;
 
     // check if verification comes from verifier
     verifications
         .iter()
         .try_for_each(|(pubkey, _)| -> Result<(), ContractError> {
-            config
+            identity_provider
                 .verifier_pubkeys
                 .iter()
                 .find(|verifier| *verifier == pubkey)
@@ -167,30 +402,109 @@ pub fn check_verification_pass_threshold(
         return Err(ContractError::DuplicatedVerification { signature });
     }
 
-    // verify all signatures using secp256k1
-    verifications
+    // check for duplicated signers, since a verifier can produce different signatures for the same message
+    let duplicated_signer = verifications
         .iter()
-        .unique()
-        .try_for_each(|(public_key, signature)| {
-            let msg_hash = sha2::Sha256::digest(msg);
-            let verified = deps
-                .api
-                .secp256k1_verify(&msg_hash, signature, public_key)?;
+        .map(|(public_key, _)| public_key)
+        .duplicates()
+        .next();
 
-            if !verified {
-                return Err(ContractError::InvalidSignature {});
+    if let Some(duplicated_signer) = duplicated_signer {
+        let public_key = Binary(duplicated_signer.to_vec());
+        return Err(ContractError::DuplicatedVerifier { public_key });
+    }
+
+    // verify secp256k1 signatures one by one, and ed25519 signatures in batch
+    let mut ed25519_verifications = vec![];
+    for (public_key, signature) in verifications.iter().unique() {
+        match verifier_key_type(deps.storage, provider, public_key)? {
+            KeyType::Secp256k1 => {
+                let msg_hash = sha2::Sha256::digest(msg);
+                let verified = deps
+                    .api
+                    .secp256k1_verify(&msg_hash, signature, public_key)?;
+
+                if !verified {
+                    return Err(ContractError::InvalidSignature {});
+                }
             }
+            KeyType::Ed25519 => ed25519_verifications.push((public_key, signature)),
+        }
+    }
 
-            Ok(())
-        })?;
+    let verified = match ed25519_verifications.as_slice() {
+        [] => true,
+        [(public_key, signature)] => {
+            deps.api
+                .ed25519_verify(msg.as_bytes(), signature, public_key)?
+        }
+        _ => {
+            let (public_keys, signatures): (Vec<_>, Vec<_>) = ed25519_verifications
+                .iter()
+                .map(|(public_key, signature)| (public_key.as_slice(), signature.as_slice()))
+                .unzip();
+            deps.api
+                .ed25519_batch_verify(&[msg.as_bytes()], &signatures, &public_keys)?
+        }
+    };
+    if !verified {
+        return Err(ContractError::InvalidSignature {});
+    }
 
     // when all signature are valid, no duplicates and all from verifiers, check if it pass threshold
-    let verification_count = verifications.len() as u64;
-    config.check_pass_threshold(Decimal::new(verification_count.into()))?;
+    let passed_weight = verifications
+        .iter()
+        .map(|(pubkey, _)| verifier_weight(deps.storage, provider, pubkey))
+        .sum::<StdResult<u64>>()?;
+    let total_weight = identity_provider.total_weight(deps.storage, provider)?;
+    identity_provider.check_pass_threshold(passed_weight, total_weight)?;
 
     Ok(())
 }
 
+// (public_key, signature)
+type SignedVerification = (Vec<u8>, Vec<u8>);
+
+// verification_signers returns the (public_key, signature) of each verification.
+// If the public key is omitted, the signature must be a recoverable signature,
+// which is the 64 bytes compact signature followed by the recovery id,
+// and the public key of the signer is recovered from it.
+pub fn verification_signers(
+    deps: Deps,
+    msg: &str,
+    verifications: &[Verification],
+) -> Result<Vec<SignedVerification>, ContractError> {
+    verifications
+        .iter()
+        .map(|verification| {
+            if let Some(public_key) = verification.public_key.as_ref() {
+                return Ok((public_key.to_vec(), verification.signature.to_vec()));
+            }
+
+            let (signature, recovery_id) = match verification.signature.as_slice() {
+                [signature @ .., recovery_id] if signature.len() == 64 => (signature, *recovery_id),
+                _ => return Err(ContractError::InvalidRecoverableSignatureLength {}),
+            };
+            let msg_hash = sha2::Sha256::digest(msg);
+            let public_key = deps
+                .api
+                .secp256k1_recover_pubkey(&msg_hash, signature, recovery_id)
+                .map_err(|_| ContractError::InvalidSignature {})?;
+
+            Ok((compress_pubkey(&public_key)?, signature.to_vec()))
+        })
+        .collect()
+}
+
+// compress_pubkey returns the sec1 compressed form of the uncompressed secp256k1 public key,
+// which is how verifier public keys are stored.
+fn compress_pubkey(public_key: &[u8]) -> Result<Vec<u8>, ContractError> {
+    match public_key {
+        [0x04, x @ .., y_last] if x.len() == 63 => Ok([&[0x02 | (y_last & 1)], &x[..32]].concat()),
+        _ => Err(ContractError::InvalidSignature {}),
+    }
+}
+
 // check_valid_threshold checks if the given threshold is valid.
 // returns error when given percent is not between 0 and 100.
 pub fn check_valid_threshold(percent: &Decimal) -> Result<(), ContractError> {
@@ -201,28 +515,220 @@ pub fn check_valid_threshold(percent: &Decimal) -> Result<(), ContractError> {
     }
 }
 
-// check_pubkey_length checks if the given public key is valid.
-pub fn check_pubkey_length(pubkey: &[u8]) -> Result<(), ContractError> {
-    let key_size: usize = 32;
+// check_pubkey_length checks if the given public key is valid for its key type.
+pub fn check_pubkey_length(pubkey: &[u8], key_type: &KeyType) -> Result<(), ContractError> {
+    let key_size: usize = match key_type {
+        // +1 for sec1 tag
+        KeyType::Secp256k1 => 32 + 1,
+        KeyType::Ed25519 => 32,
+    };
 
-    // +1 for sec1 tag
-    if pubkey.len() != key_size + 1 {
+    if pubkey.len() != key_size {
         return Err(ContractError::InvalidPublicKeyLength {});
     }
     Ok(())
 }
 
+// check_verifier_weight checks if the given verifier weight is valid.
+pub fn check_verifier_weight(weight: u64) -> Result<(), ContractError> {
+    if weight == 0 {
+        return Err(ContractError::InvalidVerifierWeight {});
+    }
+    Ok(())
+}
+
+// ClaimChecker decides what happens when a check of a claim fails.
+// `Claim` stops at the first failed check, while simulating the claim collects every failed check.
+pub struct ClaimChecker {
+    failures: Option<Vec<ClaimCheckFailure>>,
+}
+
+impl ClaimChecker {
+    pub fn fail_fast() -> Self {
+        Self { failures: None }
+    }
+
+    pub fn collect_failures() -> Self {
+        Self {
+            failures: Some(vec![]),
+        }
+    }
+
+    pub fn failures(self) -> Vec<ClaimCheckFailure> {
+        self.failures.unwrap_or_default()
+    }
+
+    // check returns the value of the passed check, or `None` if the failure of the check is collected.
+    fn check<T>(
+        &mut self,
+        check: ClaimCheck,
+        result: Result<T, ContractError>,
+    ) -> Result<Option<T>, ContractError> {
+        match (result, self.failures.as_mut()) {
+            (Ok(value), _) => Ok(Some(value)),
+            (Err(err), None) => Err(err),
+            (Err(err), Some(failures)) => {
+                failures.push(ClaimCheckFailure {
+                    check,
+                    error: err.to_string(),
+                });
+                Ok(None)
+            }
+        }
+    }
+}
+
+// CheckedClaim is what the checks of a claim found out about the claim.
+pub struct CheckedClaim {
+    pub recipient: Addr,
+    pub is_admin: bool,
+    pub commitment: Option<Vec<u8>>,
+    pub verifying_msg: Option<VerifyingMsg>,
+    pub paid_fee: Option<Coin>,
+    pub refund: Vec<Coin>,
+}
+
+// check_claim runs the checks of claiming the name by the sender, in the order of `ClaimCheck`.
+// The name is minted to the owner if given, otherwise to the sender.
+#[allow(clippy::too_many_arguments)]
+pub fn check_claim(
+    deps: Deps,
+    env: &Env,
+    info: &MessageInfo,
+    name: &str,
+    verifying_msg_str: &str,
+    verifications: &[Verification],
+    referral: Option<&String>,
+    secret: Option<&Binary>,
+    owner: Option<&Addr>,
+    checker: &mut ClaimChecker,
+) -> Result<CheckedClaim, ContractError> {
+    let recipient = owner.unwrap_or(&info.sender).clone();
+
+    checker.check(ClaimCheck::Paused, check_not_paused(deps))?;
+    checker.check(ClaimCheck::Name, check_valid_name(deps, name))?;
+    checker.check(
+        ClaimCheck::Availability,
+        check_name_available(deps, env, name),
+    )?;
+
+    // if commitment period is set, the claim must have been committed beforehand
+    // so that the name can not be front-run once revealed, even by admins.
+    let commitment = checker
+        .check(
+            ClaimCheck::Commitment,
+            check_commitment(deps, env, &info.sender, name, secret),
+        )?
+        .flatten();
+
+    // auctioned name can only be minted to the winner of the auction
+    checker.check(
+        ClaimCheck::Auction,
+        if AUCTIONS.has(deps.storage, name) {
+            Err(ContractError::NameInAuction {
+                name: name.to_string(),
+            })
+        } else {
+            Ok(())
+        },
+    )?;
+
+    let is_admin = is_admin(deps, &info.sender)?;
+
+    // reserved name can only be claimed by admins or for the assignee of the reservation
+    checker.check(
+        ClaimCheck::Reservation,
+        match RESERVED_NAMES.may_load(deps.storage, name)? {
+            Some(assignee) if !is_admin && assignee.as_ref() != Some(&recipient) => {
+                Err(ContractError::NameReserved {
+                    name: name.to_string(),
+                })
+            }
+            _ => Ok(()),
+        },
+    )?;
+
+    // if not admin, need to pass check verification pass threshold before being able to claim name.
+    // admin claims still can not bind an identity already bound to another name.
+    let verifying_msg = if is_admin {
+        checker.check(
+            ClaimCheck::VerifyingMsg,
+            from_slice::<VerifyingMsg>(verifying_msg_str.as_bytes())
+                .map_err(ContractError::from)
+                .and_then(|verifying_msg| {
+                    check_identity_not_bound(deps, &verifying_msg)?;
+                    Ok(verifying_msg)
+                }),
+        )?
+    } else {
+        let verifying_msg = checker.check(
+            ClaimCheck::VerifyingMsg,
+            check_verfying_msg(deps, env, info, owner, name, verifying_msg_str),
+        )?;
+        // verifications are checked against the provider of the verifying msg,
+        // even if the verifying msg fails its own check.
+        if let Ok(parsed) = from_slice::<VerifyingMsg>(verifying_msg_str.as_bytes()) {
+            checker.check(
+                ClaimCheck::Verifications,
+                verification_signers(deps, verifying_msg_str, verifications).and_then(
+                    |verifications| {
+                        check_verification_pass_threshold(
+                            deps,
+                            &parsed.provider,
+                            verifying_msg_str,
+                            &verifications,
+                        )
+                    },
+                ),
+            )?;
+        }
+        verifying_msg
+    };
+
+    // if referral is set, check referral is an existing icns name
+    if let Some(referral) = referral {
+        checker.check(
+            ClaimCheck::Referral,
+            check_existing_icns_name(deps, referral).map_err(|_| ContractError::InvalidReferral {
+                referral: referral.clone(),
+            }),
+        )?;
+    }
+
+    // check if fees are correctly given.
+    let (paid_fee, refund) = checker
+        .check(ClaimCheck::Fee, check_fee(deps, name, &info.funds, 1))?
+        .unwrap_or_default();
+
+    // names claimed without admin are rate limited
+    if !is_admin {
+        checker.check(
+            ClaimCheck::RateLimit,
+            check_claim_rate_limit(deps, env, &recipient),
+        )?;
+    }
+
+    Ok(CheckedClaim {
+        recipient,
+        is_admin,
+        commitment,
+        verifying_msg,
+        paid_fee,
+        refund,
+    })
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::{
-        state::{Config, CONFIG},
+        state::{IdentityProvider, IDENTITY_PROVIDERS},
         ContractError,
     };
     use cosmrs::{bip32, crypto::secp256k1::SigningKey};
     use cosmwasm_std::{
         testing::{mock_dependencies, mock_env, mock_info},
-        Addr, Coin, Decimal, DepsMut,
+        Decimal, DepsMut,
     };
 
     use crate::tests::helpers::ToBinary;
@@ -247,14 +753,14 @@ mod test {
         let name = "name";
 
         // success case, everything is matched
-        check_verfying_msg(deps.as_ref(), &env, &info, name, &format!(
-            r#"{{"name":"{name}","claimer":"{sender}","contract_address":"{contract_address}","chain_id":"{chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        check_verfying_msg(deps.as_ref(), &env, &info, None, name, &format!(
+            r#"{{"name":"{name}","claimer":"{sender}","contract_address":"{contract_address}","chain_id":"{chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
         )).unwrap();
 
         // name mismatched
         let mismatched_name = "mismatched_name";
-        let err = check_verfying_msg(deps.as_ref(), &env, &info, name, &format!(
-            r#"{{"name":"{mismatched_name}","claimer":"{sender}","contract_address":"{contract_address}","chain_id":"{chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        let err = check_verfying_msg(deps.as_ref(), &env, &info, None, name, &format!(
+            r#"{{"name":"{mismatched_name}","claimer":"{sender}","contract_address":"{contract_address}","chain_id":"{chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
         )).unwrap_err();
 
         assert_eq!(
@@ -269,8 +775,8 @@ mod test {
 
         // claimer is not sender
         let not_a_sender = "not_a_sender";
-        let err = check_verfying_msg(deps.as_ref(),&env, &info, name, &format!(
-            r#"{{"name":"{name}","claimer":"{not_a_sender}","contract_address":"{contract_address}","chain_id":"{chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        let err = check_verfying_msg(deps.as_ref(),&env, &info, None, name, &format!(
+            r#"{{"name":"{name}","claimer":"{not_a_sender}","contract_address":"{contract_address}","chain_id":"{chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
         )).unwrap_err();
 
         assert_eq!(
@@ -285,8 +791,8 @@ mod test {
 
         // wrong contract_address
         let wrong_contract_address = "wrong_contract_address";
-        let err = check_verfying_msg(deps.as_ref(), &env, &info, name, &format!(
-            r#"{{"name":"{name}","claimer":"{sender}","contract_address":"{wrong_contract_address}","chain_id":"{chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        let err = check_verfying_msg(deps.as_ref(), &env, &info, None, name, &format!(
+            r#"{{"name":"{name}","claimer":"{sender}","contract_address":"{wrong_contract_address}","chain_id":"{chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
         )).unwrap_err();
 
         assert_eq!(
@@ -301,8 +807,8 @@ mod test {
 
         // wrong chain_id
         let wrong_chain_id = "wrong_chain_id";
-        let err = check_verfying_msg(deps.as_ref(), &env, &info, name, &format!(
-                    r#"{{"name":"{name}","claimer":"{sender}","contract_address":"{contract_address}","chain_id":"{wrong_chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        let err = check_verfying_msg(deps.as_ref(), &env, &info, None, name, &format!(
+                    r#"{{"name":"{name}","claimer":"{sender}","contract_address":"{contract_address}","chain_id":"{wrong_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
                 )).unwrap_err();
 
         assert_eq!(
@@ -314,6 +820,49 @@ mod test {
                 ),
             }
         );
+
+        // expiry not set
+        let err = check_verfying_msg(deps.as_ref(), &env, &info, None, name, &format!(
+            r#"{{"name":"{name}","claimer":"{sender}","contract_address":"{contract_address}","chain_id":"{chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"never":{{}}}},"nonce":"{unique_twitter_id}"}}"#,
+        )).unwrap_err();
+
+        assert_eq!(
+            err,
+            ContractError::InvalidVerifyingMessage {
+                msg: "expiry must be set".to_string(),
+            }
+        );
+
+        // expired
+        let expired_height = env.block.height;
+        let err = check_verfying_msg(deps.as_ref(), &env, &info, None, name, &format!(
+            r#"{{"name":"{name}","claimer":"{sender}","contract_address":"{contract_address}","chain_id":"{chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":{expired_height}}},"nonce":"{unique_twitter_id}"}}"#,
+        )).unwrap_err();
+
+        assert_eq!(
+            err,
+            ContractError::VerifyingMessageExpired {
+                expiry: Expiration::AtHeight(expired_height),
+            }
+        );
+
+        // nonce already used
+        let mut deps = deps;
+        let nonce = "used_nonce";
+        USED_NONCES
+            .save(deps.as_mut().storage, nonce, &true)
+            .unwrap();
+        let not_expired_time = env.block.time.plus_seconds(1).nanos();
+        let err = check_verfying_msg(deps.as_ref(), &env, &info, None, name, &format!(
+            r#"{{"name":"{name}","claimer":"{sender}","contract_address":"{contract_address}","chain_id":"{chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_time":"{not_expired_time}"}},"nonce":"{nonce}"}}"#,
+        )).unwrap_err();
+
+        assert_eq!(
+            err,
+            ContractError::NonceAlreadyUsed {
+                nonce: nonce.to_string(),
+            }
+        );
     }
 
     #[test]
@@ -336,17 +885,17 @@ mod test {
         };
 
         let set_threshold_pct = |deps: DepsMut, pct: u64| {
-            CONFIG
+            IDENTITY_PROVIDERS
                 .save(
                     deps.storage,
-                    &Config {
-                        name_nft: Addr::unchecked("namenftaddr"),
-                        verifier_pubkeys: vec![verifier1(), verifier2(), verifier3()]
+                    "twitter",
+                    &IdentityProvider {
+                        verifier_pubkeys: [verifier1(), verifier2(), verifier3()]
                             .iter()
                             .map(|sk| sk.to_binary())
                             .collect(),
                         verification_threshold_percentage: Decimal::percent(pct),
-                        fee: Some(Coin::new(100000, "uosmo")),
+                        absolute_verification_threshold: None,
                     },
                 )
                 .unwrap();
@@ -371,6 +920,7 @@ mod test {
         set_threshold_pct(deps.as_mut(), 51);
         check_verification_pass_threshold(
             deps.as_ref(),
+            "twitter",
             msg,
             &sign_all(&[verifier1(), verifier2()], msg),
         )
@@ -379,9 +929,13 @@ mod test {
         // test 1/3 valid signature < 51% should error
         let mut deps = mock_dependencies();
         set_threshold_pct(deps.as_mut(), 51);
-        let err =
-            check_verification_pass_threshold(deps.as_ref(), msg, &sign_all(&[verifier1()], msg))
-                .unwrap_err();
+        let err = check_verification_pass_threshold(
+            deps.as_ref(),
+            "twitter",
+            msg,
+            &sign_all(&[verifier1()], msg),
+        )
+        .unwrap_err();
 
         assert_eq!(
             err,
@@ -396,6 +950,7 @@ mod test {
         set_threshold_pct(deps.as_mut(), 51);
         let err = check_verification_pass_threshold(
             deps.as_ref(),
+            "twitter",
             msg,
             &sign_all(&[verifier1(), non_verifier()], msg),
         )
@@ -414,8 +969,9 @@ mod test {
         let wrong_msg = "wrong msg";
         let err = check_verification_pass_threshold(
             deps.as_ref(),
+            "twitter",
             msg,
-            &vec![
+            &[
                 sign_all(&[verifier1()], msg),
                 sign_all(&[verifier2()], wrong_msg),
             ]
@@ -428,8 +984,8 @@ mod test {
         let mut deps = mock_dependencies();
         set_threshold_pct(deps.as_mut(), 51);
         let verifications = sign_all(&[verifier3(), verifier3()], msg);
-        let err =
-            check_verification_pass_threshold(deps.as_ref(), msg, &verifications).unwrap_err();
+        let err = check_verification_pass_threshold(deps.as_ref(), "twitter", msg, &verifications)
+            .unwrap_err();
         assert_eq!(
             err,
             ContractError::DuplicatedVerification {
@@ -439,22 +995,23 @@ mod test {
 
         // test 2/4 valid signature = 50% should pass
         let mut deps = mock_dependencies();
-        CONFIG
+        IDENTITY_PROVIDERS
             .save(
                 &mut deps.storage,
-                &Config {
-                    name_nft: Addr::unchecked("namenftaddr"),
-                    verifier_pubkeys: vec![verifier1(), verifier2(), verifier3(), verifier4()]
+                "twitter",
+                &IdentityProvider {
+                    verifier_pubkeys: [verifier1(), verifier2(), verifier3(), verifier4()]
                         .iter()
                         .map(|sk| Binary(sk.public_key().to_bytes()))
                         .collect(),
                     verification_threshold_percentage: Decimal::percent(50),
-                    fee: Some(Coin::new(100000, "uosmo")),
+                    absolute_verification_threshold: None,
                 },
             )
             .unwrap();
         check_verification_pass_threshold(
             deps.as_ref(),
+            "twitter",
             msg,
             &sign_all(&[verifier1(), verifier4()], msg),
         )
@@ -462,21 +1019,37 @@ mod test {
 
         // test no verifier set should error
         let mut deps = mock_dependencies();
-        CONFIG
+        IDENTITY_PROVIDERS
             .save(
                 &mut deps.storage,
-                &Config {
-                    name_nft: Addr::unchecked("namenftaddr"),
+                "twitter",
+                &IdentityProvider {
                     verifier_pubkeys: vec![],
                     verification_threshold_percentage: Decimal::percent(50),
-                    fee: Some(Coin::new(100000, "uosmo")),
+                    absolute_verification_threshold: None,
                 },
             )
             .unwrap();
 
         let err =
-            check_verification_pass_threshold(deps.as_ref(), msg, &sign_all(&[], msg)).unwrap_err();
+            check_verification_pass_threshold(deps.as_ref(), "twitter", msg, &sign_all(&[], msg))
+                .unwrap_err();
         assert_eq!(err, ContractError::NoVerifier {});
+
+        // test unknown identity provider should error
+        let err = check_verification_pass_threshold(
+            deps.as_ref(),
+            "github",
+            msg,
+            &sign_all(&[verifier1()], msg),
+        )
+        .unwrap_err();
+        assert_eq!(
+            err,
+            ContractError::UnknownIdentityProvider {
+                provider: "github".to_string()
+            }
+        );
     }
 
     #[test]
@@ -484,14 +1057,22 @@ mod test {
         let private_key = SigningKey::random();
 
         // generated pubkey should pass
-        check_pubkey_length(&private_key.public_key().to_bytes()).unwrap();
+        check_pubkey_length(&private_key.public_key().to_bytes(), &KeyType::Secp256k1).unwrap();
 
         // invalid pubkey length should fail
         let err = check_pubkey_length(
-            &vec![private_key.public_key().to_bytes().to_vec(), vec![0u8]].concat(),
+            &[private_key.public_key().to_bytes().to_vec(), vec![0u8]].concat(),
+            &KeyType::Secp256k1,
         )
         .unwrap_err();
 
         assert_eq!(err, ContractError::InvalidPublicKeyLength {});
+
+        // ed25519 pubkey has no sec1 tag
+        check_pubkey_length(&[1u8; 32], &KeyType::Ed25519).unwrap();
+        let err = check_pubkey_length(&private_key.public_key().to_bytes(), &KeyType::Ed25519)
+            .unwrap_err();
+
+        assert_eq!(err, ContractError::InvalidPublicKeyLength {});
     }
 }
@@ -1,26 +1,62 @@
 #[cfg(not(feature = "library"))]
 use cosmwasm_std::entry_point;
 use cosmwasm_std::{
-    attr, from_slice, to_binary, BankMsg, Binary, Coin, Decimal, Deps, DepsMut, Env, MessageInfo,
-    Response, StdError, StdResult, WasmMsg,
+    attr, to_binary, Addr, BankMsg, Binary, Coin, Decimal, Deps, DepsMut, Env, Event, MessageInfo,
+    Order, Response, StdError, StdResult, Storage, Timestamp, WasmMsg,
 };
 use cw2::set_contract_version;
-use icns_name_nft::msg::Metadata;
+use cw_storage_plus::Bound;
+use icns_name_nft::msg::{ICNSNameExecuteMsg, IdentityRef, Metadata};
 use icns_name_nft::MintMsg;
 use itertools::Itertools;
 
+use crate::auction::{
+    execute_cancel_auction, execute_place_bid, execute_reveal_bid, execute_settle_auction,
+    execute_start_auction, execute_withdraw_bid, query_auction, query_bid,
+};
 use crate::checks::{
-    check_admin, check_existing_icns_name, check_fee, check_pubkey_length, check_valid_threshold,
-    check_verfying_msg, check_verification_pass_threshold, is_admin,
+    check_admin, check_claim, check_fee, check_name_available, check_pubkey_length,
+    check_valid_threshold, check_verifier_weight, is_guardian, name_fee, name_owner, CheckedClaim,
+    ClaimChecker,
 };
 use crate::error::ContractError;
 use crate::msg::{
-    ExecuteMsg, FeeResponse, InstantiateMsg, MigrateMsg, NameByTwitterIdResponse,
-    NameNftAddressResponse, QueryMsg, ReferralCountResponse, Verification,
-    VerificationThresholdResponse, VerifierPubKeysResponse, VerifyingMsg,
+    AdminClaimEntry, CommitmentPeriodResponse, CommitmentResponse, ExecuteMsg, ExpiryResponse,
+    FeeResponse, Identity, IdentityProviderInfo, IdentityProvidersResponse, InstantiateMsg,
+    IsReservedResponse, MigrateMsg, NameByIdentityResponse, NameByTwitterIdResponse, NameFee,
+    NameNftAddressResponse, PausedResponse, PriceResponse, QueryMsg, ReferralCount,
+    ReferralCountResponse, ReferralLeaderboardResponse, ReferralRewardShareResponse,
+    ReferralRewardsResponse, ReferredNamesResponse, RegistrationPeriodResponse, ReservedName,
+    ReservedNamesResponse, Verification, VerificationThresholdResponse, VerifierPubKey,
+    VerifierPubKeysResponse,
+};
+
+use crate::identity::{
+    execute_rebind_identity, execute_release_identity, query_identity_by_name,
+    query_twitter_id_by_name,
+};
+use crate::migrations::{
+    migrate_fee_distributions, migrate_name_identities, migrate_referral_index,
+    migrate_twitter_identities,
+};
+use crate::rate_limit::{
+    execute_set_claim_rate_limit, query_claim_quota, query_claim_rate_limit, record_claim,
+};
+use crate::simulation::query_simulate_claim;
+use crate::state::{
+    bind_identity, referrals, verifier_key_type, verifier_weight, ClaimRateLimit, CommitmentPeriod,
+    Config, IdentityProvider, KeyType, LengthTierFee, AUCTIONS, COMMITMENTS, CONFIG, IDENTITIES,
+    IDENTITY_PROVIDERS, NAME_EXPIRY, NAME_FEE, REFERRAL_REWARDS, REFERRED_NAMES, RESERVED_NAMES,
+    SECONDS_PER_YEAR, TWITTER_PROVIDER, USED_NONCES, VERIFIER_KEY_TYPES, VERIFIER_WEIGHTS,
+};
+use crate::treasury::{
+    collect_fee, execute_distribute_fees, execute_set_treasury, query_fee_distributions,
+    query_treasury, withdraw_fees,
 };
 
-use crate::state::{Config, CONFIG, REFERRAL, UNIQUE_TWITTER_ID};
+// default and maximum number of items returned by paginated queries
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 100;
 
 // version info for migration info
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -39,22 +75,25 @@ pub fn instantiate(
     // validate name nft address
     let name_nft_addr = deps.api.addr_validate(&msg.name_nft_addr)?;
 
-    // check each verififying key if there is invalid key
-    msg.verifier_pubkeys
-        .iter()
-        .try_for_each(|pubkey| check_pubkey_length(pubkey).map(|_| ()))?;
-
-    // check if threshold is valid (0.0-1.0)
-    check_valid_threshold(&msg.verification_threshold)?;
+    // save each identity provider along with its verifiers and threshold
+    msg.identity_providers
+        .into_iter()
+        .try_for_each(|identity_provider| add_identity_provider(deps.storage, identity_provider))?;
 
     // save all configs
     CONFIG.save(
         deps.storage,
         &Config {
             name_nft: name_nft_addr,
-            verifier_pubkeys: msg.verifier_pubkeys,
-            verification_threshold_percentage: msg.verification_threshold,
-            fee: msg.fee,
+            fees: msg.fees,
+            registration_years: None,
+            length_tier_fees: vec![],
+            referral_reward_share: Decimal::zero(),
+            commitment_period: None,
+            treasury: vec![],
+            auto_distribute_fees: false,
+            claim_rate_limit: ClaimRateLimit::default(),
+            paused: false,
         },
     )?;
 
@@ -77,6 +116,8 @@ pub fn execute(
             verifying_msg,
             verifications,
             referral,
+            secret,
+            owner,
         } => execute_claim(
             deps,
             env,
@@ -85,17 +126,97 @@ pub fn execute(
             verifying_msg,
             verifications,
             referral,
+            secret,
+            owner,
         ),
-        ExecuteMsg::SetVerificationThreshold { threshold } => {
-            execute_set_verification_threshold(deps, info, threshold)
+        ExecuteMsg::CommitClaim { commitment } => execute_commit_claim(deps, env, info, commitment),
+        ExecuteMsg::SetCommitmentPeriod { period } => {
+            execute_set_commitment_period(deps, info, period)
         }
-        ExecuteMsg::UpdateVerifierPubkeys { add, remove } => {
-            execute_update_verifier_pubkeys(deps, info, add, remove)
+        ExecuteMsg::AddIdentityProvider { identity_provider } => {
+            execute_add_identity_provider(deps, info, identity_provider)
         }
+        ExecuteMsg::RemoveIdentityProvider { provider } => {
+            execute_remove_identity_provider(deps, info, provider)
+        }
+        ExecuteMsg::SetVerificationThreshold {
+            provider,
+            threshold,
+        } => execute_set_verification_threshold(deps, info, provider, threshold),
+        ExecuteMsg::SetAbsoluteVerificationThreshold {
+            provider,
+            threshold,
+        } => execute_set_absolute_verification_threshold(deps, info, provider, threshold),
+        ExecuteMsg::UpdateVerifierPubkeys {
+            provider,
+            add,
+            remove,
+        } => execute_update_verifier_pubkeys(deps, info, provider, add, remove),
         ExecuteMsg::SetNameNftAddress { name_nft_address } => {
             execute_set_name_nft_address(deps, info, name_nft_address)
         }
-        ExecuteMsg::SetMintingFee { minting_fee: fee } => execute_set_fee(deps, info, fee),
+        ExecuteMsg::UpdateAcceptedFees { add, remove } => {
+            execute_update_accepted_fees(deps, info, add, remove)
+        }
+        ExecuteMsg::SetLengthTierFees { tiers } => execute_set_length_tier_fees(deps, info, tiers),
+        ExecuteMsg::SetNameFees { fees } => execute_set_name_fees(deps, info, fees),
+        ExecuteMsg::Renew { name, years } => execute_renew(deps, env, info, name, years),
+        ExecuteMsg::SetRegistrationPeriod { years } => {
+            execute_set_registration_period(deps, info, years)
+        }
+        ExecuteMsg::SetReferralRewardShare { share } => {
+            execute_set_referral_reward_share(deps, info, share)
+        }
+        ExecuteMsg::ClaimReferralRewards { name } => {
+            execute_claim_referral_rewards(deps, info, name)
+        }
+        ExecuteMsg::ReserveNames { names } => execute_reserve_names(deps, info, names),
+        ExecuteMsg::UnreserveNames { names } => execute_unreserve_names(deps, info, names),
+        ExecuteMsg::AdminBatchClaim { entries } => {
+            execute_admin_batch_claim(deps, env, info, entries)
+        }
+        ExecuteMsg::StartAuction {
+            name,
+            denom,
+            min_bid,
+            bid_duration,
+            reveal_duration,
+        } => execute_start_auction(
+            deps,
+            env,
+            info,
+            name,
+            denom,
+            min_bid,
+            bid_duration,
+            reveal_duration,
+        ),
+        ExecuteMsg::PlaceBid { name, sealed_bid } => {
+            execute_place_bid(deps, env, info, name, sealed_bid)
+        }
+        ExecuteMsg::RevealBid { name, amount, salt } => {
+            execute_reveal_bid(deps, env, info, name, amount, salt)
+        }
+        ExecuteMsg::SettleAuction { name } => execute_settle_auction(deps, env, name),
+        ExecuteMsg::WithdrawBid { name } => execute_withdraw_bid(deps, env, info, name),
+        ExecuteMsg::CancelAuction { name } => execute_cancel_auction(deps, info, name),
+        ExecuteMsg::SetPaused { paused } => execute_set_paused(deps, info, paused),
+        ExecuteMsg::SetClaimRateLimit { rate_limit } => {
+            execute_set_claim_rate_limit(deps, info, rate_limit)
+        }
+        ExecuteMsg::SetTreasury {
+            recipients,
+            auto_distribute,
+        } => execute_set_treasury(deps, info, recipients, auto_distribute),
+        ExecuteMsg::DistributeFees {} => execute_distribute_fees(deps, env),
+        ExecuteMsg::ReleaseIdentity { name, provider } => {
+            execute_release_identity(deps, info, name, provider)
+        }
+        ExecuteMsg::RebindIdentity {
+            name,
+            verifying_msg,
+            verifications,
+        } => execute_rebind_identity(deps, env, info, name, verifying_msg, verifications),
         ExecuteMsg::WithdrawFunds { amount, to_address } => {
             execute_withdraw_funds(deps, info, amount, to_address)
         }
@@ -103,12 +224,21 @@ pub fn execute(
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // No state migrations performed, just returned a Response
+pub fn migrate(deps: DepsMut, env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    // move verifiers and twitter ids of the contracts claiming with twitter only to "twitter" provider
+    migrate_twitter_identities(deps.storage)?;
+    // index identities by name for the contracts claimed names before the index is introduced
+    migrate_name_identities(deps.storage)?;
+    // index referral counts by count for the contracts counted referrals before the index is introduced
+    migrate_referral_index(deps.storage)?;
+    // track fees held by the contracts collected fees before fee distributions are tracked
+    migrate_fee_distributions(deps, &env)?;
+
     Ok(Response::default())
 }
 
 // executes the claiming process for the icns name and nft.
+#[allow(clippy::too_many_arguments)]
 pub fn execute_claim(
     deps: DepsMut,
     env: Env,
@@ -117,81 +247,496 @@ pub fn execute_claim(
     verifying_msg_str: String,
     verifications: Vec<Verification>,
     referral: Option<String>,
+    secret: Option<Binary>,
+    owner: Option<String>,
 ) -> Result<Response, ContractError> {
-    let is_admin = is_admin(deps.as_ref(), &info.sender)?;
+    // name can be claimed on behalf of another owner, who receives the name nft
+    let owner = owner
+        .map(|owner| deps.api.addr_validate(&owner))
+        .transpose()?;
 
-    // if not admin, need to pass check verification pass threshold before being able to claim name
-    if !is_admin {
-        check_verfying_msg(deps.as_ref(), &env, &info, &name, &verifying_msg_str)?;
-        // Client creates `verfifying_msg` and send to verifiers to get verifications
-        // with thier signatures. Then accumulates and send those signatures to this
-        // contract via `Claim` message.
-        // given verifications(signatures), check if the given verifications are valid and they pass the threshold set in config.
-        // only the verifiers in the config can sign the verfifying_msg.
-        check_verification_pass_threshold(
-            deps.as_ref(),
-            &verifying_msg_str,
-            &verifications
-                .iter()
-                .map(|verification| {
-                    Ok((
-                        verification.public_key.to_vec(),
-                        verification.signature.to_vec(),
-                    ))
-                })
-                .collect::<StdResult<Vec<_>>>()?,
-        )?;
-    }
+    let CheckedClaim {
+        recipient,
+        is_admin,
+        commitment,
+        verifying_msg,
+        mut paid_fee,
+        refund,
+    } = check_claim(
+        deps.as_ref(),
+        &env,
+        &info,
+        &name,
+        &verifying_msg_str,
+        &verifications,
+        referral.as_ref(),
+        secret.as_ref(),
+        owner.as_ref(),
+        &mut ClaimChecker::fail_fast(),
+    )?;
+    // all checks have passed, so the verifying msg is known to be valid
+    let verifying_msg = verifying_msg.ok_or_else(|| ContractError::InvalidVerifyingMessage {
+        msg: "verifying msg is not checked".to_string(),
+    })?;
 
-    // if referral is set, check referral is an existing icns name
-    if let Some(referral) = referral.as_ref() {
-        check_existing_icns_name(deps.as_ref(), referral).map_err(|_| {
-            ContractError::InvalidReferral {
-                referral: referral.clone(),
-            }
-        })?;
+    if let Some(commitment) = commitment {
+        COMMITMENTS.remove(deps.storage, (&info.sender, &commitment));
+    }
+    if !is_admin {
+        record_claim(deps.storage, &env, &recipient)?;
     }
 
-    // check if fees are correctly given.
-    check_fee(deps.as_ref(), &info.funds)?;
+    let config = CONFIG.load(deps.storage)?;
 
     // add referral count if referral is set
     if let Some(referral) = referral.clone() {
         // initialize referral count to 1 if not exists
-        REFERRAL.update(deps.storage, referral, |referral_count| -> StdResult<_> {
-            Ok(referral_count.unwrap_or(0) + 1)
-        })?;
+        referrals().update(
+            deps.storage,
+            referral.clone(),
+            |referral_count| -> StdResult<_> { Ok(referral_count.unwrap_or(0) + 1) },
+        )?;
+        REFERRED_NAMES.save(deps.storage, (&referral, &name), &true)?;
+
+        // credit share of the paid fee to the referrer name
+        let reward = paid_fee.as_ref().map(|fee| Coin {
+            amount: fee.amount * config.referral_reward_share,
+            denom: fee.denom.clone(),
+        });
+        if let Some(reward) = reward.filter(|reward| !reward.amount.is_zero()) {
+            // referral reward is not collected for the treasury
+            if let Some(fee) = paid_fee.as_mut() {
+                fee.amount -= reward.amount;
+            }
+            REFERRAL_REWARDS.update(deps.storage, referral, |rewards| -> StdResult<_> {
+                let mut rewards = rewards.unwrap_or_default();
+                rewards.credit(reward);
+                Ok(rewards)
+            })?;
+        }
     }
 
-    // save unique_twitter_id to storage to prevent duplicate claim for single user.
-    let verifying_msg: VerifyingMsg = from_slice(verifying_msg_str.as_bytes())?;
-    UNIQUE_TWITTER_ID.save(deps.storage, verifying_msg.unique_twitter_id, &name)?;
+    // save identity to storage to prevent duplicate claim for single user.
+    bind_identity(
+        deps.storage,
+        &verifying_msg.provider,
+        &verifying_msg.subject_id,
+        &name,
+    )?;
+    // mark nonce as used to prevent the verifications from being replayed.
+    USED_NONCES.save(deps.storage, &verifying_msg.nonce, &true)?;
+
+    let fee_msgs = match paid_fee {
+        Some(fee) => collect_fee(deps.storage, &env, &fee)?,
+        None => vec![],
+    };
 
-    // mint name nft
+    let mint_msg = mint_name_msg(
+        deps,
+        &env,
+        &name,
+        &recipient,
+        &info.sender,
+        Metadata {
+            referral,
+            identity: Some(IdentityRef {
+                provider: verifying_msg.provider,
+                subject_id: verifying_msg.subject_id,
+            }),
+            ..Default::default()
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "claim")
+        .add_attribute("name", name)
+        .add_attribute("owner", recipient)
+        .add_message(mint_msg)
+        .add_messages(refund_msg(&info.sender, refund))
+        .add_messages(fee_msgs))
+}
+
+// mint_name_msg returns message for minting the name nft to the owner with the given metadata.
+// The claimer is recorded as the original claimer of the name.
+// Expiry of the name is set if registration period is set.
+pub(crate) fn mint_name_msg(
+    deps: DepsMut,
+    env: &Env,
+    name: &str,
+    owner: &Addr,
+    claimer: &Addr,
+    metadata: Metadata,
+) -> Result<WasmMsg, ContractError> {
     let config = CONFIG.load(deps.storage)?;
 
+    // set expiry of the name if registration period is set
+    let expiry = config
+        .registration_years
+        .map(|years| env.block.time.plus_seconds(years * SECONDS_PER_YEAR));
+    // expiry of the previous registration must not outlive the name being claimed again
+    match expiry {
+        Some(expiry) => NAME_EXPIRY.save(deps.storage, name.to_string(), &expiry)?,
+        None => NAME_EXPIRY.remove(deps.storage, name.to_string()),
+    }
+
     // set minter of `icns-name-nft` to this contract
     // so that only this contract can mint name nft
-    let mint_msg = WasmMsg::Execute {
+    Ok(WasmMsg::Execute {
         contract_addr: config.name_nft.to_string(),
         msg: to_binary(&icns_name_nft::ExecuteMsg::Mint(MintMsg {
-            token_id: name.clone(),
-            owner: info.sender.to_string(),
+            token_id: name.to_string(),
+            owner: owner.to_string(),
             token_uri: None,
-            extension: Metadata { referral },
+            extension: Metadata {
+                expiry,
+                original_claimer: Some(claimer.to_string()),
+                ..metadata
+            },
         }))?,
         funds: vec![],
+    })
+}
+
+// refund_msg returns message for sending back the given funds to the sender, if any.
+pub(crate) fn refund_msg(sender: &Addr, refund: Vec<Coin>) -> Option<BankMsg> {
+    if refund.is_empty() {
+        return None;
+    }
+
+    Some(BankMsg::Send {
+        to_address: sender.to_string(),
+        amount: refund,
+    })
+}
+
+// execute_reserve_names reserves the names, optionally assigned to an address.
+fn execute_reserve_names(
+    deps: DepsMut,
+    info: MessageInfo,
+    names: Vec<ReservedName>,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    for ReservedName { name, assignee } in names.iter() {
+        let assignee = assignee
+            .as_ref()
+            .map(|assignee| deps.api.addr_validate(assignee))
+            .transpose()?;
+        RESERVED_NAMES.save(deps.storage, name, &assignee)?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "reserve_names")
+        .add_attribute(
+            "names",
+            names
+                .into_iter()
+                .map(|ReservedName { name, .. }| name)
+                .join(","),
+        ))
+}
+
+// execute_unreserve_names removes reservation of the names.
+fn execute_unreserve_names(
+    deps: DepsMut,
+    info: MessageInfo,
+    names: Vec<String>,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    for name in names.iter() {
+        RESERVED_NAMES.remove(deps.storage, name);
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "unreserve_names")
+        .add_attribute("names", names.join(",")))
+}
+
+// execute_admin_batch_claim mints the names to their owners, skipping verifications and fees.
+// Identities of the entries are bound to the names to prevent them from claiming another name.
+fn execute_admin_batch_claim(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    entries: Vec<AdminClaimEntry>,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    let mut res = Response::new().add_attribute("method", "admin_batch_claim");
+    for AdminClaimEntry {
+        name,
+        owner,
+        identity,
+    } in entries
+    {
+        let owner = deps.api.addr_validate(&owner)?;
+        check_name_available(deps.as_ref(), &env, &name)?;
+        if AUCTIONS.has(deps.storage, &name) {
+            return Err(ContractError::NameInAuction { name });
+        }
+
+        let mut event = Event::new("admin_claim")
+            .add_attribute("name", &name)
+            .add_attribute("owner", &owner);
+
+        if let Some(Identity { provider, id }) = identity.as_ref() {
+            if !IDENTITY_PROVIDERS.has(deps.storage, provider) {
+                return Err(ContractError::UnknownIdentityProvider {
+                    provider: provider.clone(),
+                });
+            }
+            if IDENTITIES.has(deps.storage, (provider, id)) {
+                return Err(ContractError::DuplicatedIdentity {
+                    provider: provider.clone(),
+                    id: id.clone(),
+                });
+            }
+            bind_identity(deps.storage, provider, id, &name)?;
+            event = event
+                .add_attribute("provider", provider)
+                .add_attribute("id", id);
+        }
+
+        res = res
+            .add_message(mint_name_msg(
+                deps.branch(),
+                &env,
+                &name,
+                &owner,
+                &info.sender,
+                Metadata {
+                    identity: identity.map(|Identity { provider, id }| IdentityRef {
+                        provider,
+                        subject_id: id,
+                    }),
+                    ..Default::default()
+                },
+            )?)
+            .add_event(event);
+    }
+
+    Ok(res)
+}
+
+// execute_commit_claim stores the commitment of the sender with the current block height.
+// Committing again with the same commitment restarts the commitment period.
+fn execute_commit_claim(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    commitment: Binary,
+) -> Result<Response, ContractError> {
+    COMMITMENTS.save(
+        deps.storage,
+        (&info.sender, commitment.as_slice()),
+        &env.block.height,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "commit_claim")
+        .add_attribute("committer", info.sender)
+        .add_attribute("commitment", commitment.to_base64()))
+}
+
+// execute_set_paused pauses or unpauses claiming names.
+// Guardians can only pause, while admins can also unpause.
+fn execute_set_paused(
+    deps: DepsMut,
+    info: MessageInfo,
+    paused: bool,
+) -> Result<Response, ContractError> {
+    if !(paused && is_guardian(deps.as_ref(), &info.sender)?) {
+        check_admin(deps.as_ref(), &info.sender)?;
+    }
+
+    CONFIG.update(deps.storage, |config| -> StdResult<_> {
+        Ok(Config { paused, ..config })
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_paused")
+        .add_attribute("paused", paused.to_string()))
+}
+
+// execute_set_commitment_period sets the number of blocks within which a claim must be revealed.
+fn execute_set_commitment_period(
+    deps: DepsMut,
+    info: MessageInfo,
+    period: Option<CommitmentPeriod>,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    if let Some(period) = period.as_ref() {
+        if period.max_blocks < period.min_blocks {
+            return Err(ContractError::InvalidCommitmentPeriod {
+                msg: "max blocks must not be less than min blocks".to_string(),
+            });
+        }
+    }
+
+    let attrs = vec![
+        attr("method", "set_commitment_period"),
+        attr(
+            "commitment_period",
+            period
+                .as_ref()
+                .map(|period| format!("{}-{}", period.min_blocks, period.max_blocks))
+                .unwrap_or_else(|| "none".to_string()),
+        ),
+    ];
+
+    CONFIG.update(deps.storage, |config| -> StdResult<_> {
+        Ok(Config {
+            commitment_period: period,
+            ..config
+        })
+    })?;
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+// execute_renew extends the registration of the name by the given number of years.
+// Anyone can renew the name as long as the fee for each year is paid.
+pub fn execute_renew(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    years: u64,
+) -> Result<Response, ContractError> {
+    if years == 0 {
+        return Err(ContractError::InvalidRenewalYears {});
+    }
+
+    let expiry = NAME_EXPIRY
+        .may_load(deps.storage, name.clone())?
+        .ok_or_else(|| ContractError::NotRenewable { name: name.clone() })?;
+
+    // expired name is released and must be claimed again
+    if expiry <= env.block.time {
+        return Err(ContractError::NameExpired { name });
+    }
+
+    // check if fees are correctly given.
+    let (paid_fee, refund) = check_fee(deps.as_ref(), &name, &info.funds, years)?;
+    let fee_msgs = match paid_fee {
+        Some(fee) => collect_fee(deps.storage, &env, &fee)?,
+        None => vec![],
+    };
+
+    let expiry = expiry.plus_seconds(years * SECONDS_PER_YEAR);
+    NAME_EXPIRY.save(deps.storage, name.clone(), &expiry)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let set_expiry_msg = WasmMsg::Execute {
+        contract_addr: config.name_nft.to_string(),
+        msg: to_binary(&icns_name_nft::ExecuteMsg::Extension {
+            msg: ICNSNameExecuteMsg::SetExpiry {
+                token_id: name.clone(),
+                expiry: Some(expiry),
+            },
+        })?,
+        funds: vec![],
     };
 
     Ok(Response::new()
-        .add_attribute("method", "claim")
+        .add_attribute("method", "renew")
         .add_attribute("name", name)
-        .add_message(mint_msg))
+        .add_attribute("expiry", expiry.to_string())
+        .add_message(set_expiry_msg)
+        .add_messages(refund_msg(&info.sender, refund))
+        .add_messages(fee_msgs))
+}
+
+// execute_set_referral_reward_share sets the share of the claim fee credited to the referrer name.
+fn execute_set_referral_reward_share(
+    deps: DepsMut,
+    info: MessageInfo,
+    share: Decimal,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    if share > Decimal::one() {
+        return Err(ContractError::InvalidReferralRewardShare {});
+    }
+
+    CONFIG.update(deps.storage, |config| -> StdResult<_> {
+        Ok(Config {
+            referral_reward_share: share,
+            ..config
+        })
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_referral_reward_share")
+        .add_attribute("share", share.to_string()))
+}
+
+// execute_claim_referral_rewards sends pending referral rewards of the name to its current owner.
+fn execute_claim_referral_rewards(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+) -> Result<Response, ContractError> {
+    // rewards belong to whoever owns the referrer name at the time of claiming
+    if name_owner(deps.as_ref(), &name)? != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let mut rewards = REFERRAL_REWARDS
+        .may_load(deps.storage, name.clone())?
+        .unwrap_or_default();
+    let amount = rewards.claim();
+    if amount.is_empty() {
+        return Err(ContractError::NoReferralRewards { name });
+    }
+    REFERRAL_REWARDS.save(deps.storage, name.clone(), &rewards)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "claim_referral_rewards")
+        .add_attribute("name", name)
+        .add_attribute("amount", amount.iter().join(","))
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount,
+        }))
+}
+
+// execute_set_registration_period sets the number of years a claimed name is registered for.
+// This only applies to names claimed after the change.
+fn execute_set_registration_period(
+    deps: DepsMut,
+    info: MessageInfo,
+    registration_years: Option<u64>,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    if registration_years == Some(0) {
+        return Err(ContractError::InvalidRenewalYears {});
+    }
+
+    CONFIG.update(deps.storage, |config| -> StdResult<_> {
+        Ok(Config {
+            registration_years,
+            ..config
+        })
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_registration_period")
+        .add_attribute(
+            "registration_years",
+            registration_years
+                .map(|years| years.to_string())
+                .unwrap_or_else(|| "never expire".to_string()),
+        ))
 }
 
 fn execute_set_verification_threshold(
     deps: DepsMut,
     info: MessageInfo,
+    provider: String,
     verification_threshold: Decimal,
 ) -> Result<Response, ContractError> {
     // check if sender is admin. Only admin can set verification threshold
@@ -199,48 +744,216 @@ fn execute_set_verification_threshold(
 
     let attrs = vec![
         attr("method", "set_verification_threshold"),
+        attr("provider", &provider),
         attr("verfication_threshold", verification_threshold.to_string()),
     ];
 
     // sanity check on the given threshold
     check_valid_threshold(&verification_threshold)?;
 
-    CONFIG.update(deps.storage, |config| -> StdResult<_> {
-        Ok(Config {
+    update_identity_provider(deps.storage, &provider, |identity_provider| {
+        Ok(IdentityProvider {
             verification_threshold_percentage: verification_threshold,
-            ..config
+            ..identity_provider
         })
     })?;
 
     Ok(Response::new().add_attributes(attrs))
 }
 
-// execute_update_verifier_pubkeys updates the list of verifier pubkeys.
+// execute_set_absolute_verification_threshold sets the total weight of verification required.
+fn execute_set_absolute_verification_threshold(
+    deps: DepsMut,
+    info: MessageInfo,
+    provider: String,
+    threshold: Option<u64>,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    if threshold == Some(0) {
+        return Err(ContractError::InvalidAbsoluteThreshold {});
+    }
+
+    update_identity_provider(deps.storage, &provider, |identity_provider| {
+        Ok(IdentityProvider {
+            absolute_verification_threshold: threshold,
+            ..identity_provider
+        })
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_absolute_verification_threshold")
+        .add_attribute("provider", provider)
+        .add_attribute(
+            "absolute_verification_threshold",
+            threshold
+                .map(|threshold| threshold.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+        ))
+}
+
+// execute_update_verifier_pubkeys updates the list of verifier pubkeys of the identity provider and their weights.
 fn execute_update_verifier_pubkeys(
     deps: DepsMut,
     info: MessageInfo,
-    add: Vec<Binary>,
+    provider: String,
+    add: Vec<VerifierPubKey>,
     remove: Vec<Binary>,
 ) -> Result<Response, ContractError> {
     check_admin(deps.as_ref(), &info.sender)?;
 
-    CONFIG.update(deps.storage, |config| -> Result<_, ContractError> {
-        Ok(Config {
-            verifier_pubkeys: vec![config.verifier_pubkeys, add]
-                .concat()
-                .into_iter()
-                .filter(|v| !remove.contains(v))
-                .unique()
-                .map(|verifier_pubkey| {
-                    check_pubkey_length(verifier_pubkey.as_slice())?;
-                    Ok(verifier_pubkey)
-                })
-                .collect::<Result<_, ContractError>>()?,
-            ..config
+    for verifier in add.iter().filter(|v| !remove.contains(&v.pubkey)) {
+        save_verifier(deps.storage, &provider, verifier)?;
+    }
+
+    for pubkey in remove.iter() {
+        VERIFIER_WEIGHTS.remove(deps.storage, (&provider, pubkey));
+        VERIFIER_KEY_TYPES.remove(deps.storage, (&provider, pubkey));
+    }
+
+    update_identity_provider(deps.storage, &provider, |identity_provider| {
+        Ok(IdentityProvider {
+            verifier_pubkeys: [
+                identity_provider.verifier_pubkeys,
+                add.into_iter().map(|verifier| verifier.pubkey).collect(),
+            ]
+            .concat()
+            .into_iter()
+            .filter(|v| !remove.contains(v))
+            .unique()
+            .collect(),
+            ..identity_provider
         })
     })?;
 
-    Ok(Response::new().add_attribute("method", "update_verifier_pubkeys"))
+    Ok(Response::new()
+        .add_attribute("method", "update_verifier_pubkeys")
+        .add_attribute("provider", provider))
+}
+
+// execute_add_identity_provider adds an identity provider that names can be claimed with.
+fn execute_add_identity_provider(
+    deps: DepsMut,
+    info: MessageInfo,
+    identity_provider: IdentityProviderInfo,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    let provider = identity_provider.provider.clone();
+    add_identity_provider(deps.storage, identity_provider)?;
+
+    Ok(Response::new()
+        .add_attribute("method", "add_identity_provider")
+        .add_attribute("provider", provider))
+}
+
+// execute_remove_identity_provider removes an identity provider along with its verifiers.
+// Identities already bound to names are kept.
+fn execute_remove_identity_provider(
+    deps: DepsMut,
+    info: MessageInfo,
+    provider: String,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    let identity_provider = IDENTITY_PROVIDERS
+        .may_load(deps.storage, &provider)?
+        .ok_or_else(|| ContractError::UnknownIdentityProvider {
+            provider: provider.clone(),
+        })?;
+
+    for pubkey in identity_provider.verifier_pubkeys.iter() {
+        VERIFIER_WEIGHTS.remove(deps.storage, (&provider, pubkey));
+        VERIFIER_KEY_TYPES.remove(deps.storage, (&provider, pubkey));
+    }
+    IDENTITY_PROVIDERS.remove(deps.storage, &provider);
+
+    Ok(Response::new()
+        .add_attribute("method", "remove_identity_provider")
+        .add_attribute("provider", provider))
+}
+
+// save_verifier validates and saves the weight and key type of the verifier of the identity provider.
+fn save_verifier(
+    storage: &mut dyn Storage,
+    provider: &str,
+    verifier: &VerifierPubKey,
+) -> Result<(), ContractError> {
+    check_pubkey_length(&verifier.pubkey, &verifier.key_type)?;
+    check_verifier_weight(verifier.weight)?;
+    VERIFIER_WEIGHTS.save(storage, (provider, &verifier.pubkey), &verifier.weight)?;
+    VERIFIER_KEY_TYPES.save(storage, (provider, &verifier.pubkey), &verifier.key_type)?;
+    Ok(())
+}
+
+// add_identity_provider validates and saves a new identity provider with the weights of its verifiers.
+fn add_identity_provider(
+    storage: &mut dyn Storage,
+    identity_provider: IdentityProviderInfo,
+) -> Result<(), ContractError> {
+    let IdentityProviderInfo {
+        provider,
+        verifier_pubkeys,
+        verification_threshold,
+    } = identity_provider;
+
+    if IDENTITY_PROVIDERS.has(storage, &provider) {
+        return Err(ContractError::IdentityProviderAlreadyExists { provider });
+    }
+
+    // check each verififying key if there is invalid key or weight
+    verifier_pubkeys
+        .iter()
+        .try_for_each(|verifier| save_verifier(storage, &provider, verifier))?;
+
+    // check if threshold is valid (0.0-1.0)
+    check_valid_threshold(&verification_threshold)?;
+
+    IDENTITY_PROVIDERS.save(
+        storage,
+        &provider,
+        &IdentityProvider {
+            verifier_pubkeys: verifier_pubkeys
+                .into_iter()
+                .map(|verifier| verifier.pubkey)
+                .unique()
+                .collect(),
+            verification_threshold_percentage: verification_threshold,
+            absolute_verification_threshold: None,
+        },
+    )?;
+
+    Ok(())
+}
+
+// update_identity_provider updates the existing identity provider, errors if the provider does not exist.
+fn update_identity_provider(
+    storage: &mut dyn Storage,
+    provider: &str,
+    update: impl FnOnce(IdentityProvider) -> Result<IdentityProvider, ContractError>,
+) -> Result<(), ContractError> {
+    let identity_provider = IDENTITY_PROVIDERS
+        .may_load(storage, provider)?
+        .ok_or_else(|| ContractError::UnknownIdentityProvider {
+            provider: provider.to_string(),
+        })?;
+
+    let identity_provider = update(identity_provider)?;
+
+    // absolute threshold must stay reachable by the verifiers of the provider
+    if let Some(threshold) = identity_provider.absolute_verification_threshold {
+        let total_weight = identity_provider.total_weight(storage, provider)?;
+        if threshold > total_weight {
+            return Err(ContractError::AbsoluteThresholdAboveTotalWeight {
+                threshold,
+                total_weight,
+            });
+        }
+    }
+
+    IDENTITY_PROVIDERS.save(storage, provider, &identity_provider)?;
+
+    Ok(())
 }
 
 // execute_withdraw_funds withdraws accumulated funds from fees.
@@ -253,6 +966,10 @@ fn execute_withdraw_funds(
     // check if the sender is admin. If not, return error.
     check_admin(deps.as_ref(), &info.sender)?;
     deps.api.addr_validate(&to_address)?;
+
+    // only the pending collected fees can be withdrawn, and they are no longer distributed to the treasury
+    withdraw_fees(deps.storage, &amount)?;
+
     let attrs = vec![
         attr("method", "withraw_funds"),
         attr("to_address", &to_address),
@@ -271,32 +988,99 @@ fn execute_withdraw_funds(
         .add_message(BankMsg::Send { to_address, amount }))
 }
 
-// execute_set_fee sets the fee for claiming a name.
-// This can either be a fixed amount or None.
-fn execute_set_fee(
+// execute_update_accepted_fees updates the list of fees accepted for claiming a name.
+// Only one fee per denom is accepted, adding an accepted denom replaces its amount.
+fn execute_update_accepted_fees(
+    deps: DepsMut,
+    info: MessageInfo,
+    add: Vec<Coin>,
+    remove: Vec<String>,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    let config = CONFIG.load(deps.storage)?;
+    let fees: Vec<Coin> = config
+        .fees
+        .into_iter()
+        .filter(|fee| !add.iter().any(|added| added.denom == fee.denom))
+        .chain(add.iter().cloned().unique_by(|fee| fee.denom.clone()))
+        .filter(|fee| !remove.contains(&fee.denom))
+        .collect();
+
+    let attrs = vec![
+        attr("method", "update_accepted_fees"),
+        attr("fees", fees.iter().join(",")),
+    ];
+
+    CONFIG.save(deps.storage, &Config { fees, ..config })?;
+
+    Ok(Response::new().add_attributes(attrs))
+}
+
+// execute_set_length_tier_fees replaces the fees for names by their length.
+fn execute_set_length_tier_fees(
     deps: DepsMut,
     info: MessageInfo,
-    fee: Option<Coin>,
+    tiers: Vec<LengthTierFee>,
 ) -> Result<Response, ContractError> {
     check_admin(deps.as_ref(), &info.sender)?;
 
+    if tiers.iter().any(|tier| tier.max_length == 0) {
+        return Err(ContractError::InvalidLengthTier {
+            msg: "max length must be greater than 0".to_string(),
+        });
+    }
+
+    if let Some(max_length) = tiers.iter().map(|tier| tier.max_length).duplicates().next() {
+        return Err(ContractError::InvalidLengthTier {
+            msg: format!("max length `{}` is duplicated", max_length),
+        });
+    }
+
     let attrs = vec![
-        attr("method", "set_fee"),
+        attr("method", "set_length_tier_fees"),
         attr(
-            "fee",
-            fee.as_ref()
-                .map(|fee| fee.to_string())
-                .unwrap_or_else(|| "no fee".to_string()),
+            "tiers",
+            tiers
+                .iter()
+                .map(|tier| format!("{}:{}", tier.max_length, tier.fees.iter().join("|")))
+                .join(","),
         ),
     ];
 
     CONFIG.update(deps.storage, |config| -> StdResult<_> {
-        Ok(Config { fee, ..config })
+        Ok(Config {
+            length_tier_fees: tiers,
+            ..config
+        })
     })?;
 
     Ok(Response::new().add_attributes(attrs))
 }
 
+// execute_set_name_fees sets or removes fees for specific names.
+fn execute_set_name_fees(
+    deps: DepsMut,
+    info: MessageInfo,
+    fees: Vec<NameFee>,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    for NameFee { name, fees } in fees.iter() {
+        match fees {
+            Some(fees) => NAME_FEE.save(deps.storage, name.clone(), fees)?,
+            None => NAME_FEE.remove(deps.storage, name.clone()),
+        }
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "set_name_fees")
+        .add_attribute(
+            "names",
+            fees.into_iter().map(|NameFee { name, .. }| name).join(","),
+        ))
+}
+
 // execute_set_name_nft_address sets the address of the name nft contract.
 // This is used to mint name nft when a name is claimed.
 fn execute_set_name_nft_address(
@@ -317,95 +1101,329 @@ fn execute_set_name_nft_address(
         .add_attribute("name_nft_address", name_nft_address))
 }
 
-// execute_add_verifier adds an verifier to the list of verifiers
+// execute_add_verifier adds an verifier to the list of verifiers of the identity provider
 pub fn execute_add_verifier(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    provider: String,
     verifier_pubkey: Binary,
 ) -> Result<Response, ContractError> {
     let attrs = vec![
         attr("method", "add_verifier"),
+        attr("provider", &provider),
         attr("verifier", verifier_pubkey.to_base64()),
     ];
 
     check_admin(deps.as_ref(), &info.sender)?;
     let adding_verifier = verifier_pubkey;
-    check_pubkey_length(&adding_verifier)?;
+    save_verifier(
+        deps.storage,
+        &provider,
+        &VerifierPubKey {
+            pubkey: adding_verifier.clone(),
+            weight: 1,
+            key_type: KeyType::Secp256k1,
+        },
+    )?;
 
-    CONFIG.update(deps.storage, |config| -> StdResult<_> {
-        Ok(Config {
-            verifier_pubkeys: vec![config.verifier_pubkeys, vec![adding_verifier]].concat(),
-            ..config
+    update_identity_provider(deps.storage, &provider, |identity_provider| {
+        Ok(IdentityProvider {
+            verifier_pubkeys: [identity_provider.verifier_pubkeys, vec![adding_verifier]].concat(),
+            ..identity_provider
         })
     })?;
 
     Ok(Response::new().add_attributes(attrs))
 }
 
-// execute_remove_verifier removes an verifier from the list of verifiers
+// execute_remove_verifier removes an verifier from the list of verifiers of the identity provider
 pub fn execute_remove_verifier(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    provider: String,
     verifier_pubkey: Binary,
 ) -> Result<Response, ContractError> {
     check_admin(deps.as_ref(), &info.sender)?;
     let removing_verifier = verifier_pubkey.to_vec();
-    check_pubkey_length(&removing_verifier)?;
+    check_pubkey_length(
+        &removing_verifier,
+        &verifier_key_type(deps.storage, &provider, &removing_verifier)?,
+    )?;
+    VERIFIER_WEIGHTS.remove(deps.storage, (&provider, &removing_verifier));
+    VERIFIER_KEY_TYPES.remove(deps.storage, (&provider, &removing_verifier));
 
-    CONFIG.update(deps.storage, |config| -> StdResult<_> {
-        Ok(Config {
-            verifier_pubkeys: config
+    update_identity_provider(deps.storage, &provider, |identity_provider| {
+        Ok(IdentityProvider {
+            verifier_pubkeys: identity_provider
                 .verifier_pubkeys
                 .into_iter()
                 .filter(|v| *v != removing_verifier)
                 .collect(),
-            ..config
+            ..identity_provider
         })
     })?;
 
     Ok(Response::new()
         .add_attribute("method", "remove_verifier")
+        .add_attribute("provider", provider)
         .add_attribute("verifier", verifier_pubkey.to_base64()))
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn query(deps: Deps, _env: Env, msg: QueryMsg) -> Result<Binary, StdError> {
+pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> Result<Binary, StdError> {
     match msg {
-        QueryMsg::VerifierPubKeys {} => to_binary(&VerifierPubKeysResponse {
-            verifier_pubkeys: CONFIG.load(deps.storage)?.verifier_pubkeys,
-        }),
-        QueryMsg::VerificationThreshold {} => to_binary(&VerificationThresholdResponse {
-            verification_threshold_percentage: CONFIG
-                .load(deps.storage)?
-                .verification_threshold_percentage,
-        }),
+        QueryMsg::IdentityProviders {} => to_binary(&query_identity_providers(deps)?),
+        QueryMsg::VerifierPubKeys { provider } => {
+            to_binary(&query_verifier_pubkeys(deps, provider)?)
+        }
+        QueryMsg::VerificationThreshold { provider } => {
+            let identity_provider = IDENTITY_PROVIDERS.load(deps.storage, &provider)?;
+            to_binary(&VerificationThresholdResponse {
+                verification_threshold_percentage: identity_provider
+                    .verification_threshold_percentage,
+                absolute_verification_threshold: identity_provider.absolute_verification_threshold,
+            })
+        }
         QueryMsg::NameNftAddress {} => to_binary(&NameNftAddressResponse {
             name_nft_address: CONFIG.load(deps.storage)?.name_nft.to_string(),
         }),
         QueryMsg::ReferralCount { name } => to_binary(&query_referral_count(deps, name)?),
+        QueryMsg::ReferralLeaderboard { start_after, limit } => {
+            to_binary(&query_referral_leaderboard(deps, start_after, limit)?)
+        }
+        QueryMsg::ReferredNames {
+            referrer,
+            start_after,
+            limit,
+        } => to_binary(&query_referred_names(deps, referrer, start_after, limit)?),
+        QueryMsg::CommitmentPeriod {} => to_binary(&CommitmentPeriodResponse {
+            period: CONFIG.load(deps.storage)?.commitment_period,
+        }),
+        QueryMsg::Commitment {
+            committer,
+            commitment,
+        } => to_binary(&query_commitment(deps, committer, commitment)?),
+        QueryMsg::ReferralRewardShare {} => to_binary(&ReferralRewardShareResponse {
+            share: CONFIG.load(deps.storage)?.referral_reward_share,
+        }),
+        QueryMsg::ReferralRewards { name } => to_binary(&query_referral_rewards(deps, name)?),
+        QueryMsg::IsReserved { name } => to_binary(&query_is_reserved(deps, name)?),
+        QueryMsg::ReservedNames { start_after, limit } => {
+            to_binary(&query_reserved_names(deps, start_after, limit)?)
+        }
+        QueryMsg::Auction { name } => to_binary(&query_auction(deps, name)?),
+        QueryMsg::Bid { name, bidder } => to_binary(&query_bid(deps, name, bidder)?),
+        QueryMsg::SimulateClaim {
+            name,
+            claimer,
+            verifying_msg,
+            verifications,
+            referral,
+            secret,
+            owner,
+            funds,
+        } => to_binary(&query_simulate_claim(
+            deps,
+            env,
+            name,
+            claimer,
+            verifying_msg,
+            verifications,
+            referral,
+            secret,
+            owner,
+            funds,
+        )?),
         QueryMsg::Fee {} => to_binary(&query_fee(deps)?),
+        QueryMsg::Paused {} => to_binary(&PausedResponse {
+            paused: CONFIG.load(deps.storage)?.paused,
+        }),
+        QueryMsg::ClaimRateLimit {} => to_binary(&query_claim_rate_limit(deps)?),
+        QueryMsg::ClaimQuota { address } => to_binary(&query_claim_quota(deps, env, address)?),
+        QueryMsg::Treasury {} => to_binary(&query_treasury(deps)?),
+        QueryMsg::FeeDistributions {} => to_binary(&query_fee_distributions(deps)?),
+        QueryMsg::Price { name } => to_binary(&query_price(deps, name)?),
+        QueryMsg::NameByIdentity { provider, id } => {
+            to_binary(&query_name_by_identity(deps, provider, id)?)
+        }
         QueryMsg::NameByTwitterId { twitter_id } => {
             to_binary(&query_name_by_twitter_id(deps, twitter_id)?)
         }
+        QueryMsg::IdentityByName { name, provider } => {
+            to_binary(&query_identity_by_name(deps, name, provider)?)
+        }
+        QueryMsg::TwitterIdByName { name } => to_binary(&query_twitter_id_by_name(deps, name)?),
+        QueryMsg::Expiry { name } => to_binary(&query_expiry(deps, env.block.time, name)?),
+        QueryMsg::RegistrationPeriod {} => to_binary(&RegistrationPeriodResponse {
+            years: CONFIG.load(deps.storage)?.registration_years,
+        }),
     }
 }
 
+fn query_identity_providers(deps: Deps) -> StdResult<IdentityProvidersResponse> {
+    let providers = IDENTITY_PROVIDERS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+
+    Ok(IdentityProvidersResponse { providers })
+}
+
+fn query_verifier_pubkeys(deps: Deps, provider: String) -> StdResult<VerifierPubKeysResponse> {
+    let verifier_pubkeys = IDENTITY_PROVIDERS
+        .load(deps.storage, &provider)?
+        .verifier_pubkeys
+        .into_iter()
+        .map(|pubkey| {
+            Ok(VerifierPubKey {
+                weight: verifier_weight(deps.storage, &provider, &pubkey)?,
+                key_type: verifier_key_type(deps.storage, &provider, &pubkey)?,
+                pubkey,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    Ok(VerifierPubKeysResponse { verifier_pubkeys })
+}
+
+fn query_is_reserved(deps: Deps, name: String) -> StdResult<IsReservedResponse> {
+    let reservation = RESERVED_NAMES.may_load(deps.storage, &name)?;
+    Ok(IsReservedResponse {
+        reserved: reservation.is_some(),
+        assignee: reservation.flatten().map(|assignee| assignee.to_string()),
+    })
+}
+
+fn query_reserved_names(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ReservedNamesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    let names = RESERVED_NAMES
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| {
+            let (name, assignee) = item?;
+            Ok(ReservedName {
+                name,
+                assignee: assignee.map(|assignee| assignee.to_string()),
+            })
+        })
+        .collect::<StdResult<_>>()?;
+
+    Ok(ReservedNamesResponse { names })
+}
+
+fn query_expiry(deps: Deps, now: Timestamp, name: String) -> StdResult<ExpiryResponse> {
+    let expiry = NAME_EXPIRY.may_load(deps.storage, name)?;
+    Ok(ExpiryResponse {
+        expiry,
+        expired: expiry.map(|expiry| expiry <= now).unwrap_or(false),
+    })
+}
+
+fn query_name_by_identity(
+    deps: Deps,
+    provider: String,
+    id: String,
+) -> StdResult<NameByIdentityResponse> {
+    Ok(NameByIdentityResponse {
+        name: IDENTITIES.load(deps.storage, (&provider, &id))?,
+    })
+}
+
 fn query_name_by_twitter_id(deps: Deps, twitter_id: String) -> StdResult<NameByTwitterIdResponse> {
     Ok(NameByTwitterIdResponse {
-        name: UNIQUE_TWITTER_ID.load(deps.storage, twitter_id)?,
+        name: IDENTITIES.load(deps.storage, (TWITTER_PROVIDER, &twitter_id))?,
     })
 }
 
 fn query_fee(deps: Deps) -> StdResult<FeeResponse> {
     let config = CONFIG.load(deps.storage)?;
-    Ok(FeeResponse { fee: config.fee })
+    Ok(FeeResponse { fees: config.fees })
+}
+
+fn query_price(deps: Deps, name: String) -> StdResult<PriceResponse> {
+    Ok(PriceResponse {
+        price: name_fee(deps, &name)?,
+    })
 }
 
 fn query_referral_count(deps: Deps, name: String) -> StdResult<ReferralCountResponse> {
-    let count = REFERRAL.may_load(deps.storage, name)?;
+    let count = referrals().may_load(deps.storage, name)?;
     Ok(ReferralCountResponse {
         count: count.unwrap_or_default(),
     })
 }
+
+// query_referral_leaderboard returns referrers ordered by referral count from the highest,
+// and by name in descending order among referrers with the same count.
+fn query_referral_leaderboard(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ReferralLeaderboardResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let end = start_after
+        .map(|name| -> StdResult<_> {
+            let count = referrals().load(deps.storage, name.clone())?;
+            Ok(Bound::exclusive((count, name)))
+        })
+        .transpose()?;
+
+    let referrals = referrals()
+        .idx
+        .count
+        .range(deps.storage, None, end, Order::Descending)
+        .take(limit)
+        .map(|item| {
+            let (name, count) = item?;
+            Ok(ReferralCount { name, count })
+        })
+        .collect::<StdResult<_>>()?;
+
+    Ok(ReferralLeaderboardResponse { referrals })
+}
+
+fn query_referred_names(
+    deps: Deps,
+    referrer: String,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ReferredNamesResponse> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+
+    let names = REFERRED_NAMES
+        .prefix(&referrer)
+        .keys(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<_>>()?;
+
+    Ok(ReferredNamesResponse { names })
+}
+
+fn query_referral_rewards(deps: Deps, name: String) -> StdResult<ReferralRewardsResponse> {
+    let rewards = REFERRAL_REWARDS
+        .may_load(deps.storage, name)?
+        .unwrap_or_default();
+    Ok(ReferralRewardsResponse {
+        pending: rewards.pending,
+        paid: rewards.paid,
+    })
+}
+
+fn query_commitment(
+    deps: Deps,
+    committer: String,
+    commitment: Binary,
+) -> StdResult<CommitmentResponse> {
+    let committer = deps.api.addr_validate(&committer)?;
+    Ok(CommitmentResponse {
+        height: COMMITMENTS.may_load(deps.storage, (&committer, commitment.as_slice()))?,
+    })
+}
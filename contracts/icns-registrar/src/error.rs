@@ -1,5 +1,6 @@
 use cosmwasm_std::{Binary, Coin, Decimal, StdError, VerificationError};
-use cw_utils::ThresholdError;
+use cw_utils::{Expiration, ThresholdError};
+use icns_name_nft::msg::NameViolation;
 use thiserror::Error;
 
 #[derive(Error, Debug, PartialEq)]
@@ -25,6 +26,12 @@ pub enum ContractError {
     #[error("Invalid name: {name:?}")]
     InvalidName { name: String },
 
+    #[error("Name {name:?} is not allowed: {violation}")]
+    NameNotAllowed {
+        name: String,
+        violation: NameViolation,
+    },
+
     #[error("Verifier already exists")]
     VerifierAlreadyExists {},
 
@@ -48,11 +55,42 @@ pub enum ContractError {
         actual: Decimal,
     },
 
+    #[error(
+        "Valid verfication weight is below threshold: expected at least {expected_at_least} but got {actual}"
+    )]
+    ValidVerificationWeightIsBelowThreshold { expected_at_least: u64, actual: u64 },
+
+    #[error("Invalid verifier weight, must be greater than 0")]
+    InvalidVerifierWeight {},
+
+    #[error("Invalid absolute verification threshold, must be greater than 0")]
+    InvalidAbsoluteThreshold {},
+
+    #[error(
+        "Absolute verification threshold exceeds total verifier weight: threshold {threshold} but total weight {total_weight}"
+    )]
+    AbsoluteThresholdAboveTotalWeight { threshold: u64, total_weight: u64 },
+
     #[error("Invalid verifying message: {msg}")]
     InvalidVerifyingMessage { msg: String },
 
-    #[error("User already registered")]
-    DuplicatedTwitterId { msg: String },
+    #[error("Verifying message has expired: {expiry}")]
+    VerifyingMessageExpired { expiry: Expiration },
+
+    #[error("Verifying message nonce `{nonce}` is already used")]
+    NonceAlreadyUsed { nonce: String },
+
+    #[error("Identity `{id}` of `{provider}` is already used")]
+    DuplicatedIdentity { provider: String, id: String },
+
+    #[error("Unknown identity provider `{provider}`")]
+    UnknownIdentityProvider { provider: String },
+
+    #[error("No identity of `{provider}` is bound to `{name}`")]
+    NoIdentityBound { name: String, provider: String },
+
+    #[error("Identity provider `{provider}` already exists")]
+    IdentityProviderAlreadyExists { provider: String },
 
     #[error("No verifier set")]
     NoVerifier {},
@@ -60,12 +98,116 @@ pub enum ContractError {
     #[error("Verification signatures must be unique: `{signature}` is duplicated")]
     DuplicatedVerification { signature: Binary },
 
+    #[error("Verifications must come from unique verifiers: `{public_key}` signed more than once")]
+    DuplicatedVerifier { public_key: Binary },
+
+    #[error("Recoverable signature must be 64 bytes signature followed by 1 byte recovery id")]
+    InvalidRecoverableSignatureLength {},
+
     #[error("Invalid voting threshold percentage, must be in the 0-1.0 range")]
     InvalidThreshold {},
 
-    #[error("Invalid fee: required exactly {fee_required}")]
-    InvalidFee { fee_required: Coin },
+    #[error("Invalid fee: required one of {fee_required:?}")]
+    InvalidFee { fee_required: Vec<Coin> },
 
     #[error("Invalid referral: `{referral}` must be an ICNS name")]
     InvalidReferral { referral: String },
+
+    #[error("Name `{name}` does not expire and can not be renewed")]
+    NotRenewable { name: String },
+
+    #[error("Name `{name}` has already expired")]
+    NameExpired { name: String },
+
+    #[error("Invalid renewal period: years must be greater than 0")]
+    InvalidRenewalYears {},
+
+    #[error("Invalid length tier: {msg}")]
+    InvalidLengthTier { msg: String },
+
+    #[error("Invalid referral reward share, must be in the 0-1.0 range")]
+    InvalidReferralRewardShare {},
+
+    #[error("No pending referral rewards for `{name}`")]
+    NoReferralRewards { name: String },
+
+    #[error("Invalid claim rate limit: {msg}")]
+    InvalidClaimRateLimit { msg: String },
+
+    #[error("`{address}` has reached the limit of {limit} claimed names")]
+    AddressClaimLimitExceeded { address: String, limit: u64 },
+
+    #[error("Limit of {limit} names claimed within {blocks} blocks is reached")]
+    WindowClaimLimitExceeded { limit: u64, blocks: u64 },
+
+    #[error("Invalid treasury: {msg}")]
+    InvalidTreasury { msg: String },
+
+    #[error("No treasury recipients to distribute fees to")]
+    NoTreasury {},
+
+    #[error("No pending fees to distribute")]
+    NoPendingFees {},
+
+    #[error("Withdrawal of {requested} exceeds the pending fees of {pending}")]
+    InsufficientPendingFees { requested: Coin, pending: Coin },
+
+    #[error("Invalid commitment period: {msg}")]
+    InvalidCommitmentPeriod { msg: String },
+
+    #[error("Claim must be committed before being revealed with a secret")]
+    CommitmentRequired {},
+
+    #[error("No commitment found for claiming `{name}`")]
+    CommitmentNotFound { name: String },
+
+    #[error("Commitment can not be revealed until block height {reveal_height}")]
+    CommitmentNotRevealable { reveal_height: u64 },
+
+    #[error("Commitment has expired at block height {expired_height}")]
+    CommitmentExpired { expired_height: u64 },
+
+    #[error("Name `{name}` is reserved")]
+    NameReserved { name: String },
+
+    #[error("Name `{name}` is already claimed")]
+    NameAlreadyClaimed { name: String },
+
+    #[error("Name `{name}` is being auctioned")]
+    NameInAuction { name: String },
+
+    #[error("No auction for name `{name}`")]
+    AuctionNotFound { name: String },
+
+    #[error("Invalid auction: {msg}")]
+    InvalidAuction { msg: String },
+
+    #[error("Bidding for `{name}` has ended")]
+    BiddingEnded { name: String },
+
+    #[error(
+        "Bids for `{name}` can only be revealed between the end of bidding and the end of reveal"
+    )]
+    NotRevealPeriod { name: String },
+
+    #[error("Auction for `{name}` can not be settled before the end of reveal")]
+    AuctionNotEnded { name: String },
+
+    #[error("Bid for `{name}` has already been placed")]
+    BidAlreadyPlaced { name: String },
+
+    #[error("No bid for `{name}` placed by the sender")]
+    BidNotFound { name: String },
+
+    #[error("Invalid bid: {msg}")]
+    InvalidBid { msg: String },
+
+    #[error("Bid for `{name}` can not be withdrawn before the end of reveal")]
+    BidNotWithdrawable { name: String },
+
+    #[error("Highest bid for `{name}` can not be withdrawn")]
+    HighestBidNotWithdrawable { name: String },
+
+    #[error("Contract is paused")]
+    Paused {},
 }
@@ -0,0 +1,116 @@
+use cosmwasm_std::{from_slice, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+
+use crate::checks::{
+    check_verfying_msg, check_verification_pass_threshold, is_admin, name_owner,
+    verification_signers,
+};
+use crate::msg::{IdentityByNameResponse, TwitterIdByNameResponse, Verification, VerifyingMsg};
+use crate::state::{
+    bind_identity, release_identity, IDENTITIES, IDENTITY_PROVIDERS, NAME_IDENTITIES,
+    TWITTER_PROVIDER, USED_NONCES,
+};
+use crate::ContractError;
+
+// execute_release_identity removes the binding between the name and its identity of the provider,
+// so that the identity can be used to claim another name.
+pub fn execute_release_identity(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+    provider: String,
+) -> Result<Response, ContractError> {
+    if name_owner(deps.as_ref(), &name)? != info.sender {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let id = release_identity(deps.storage, &provider, &name)?.ok_or_else(|| {
+        ContractError::NoIdentityBound {
+            name: name.clone(),
+            provider: provider.clone(),
+        }
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "release_identity")
+        .add_attribute("name", name)
+        .add_attribute("provider", provider)
+        .add_attribute("id", id))
+}
+
+// execute_rebind_identity binds the identity attested by the verifiers in the verifying msg to the name,
+// releasing the identity of the same provider previously bound to the name.
+// Admins can rebind the identity without verifications.
+pub fn execute_rebind_identity(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    name: String,
+    verifying_msg_str: String,
+    verifications: Vec<Verification>,
+) -> Result<Response, ContractError> {
+    let is_admin = is_admin(deps.as_ref(), &info.sender)?;
+    if name_owner(deps.as_ref(), &name)? != info.sender && !is_admin {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let verifying_msg: VerifyingMsg = from_slice(verifying_msg_str.as_bytes())?;
+    if !IDENTITY_PROVIDERS.has(deps.storage, &verifying_msg.provider) {
+        return Err(ContractError::UnknownIdentityProvider {
+            provider: verifying_msg.provider,
+        });
+    }
+    if IDENTITIES.has(
+        deps.storage,
+        (&verifying_msg.provider, &verifying_msg.subject_id),
+    ) {
+        return Err(ContractError::DuplicatedIdentity {
+            provider: verifying_msg.provider,
+            id: verifying_msg.subject_id,
+        });
+    }
+
+    if !is_admin {
+        check_verfying_msg(deps.as_ref(), &env, &info, None, &name, &verifying_msg_str)?;
+        check_verification_pass_threshold(
+            deps.as_ref(),
+            &verifying_msg.provider,
+            &verifying_msg_str,
+            &verification_signers(deps.as_ref(), &verifying_msg_str, &verifications)?,
+        )?;
+        USED_NONCES.save(deps.storage, &verifying_msg.nonce, &true)?;
+    }
+
+    let released_id = release_identity(deps.storage, &verifying_msg.provider, &name)?;
+    bind_identity(
+        deps.storage,
+        &verifying_msg.provider,
+        &verifying_msg.subject_id,
+        &name,
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("method", "rebind_identity")
+        .add_attribute("name", name)
+        .add_attribute("provider", verifying_msg.provider)
+        .add_attribute(
+            "released_id",
+            released_id.unwrap_or_else(|| "none".to_string()),
+        )
+        .add_attribute("id", verifying_msg.subject_id))
+}
+
+pub fn query_identity_by_name(
+    deps: Deps,
+    name: String,
+    provider: String,
+) -> StdResult<IdentityByNameResponse> {
+    Ok(IdentityByNameResponse {
+        id: NAME_IDENTITIES.load(deps.storage, (&name, &provider))?,
+    })
+}
+
+pub fn query_twitter_id_by_name(deps: Deps, name: String) -> StdResult<TwitterIdByNameResponse> {
+    Ok(TwitterIdByNameResponse {
+        twitter_id: NAME_IDENTITIES.load(deps.storage, (&name, TWITTER_PROVIDER))?,
+    })
+}
@@ -1,8 +1,14 @@
+mod auction;
 mod checks;
 pub mod contract;
 mod error;
+mod identity;
+mod migrations;
 pub mod msg;
+mod rate_limit;
+mod simulation;
 pub mod state;
+mod treasury;
 
 pub use crate::error::ContractError;
 
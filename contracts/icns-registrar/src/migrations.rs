@@ -0,0 +1,375 @@
+use cosmwasm_schema::cw_serde;
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, DepsMut, Env, Order, StdResult, Storage, Uint128};
+use cw_storage_plus::{Item, Map};
+
+use crate::state::{
+    referrals, ClaimRateLimit, CommitmentPeriod, Config, IdentityProvider, LengthTierFee, AUCTIONS,
+    CONFIG, FEE_DISTRIBUTIONS, IDENTITIES, IDENTITY_PROVIDERS, NAME_IDENTITIES, REFERRAL_REWARDS,
+    SEALED_BIDS, TWITTER_PROVIDER, VERIFIER_WEIGHTS,
+};
+
+/// Config of the contracts claiming names with twitter only,
+/// where verifiers and threshold are set in the config.
+#[cw_serde]
+pub struct LegacyConfig {
+    pub name_nft: Addr,
+    pub verifier_pubkeys: Vec<Binary>,
+    pub verification_threshold_percentage: Decimal,
+    #[serde(default)]
+    pub absolute_verification_threshold: Option<u64>,
+    /// single fee of the contracts accepting only one fee
+    #[serde(default)]
+    pub fee: Option<Coin>,
+    #[serde(default)]
+    pub fees: Vec<Coin>,
+    #[serde(default)]
+    pub registration_years: Option<u64>,
+    #[serde(default)]
+    pub length_tier_fees: Vec<LengthTierFee>,
+    #[serde(default)]
+    pub referral_reward_share: Decimal,
+    #[serde(default)]
+    pub commitment_period: Option<CommitmentPeriod>,
+}
+
+pub const LEGACY_CONFIG: Item<LegacyConfig> = Item::new("config");
+// unique_twitter_id => claimed_name
+pub const LEGACY_UNIQUE_TWITTER_ID: Map<String, String> = Map::new("unique_twitter_id");
+// verifier pubkey => weight of the verification signed by the verifier
+pub const LEGACY_VERIFIER_WEIGHTS: Map<&[u8], u64> = Map::new("verifier_weights");
+
+// migrate_twitter_identities moves verifiers and threshold in the config to "twitter" identity provider,
+// and unique twitter ids to identities of "twitter" provider.
+// Does nothing if the contract is already migrated.
+pub fn migrate_twitter_identities(storage: &mut dyn Storage) -> StdResult<()> {
+    if CONFIG.load(storage).is_ok() {
+        return Ok(());
+    }
+
+    let legacy_config = LEGACY_CONFIG.load(storage)?;
+
+    // move verifiers and their weights to twitter provider
+    for pubkey in legacy_config.verifier_pubkeys.iter() {
+        if let Some(weight) = LEGACY_VERIFIER_WEIGHTS.may_load(storage, pubkey)? {
+            VERIFIER_WEIGHTS.save(storage, (TWITTER_PROVIDER, pubkey), &weight)?;
+            LEGACY_VERIFIER_WEIGHTS.remove(storage, pubkey);
+        }
+    }
+    IDENTITY_PROVIDERS.save(
+        storage,
+        TWITTER_PROVIDER,
+        &IdentityProvider {
+            verifier_pubkeys: legacy_config.verifier_pubkeys,
+            verification_threshold_percentage: legacy_config.verification_threshold_percentage,
+            absolute_verification_threshold: legacy_config.absolute_verification_threshold,
+        },
+    )?;
+
+    CONFIG.save(
+        storage,
+        &Config {
+            name_nft: legacy_config.name_nft,
+            fees: if legacy_config.fees.is_empty() {
+                legacy_config.fee.into_iter().collect()
+            } else {
+                legacy_config.fees
+            },
+            registration_years: legacy_config.registration_years,
+            length_tier_fees: legacy_config.length_tier_fees,
+            referral_reward_share: legacy_config.referral_reward_share,
+            commitment_period: legacy_config.commitment_period,
+            treasury: vec![],
+            auto_distribute_fees: false,
+            claim_rate_limit: ClaimRateLimit::default(),
+            paused: false,
+        },
+    )?;
+
+    // move unique twitter ids to identities of twitter provider
+    let twitter_ids = LEGACY_UNIQUE_TWITTER_ID
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (twitter_id, name) in twitter_ids {
+        IDENTITIES.save(storage, (TWITTER_PROVIDER, &twitter_id), &name)?;
+        LEGACY_UNIQUE_TWITTER_ID.remove(storage, twitter_id);
+    }
+
+    Ok(())
+}
+
+// migrate_name_identities indexes the identities bound to names by name.
+pub fn migrate_name_identities(storage: &mut dyn Storage) -> StdResult<()> {
+    let identities = IDENTITIES
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for ((provider, subject_id), name) in identities {
+        NAME_IDENTITIES.save(storage, (&name, &provider), &subject_id)?;
+    }
+
+    Ok(())
+}
+
+// migrate_referral_index indexes the referral counts by count for the referral leaderboard.
+pub fn migrate_referral_index(storage: &mut dyn Storage) -> StdResult<()> {
+    let referral_counts = referrals()
+        .range(storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for (name, count) in referral_counts {
+        referrals().save(storage, name, &count)?;
+    }
+
+    Ok(())
+}
+
+// migrate_fee_distributions records the balance held by the contract but not tracked by the fee distributions
+// as collected fees, so that fees collected before the tracking is introduced can be withdrawn or distributed.
+// Deposits escrowed for sealed bids and unclaimed referral rewards are not fees and stay untracked.
+pub fn migrate_fee_distributions(deps: DepsMut, env: &Env) -> StdResult<()> {
+    let mut accounted: BTreeMap<String, Uint128> = BTreeMap::new();
+
+    let sealed_bids = SEALED_BIDS
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+    for ((name, _), sealed_bid) in sealed_bids {
+        let denom = AUCTIONS.load(deps.storage, &name)?.denom;
+        *accounted.entry(denom).or_default() += sealed_bid.deposit;
+    }
+
+    for item in REFERRAL_REWARDS.range(deps.storage, None, None, Order::Ascending) {
+        let (_, rewards) = item?;
+        for reward in rewards.pending {
+            *accounted.entry(reward.denom).or_default() += reward.amount;
+        }
+    }
+
+    for item in FEE_DISTRIBUTIONS.range(deps.storage, None, None, Order::Ascending) {
+        let (denom, distribution) = item?;
+        *accounted.entry(denom).or_default() += distribution.collected - distribution.distributed;
+    }
+
+    let balances = deps.querier.query_all_balances(&env.contract.address)?;
+    for balance in balances {
+        let untracked = balance
+            .amount
+            .saturating_sub(accounted.get(&balance.denom).copied().unwrap_or_default());
+        if untracked.is_zero() {
+            continue;
+        }
+
+        let mut distribution = FEE_DISTRIBUTIONS
+            .may_load(deps.storage, &balance.denom)?
+            .unwrap_or_default();
+        distribution.collected += untracked;
+        FEE_DISTRIBUTIONS.save(deps.storage, &balance.denom, &distribution)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::state::{verifier_weight, Auction, FeeDistribution, ReferralRewards, SealedBid};
+    use cosmwasm_std::testing::{mock_dependencies, mock_dependencies_with_balance, mock_env};
+    use cosmwasm_std::Timestamp;
+
+    #[test]
+    fn test_migrate_twitter_identities() {
+        let mut deps = mock_dependencies();
+        let verifier1 = Binary(vec![1; 33]);
+        let verifier2 = Binary(vec![2; 33]);
+
+        LEGACY_CONFIG
+            .save(
+                &mut deps.storage,
+                &LegacyConfig {
+                    name_nft: Addr::unchecked("namenftaddr"),
+                    verifier_pubkeys: vec![verifier1.clone(), verifier2.clone()],
+                    verification_threshold_percentage: Decimal::percent(50),
+                    absolute_verification_threshold: None,
+                    fee: Some(Coin::new(100000, "uosmo")),
+                    fees: vec![],
+                    registration_years: None,
+                    length_tier_fees: vec![],
+                    referral_reward_share: Decimal::zero(),
+                    commitment_period: None,
+                },
+            )
+            .unwrap();
+        LEGACY_VERIFIER_WEIGHTS
+            .save(&mut deps.storage, &verifier2, &3)
+            .unwrap();
+        LEGACY_UNIQUE_TWITTER_ID
+            .save(&mut deps.storage, "1234".to_string(), &"bob".to_string())
+            .unwrap();
+
+        migrate_twitter_identities(&mut deps.storage).unwrap();
+
+        assert_eq!(
+            CONFIG.load(&deps.storage).unwrap(),
+            Config {
+                name_nft: Addr::unchecked("namenftaddr"),
+                fees: vec![Coin::new(100000, "uosmo")],
+                registration_years: None,
+                length_tier_fees: vec![],
+                referral_reward_share: Decimal::zero(),
+                commitment_period: None,
+                treasury: vec![],
+                auto_distribute_fees: false,
+                claim_rate_limit: ClaimRateLimit::default(),
+                paused: false,
+            }
+        );
+        assert_eq!(
+            IDENTITY_PROVIDERS
+                .load(&deps.storage, TWITTER_PROVIDER)
+                .unwrap(),
+            IdentityProvider {
+                verifier_pubkeys: vec![verifier1.clone(), verifier2.clone()],
+                verification_threshold_percentage: Decimal::percent(50),
+                absolute_verification_threshold: None,
+            }
+        );
+        assert_eq!(
+            verifier_weight(&deps.storage, TWITTER_PROVIDER, &verifier1).unwrap(),
+            1
+        );
+        assert_eq!(
+            verifier_weight(&deps.storage, TWITTER_PROVIDER, &verifier2).unwrap(),
+            3
+        );
+        assert_eq!(
+            IDENTITIES
+                .load(&deps.storage, (TWITTER_PROVIDER, "1234"))
+                .unwrap(),
+            "bob"
+        );
+        assert!(!LEGACY_UNIQUE_TWITTER_ID.has(&deps.storage, "1234".to_string()));
+
+        // migrating again does nothing
+        migrate_twitter_identities(&mut deps.storage).unwrap();
+        assert_eq!(
+            IDENTITIES
+                .load(&deps.storage, (TWITTER_PROVIDER, "1234"))
+                .unwrap(),
+            "bob"
+        );
+
+        migrate_name_identities(&mut deps.storage).unwrap();
+        assert_eq!(
+            NAME_IDENTITIES
+                .load(&deps.storage, ("bob", TWITTER_PROVIDER))
+                .unwrap(),
+            "1234"
+        );
+    }
+
+    #[test]
+    fn test_migrate_referral_index() {
+        let mut deps = mock_dependencies();
+        let legacy_referral: Map<String, u64> = Map::new("referral");
+        legacy_referral
+            .save(&mut deps.storage, "bob".to_string(), &3)
+            .unwrap();
+        legacy_referral
+            .save(&mut deps.storage, "alice".to_string(), &1)
+            .unwrap();
+
+        migrate_referral_index(&mut deps.storage).unwrap();
+
+        let leaderboard = referrals()
+            .idx
+            .count
+            .range(&deps.storage, None, None, Order::Descending)
+            .collect::<StdResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(
+            leaderboard,
+            vec![("bob".to_string(), 3), ("alice".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn test_migrate_fee_distributions() {
+        let mut deps =
+            mock_dependencies_with_balance(&[Coin::new(1000, "uosmo"), Coin::new(50, "uatom")]);
+        let env = mock_env();
+
+        AUCTIONS
+            .save(
+                &mut deps.storage,
+                "alice",
+                &Auction {
+                    denom: "uosmo".to_string(),
+                    min_bid: Uint128::new(10),
+                    bid_end: Timestamp::from_seconds(100),
+                    reveal_end: Timestamp::from_seconds(200),
+                    highest_bid: None,
+                },
+            )
+            .unwrap();
+        SEALED_BIDS
+            .save(
+                &mut deps.storage,
+                ("alice", &Addr::unchecked("bidder")),
+                &SealedBid {
+                    sealed_bid: Binary(vec![1; 32]),
+                    deposit: Uint128::new(100),
+                },
+            )
+            .unwrap();
+        REFERRAL_REWARDS
+            .save(
+                &mut deps.storage,
+                "bob".to_string(),
+                &ReferralRewards {
+                    pending: vec![Coin::new(30, "uosmo")],
+                    paid: vec![Coin::new(20, "uosmo")],
+                },
+            )
+            .unwrap();
+        FEE_DISTRIBUTIONS
+            .save(
+                &mut deps.storage,
+                "uosmo",
+                &FeeDistribution {
+                    collected: Uint128::new(200),
+                    distributed: Uint128::new(150),
+                },
+            )
+            .unwrap();
+
+        migrate_fee_distributions(deps.as_mut(), &env).unwrap();
+
+        // 1000 held - 100 escrowed - 30 unclaimed rewards - 50 pending fees are untracked fees
+        let expected_uosmo = FeeDistribution {
+            collected: Uint128::new(1020),
+            distributed: Uint128::new(150),
+        };
+        let expected_uatom = FeeDistribution {
+            collected: Uint128::new(50),
+            distributed: Uint128::zero(),
+        };
+        assert_eq!(
+            FEE_DISTRIBUTIONS.load(&deps.storage, "uosmo").unwrap(),
+            expected_uosmo
+        );
+        assert_eq!(
+            FEE_DISTRIBUTIONS.load(&deps.storage, "uatom").unwrap(),
+            expected_uatom
+        );
+
+        // migrating again does nothing
+        migrate_fee_distributions(deps.as_mut(), &env).unwrap();
+        assert_eq!(
+            FEE_DISTRIBUTIONS.load(&deps.storage, "uosmo").unwrap(),
+            expected_uosmo
+        );
+        assert_eq!(
+            FEE_DISTRIBUTIONS.load(&deps.storage, "uatom").unwrap(),
+            expected_uatom
+        );
+    }
+}
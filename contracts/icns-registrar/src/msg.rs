@@ -1,21 +1,25 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Binary, Coin, Decimal};
+use cosmwasm_std::{Binary, Coin, Decimal, Timestamp, Uint128};
+use cw_utils::Expiration;
+
+use crate::state::{
+    Auction, ClaimRateLimit, CommitmentPeriod, KeyType, LengthTierFee, SealedBid, TreasuryRecipient,
+};
 
 #[cw_serde]
 pub struct InstantiateMsg {
     /// valid contract address of the name nft contract
     pub name_nft_addr: String,
-    /// verifiers's public key that can sign verification message
-    pub verifier_pubkeys: Vec<Binary>,
-    /// percentage of verification signature required out of all verifiers
-    pub verification_threshold: Decimal,
-    /// fee required for minting new name
-    pub fee: Option<Coin>,
+    /// identity providers that names can be claimed with, along with their verifiers and threshold
+    pub identity_providers: Vec<IdentityProviderInfo>,
+    /// accepted fees for minting new name, any one of them is required
+    pub fees: Vec<Coin>,
 }
 
 #[cw_serde]
 pub enum ExecuteMsg {
     /// To claim name, sender needs to gather signatures of `verifying_msg` form `verifiers`
+    /// of the identity provider in `verifying_msg`.
     /// number of signatures must pass `verification_threshold` in order to proceed with minting
     /// and owning the name NFT
     ///
@@ -32,38 +36,199 @@ pub enum ExecuteMsg {
         /// and pubkey that use for that signature.
         verifications: Vec<Verification>,
 
-        /// icns name of the referer, tracked for future incentivization
+        /// icns name of the referer, credited with a share of the claim fee
         referral: Option<String>,
+
+        /// secret used for the commitment of this claim,
+        /// required if commitment period is set
+        secret: Option<Binary>,
+
+        /// address receiving the name nft if claimed on behalf of another owner,
+        /// must match the owner in `verifying_msg`. Defaults to the sender.
+        owner: Option<String>,
+    },
+
+    /// Commit to claiming a name without revealing it.
+    /// `commitment` is sha256(name || 0x00 || claimer || 0x00 || secret).
+    CommitClaim { commitment: Binary },
+
+    /// Set number of blocks within which a claim must be revealed after its commitment
+    SetCommitmentPeriod {
+        /// `None` means claiming does not require commitment
+        period: Option<CommitmentPeriod>,
+    },
+
+    /// Add identity provider that names can be claimed with
+    AddIdentityProvider {
+        identity_provider: IdentityProviderInfo,
     },
 
-    /// Update verifiers's public key that can sign verification message
+    /// Remove identity provider, names can no longer be claimed with it.
+    /// Identities already bound to names are kept.
+    RemoveIdentityProvider { provider: String },
+
+    /// Update verifiers's public key of the identity provider that can sign verification message.
+    /// Adding an existing verifier updates its weight.
     UpdateVerifierPubkeys {
-        add: Vec<Binary>,
+        provider: String,
+        add: Vec<VerifierPubKey>,
         remove: Vec<Binary>,
     },
 
-    /// Set threshold as percentage of verification signature required out of all verifiers
-    /// In order to pass verification and proceed with the claim
+    /// Set threshold as percentage of verification weight required out of all verifiers
+    /// of the identity provider In order to pass verification and proceed with the claim
     SetVerificationThreshold {
+        provider: String,
         /// threshold percentage
         /// "0" => 0%
         /// "1000000000000000000" => 100%
         threshold: Decimal,
     },
 
+    /// Set threshold as total weight of verification required for the identity provider,
+    /// which takes precedence over the percentage threshold
+    SetAbsoluteVerificationThreshold {
+        provider: String,
+        /// `None` means percentage threshold is used
+        threshold: Option<u64>,
+    },
+
     /// Set name NFT address to be minted once passing verfication
     SetNameNftAddress {
         /// valid contract address of the name nft contract
         name_nft_address: String,
     },
 
-    /// Set fee required for claim
-    SetMintingFee {
-        /// fee required for minting new name
-        minting_fee: Option<Coin>,
+    /// Update accepted fees for claim, any one of them is required.
+    /// Adding a fee in an already accepted denom replaces its amount.
+    UpdateAcceptedFees {
+        /// fees to be accepted for minting new name
+        add: Vec<Coin>,
+        /// denoms to be no longer accepted
+        remove: Vec<String>,
+    },
+
+    /// Set fees for names by their length, replacing the existing tiers.
+    /// A name is charged by the tier with the smallest `max_length` it fits in,
+    /// and by `fees` if it does not fit in any tier.
+    SetLengthTierFees { tiers: Vec<LengthTierFee> },
+
+    /// Set or remove (`fees: None`) fees for specific names.
+    /// Fee set for a name takes precedence over length tier fees.
+    SetNameFees { fees: Vec<NameFee> },
+
+    /// Extend the registration of the name by the given number of years.
+    /// Fee required for renewal is the price of the name for each year.
+    Renew {
+        /// Name to be renewed
+        name: String,
+        /// number of years to extend the registration by
+        years: u64,
+    },
+
+    /// Set number of years a claimed name is registered for before it expires
+    SetRegistrationPeriod {
+        /// `None` means claimed names never expire
+        years: Option<u64>,
+    },
+
+    /// Set share of the claim fee credited to the referrer name
+    SetReferralRewardShare {
+        /// share percentage
+        /// "0" => 0%
+        /// "1000000000000000000" => 100%
+        share: Decimal,
+    },
+
+    /// Claim pending referral rewards credited to the name.
+    /// Only the current owner of the name can claim the rewards.
+    ClaimReferralRewards { name: String },
+
+    /// Reserve names so that they can only be claimed by admins or the assignee of the reservation.
+    /// Reserving an already reserved name replaces its assignee.
+    ReserveNames { names: Vec<ReservedName> },
+
+    /// Remove reservation of the names
+    UnreserveNames { names: Vec<String> },
+
+    /// Mint the names to their owners without verifications and fees.
+    /// Only admin can batch claim names.
+    AdminBatchClaim { entries: Vec<AdminClaimEntry> },
+
+    /// Start sealed-bid auction for the name, the name can not be claimed while being auctioned.
+    /// Only admin can start an auction.
+    StartAuction {
+        name: String,
+        /// denom of the bids
+        denom: String,
+        /// minimum amount of a bid
+        min_bid: Uint128,
+        /// number of seconds from now during which sealed bids can be placed
+        bid_duration: u64,
+        /// number of seconds after bidding ends during which sealed bids can be revealed
+        reveal_duration: u64,
+    },
+
+    /// Place a sealed bid on the auctioned name with a deposit in the auction denom.
+    /// `sealed_bid` is sha256(name || 0x00 || bidder || 0x00 || amount || 0x00 || salt),
+    /// where `amount` is the decimal string of the bid amount.
+    /// The deposit must cover the bid amount and should hide it.
+    PlaceBid { name: String, sealed_bid: Binary },
+
+    /// Reveal the sealed bid after bidding ends.
+    /// Deposit of a bid that is outbid is refunded.
+    RevealBid {
+        name: String,
+        amount: Uint128,
+        salt: Binary,
+    },
+
+    /// Settle the auction after reveal ends. The name is minted to the highest bidder
+    /// and deposits of the other bids are refunded.
+    SettleAuction { name: String },
+
+    /// Withdraw the deposit of the sender's bid that is not the highest bid after reveal ends.
+    /// This lets bidders get their deposits back without waiting for the auction to be settled.
+    WithdrawBid { name: String },
+
+    /// Cancel the auction and refund the deposits of all bids, including the highest bid.
+    /// Only admin can cancel an auction.
+    CancelAuction { name: String },
+
+    /// Pause or unpause claiming names.
+    /// Admins can pause and unpause, while guardians of the name nft contract can only pause.
+    SetPaused { paused: bool },
+
+    /// Set limits on the number of names claimed without admin,
+    /// per address and per window of blocks
+    SetClaimRateLimit { rate_limit: ClaimRateLimit },
+
+    /// Set recipients of the collected fees with their shares, which must add up to 1.
+    /// If `auto_distribute` is set, fees are distributed as soon as they are paid.
+    SetTreasury {
+        recipients: Vec<TreasuryRecipient>,
+        auto_distribute: bool,
+    },
+
+    /// Distribute the pending collected fees to the treasury recipients.
+    /// Anyone can distribute fees.
+    DistributeFees {},
+
+    /// Release the identity of the provider bound to the name, so that it can claim another name.
+    /// Only the owner of the name can release its identity.
+    ReleaseIdentity { name: String, provider: String },
+
+    /// Bind a new identity attested by the verifiers to the name, releasing the identity
+    /// of the same provider previously bound to the name, e.g. to recover a lost account.
+    /// Only the owner of the name can rebind its identity, admins can rebind without verifications.
+    RebindIdentity {
+        name: String,
+        /// String representation of [`VerifyingMsg`] with the new subject id
+        verifying_msg: String,
+        verifications: Vec<Verification>,
     },
 
-    /// Withdraw funds from this contract
+    /// Withdraw the collected fees pending distribution from this contract
     WithdrawFunds {
         /// amount to withdraw
         amount: Vec<Coin>,
@@ -74,14 +239,19 @@ pub enum ExecuteMsg {
 #[cw_serde]
 #[derive(QueryResponses)]
 pub enum QueryMsg {
-    /// VerifierPubKeys returns all the public keys of the verifiers
+    /// IdentityProviders returns all the identity providers that names can be claimed with
+    #[returns(IdentityProvidersResponse)]
+    IdentityProviders {},
+
+    /// VerifierPubKeys returns all the public keys of the verifiers of the identity provider
+    /// with their weights
     #[returns(VerifierPubKeysResponse)]
-    VerifierPubKeys {},
+    VerifierPubKeys { provider: String },
 
     /// VerificationThreshold returns the threshold percentage of verification
-    /// signature required out of all verifiers
+    /// weight required out of all verifiers of the identity provider, and the absolute threshold if set
     #[returns(VerificationThresholdResponse)]
-    VerificationThreshold {},
+    VerificationThreshold { provider: String },
 
     /// NameNftAddress returns the address of the name NFT contract
     #[returns(NameNftAddressResponse)]
@@ -91,27 +261,169 @@ pub enum QueryMsg {
     #[returns(ReferralCountResponse)]
     ReferralCount { name: String },
 
-    /// Fee returns the current fee required for minting new name
+    /// ReferralLeaderboard returns referrers with their referral counts, ordered by count from the highest
+    #[returns(ReferralLeaderboardResponse)]
+    ReferralLeaderboard {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// ReferredNames returns the names claimed with the referrer name as referral, ordered by name
+    #[returns(ReferredNamesResponse)]
+    ReferredNames {
+        referrer: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// ReferralRewardShare returns the share of the claim fee credited to the referrer name
+    #[returns(ReferralRewardShareResponse)]
+    ReferralRewardShare {},
+
+    /// ReferralRewards returns pending and paid referral rewards of the name
+    #[returns(ReferralRewardsResponse)]
+    ReferralRewards { name: String },
+
+    /// CommitmentPeriod returns the number of blocks within which a claim must be revealed
+    #[returns(CommitmentPeriodResponse)]
+    CommitmentPeriod {},
+
+    /// Commitment returns the block height at which the commitment is made by the committer
+    #[returns(CommitmentResponse)]
+    Commitment {
+        committer: String,
+        commitment: Binary,
+    },
+
+    /// IsReserved returns whether the name is reserved and its assignee
+    #[returns(IsReservedResponse)]
+    IsReserved { name: String },
+
+    /// ReservedNames returns reserved names with their assignees, ordered by name
+    #[returns(ReservedNamesResponse)]
+    ReservedNames {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+
+    /// Auction returns the auction of the name, if any
+    #[returns(AuctionResponse)]
+    Auction { name: String },
+
+    /// Bid returns the sealed bid placed by the bidder on the auctioned name, if any
+    #[returns(BidResponse)]
+    Bid { name: String, bidder: String },
+
+    /// SimulateClaim runs the checks of `Claim` without claiming the name,
+    /// and returns every check that would fail
+    #[returns(SimulateClaimResponse)]
+    SimulateClaim {
+        name: String,
+        claimer: String,
+        verifying_msg: String,
+        verifications: Vec<Verification>,
+        referral: Option<String>,
+        secret: Option<Binary>,
+        owner: Option<String>,
+        funds: Vec<Coin>,
+    },
+
+    /// Fee returns all the accepted fees for minting new name
     #[returns(FeeResponse)]
     Fee {},
 
-    /// NameByTwitterId returns the name of the user by twitter id.
-    /// Note that the name returned does not indicate the "current" name of the user in Twitter,
+    /// Paused returns whether claiming names is paused
+    #[returns(PausedResponse)]
+    Paused {},
+
+    /// ClaimRateLimit returns the limits on the number of names claimed without admin
+    #[returns(ClaimRateLimitResponse)]
+    ClaimRateLimit {},
+
+    /// ClaimQuota returns the number of names the address can still claim
+    #[returns(ClaimQuotaResponse)]
+    ClaimQuota { address: String },
+
+    /// Treasury returns the recipients of the collected fees with their shares
+    #[returns(TreasuryResponse)]
+    Treasury {},
+
+    /// FeeDistributions returns the amount of fees collected and distributed per denom
+    #[returns(FeeDistributionsResponse)]
+    FeeDistributions {},
+
+    /// Price returns the accepted fees for claiming the given name,
+    /// taking per-name fees and length tier fees into account.
+    #[returns(PriceResponse)]
+    Price { name: String },
+
+    /// NameByIdentity returns the name claimed with the subject id of the identity provider.
+    /// Note that the name returned does not indicate the "current" name of the user in the provider,
     /// but the name that the user has used when claiming icns.
+    #[returns(NameByIdentityResponse)]
+    NameByIdentity { provider: String, id: String },
+
+    /// NameByTwitterId returns the name of the user by twitter id.
+    /// Same as `NameByIdentity` with "twitter" provider, kept for backward compatibility.
     #[returns(NameByTwitterIdResponse)]
     NameByTwitterId { twitter_id: String },
+
+    /// IdentityByName returns the subject id of the identity provider bound to the name
+    #[returns(IdentityByNameResponse)]
+    IdentityByName { name: String, provider: String },
+
+    /// TwitterIdByName returns the twitter id bound to the name.
+    /// Same as `IdentityByName` with "twitter" provider.
+    #[returns(TwitterIdByNameResponse)]
+    TwitterIdByName { name: String },
+
+    /// Expiry returns the time at which the registration of the name expires.
+    /// `None` if the name never expires.
+    #[returns(ExpiryResponse)]
+    Expiry { name: String },
+
+    /// RegistrationPeriod returns the number of years a claimed name is registered for
+    #[returns(RegistrationPeriodResponse)]
+    RegistrationPeriod {},
 }
 
 #[cw_serde]
 pub struct MigrateMsg {}
 
+#[cw_serde]
+pub struct VerifierPubKey {
+    /// pubkey bytes of verifier, sec1 encoded if the key type is secp256k1
+    pub pubkey: Binary,
+    /// weight of the verification signed by this verifier
+    pub weight: u64,
+    /// key type of the pubkey, secp256k1 if not set
+    #[serde(default)]
+    pub key_type: KeyType,
+}
+
+#[cw_serde]
+pub struct IdentityProviderInfo {
+    /// name of the identity provider, e.g. "twitter", "github"
+    pub provider: String,
+    /// verifiers's public key that can sign verification message, with their weights
+    pub verifier_pubkeys: Vec<VerifierPubKey>,
+    /// percentage of verification weight required out of all verifiers
+    pub verification_threshold: Decimal,
+}
+
+#[cw_serde]
+pub struct IdentityProvidersResponse {
+    pub providers: Vec<String>,
+}
+
 #[cw_serde]
 pub struct VerifierPubKeysResponse {
-    pub verifier_pubkeys: Vec<Binary>,
+    pub verifier_pubkeys: Vec<VerifierPubKey>,
 }
 #[cw_serde]
 pub struct VerificationThresholdResponse {
     pub verification_threshold_percentage: Decimal,
+    pub absolute_verification_threshold: Option<u64>,
 }
 
 #[cw_serde]
@@ -124,9 +436,185 @@ pub struct ReferralCountResponse {
     pub count: u64,
 }
 
+#[cw_serde]
+pub struct ReferralCount {
+    pub name: String,
+    pub count: u64,
+}
+
+#[cw_serde]
+pub struct ReferralLeaderboardResponse {
+    pub referrals: Vec<ReferralCount>,
+}
+
+#[cw_serde]
+pub struct ReferredNamesResponse {
+    pub names: Vec<String>,
+}
+
+#[cw_serde]
+pub struct ReservedName {
+    pub name: String,
+    /// address that can claim the reserved name besides admins
+    pub assignee: Option<String>,
+}
+
+#[cw_serde]
+pub struct AdminClaimEntry {
+    pub name: String,
+    /// address receiving the name nft
+    pub owner: String,
+    /// identity bound to the name, if any
+    pub identity: Option<Identity>,
+}
+
+#[cw_serde]
+pub struct Identity {
+    pub provider: String,
+    /// unique id of the subject in the identity provider
+    pub id: String,
+}
+
+#[cw_serde]
+pub struct IsReservedResponse {
+    pub reserved: bool,
+    pub assignee: Option<String>,
+}
+
+#[cw_serde]
+pub struct ReservedNamesResponse {
+    pub names: Vec<ReservedName>,
+}
+
+#[cw_serde]
+pub struct AuctionResponse {
+    pub auction: Option<Auction>,
+}
+
+#[cw_serde]
+pub struct BidResponse {
+    pub bid: Option<SealedBid>,
+}
+
+#[cw_serde]
+pub struct CommitmentPeriodResponse {
+    pub period: Option<CommitmentPeriod>,
+}
+
+#[cw_serde]
+pub struct CommitmentResponse {
+    pub height: Option<u64>,
+}
+
+#[cw_serde]
+pub struct ReferralRewardShareResponse {
+    pub share: Decimal,
+}
+
+#[cw_serde]
+pub struct ReferralRewardsResponse {
+    pub pending: Vec<Coin>,
+    pub paid: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct PausedResponse {
+    pub paused: bool,
+}
+
+#[cw_serde]
+pub struct ClaimRateLimitResponse {
+    pub rate_limit: ClaimRateLimit,
+}
+
+#[cw_serde]
+pub struct ClaimQuotaResponse {
+    /// number of names claimed for the address
+    pub claimed: u64,
+    /// number of names the address can still claim, unlimited if not set
+    pub remaining_for_address: Option<u64>,
+    /// number of names that can still be claimed within the current window, unlimited if not set
+    pub remaining_in_window: Option<u64>,
+    /// height at which the next window starts, if limited per window
+    pub next_window_height: Option<u64>,
+}
+
+#[cw_serde]
+pub struct TreasuryResponse {
+    pub recipients: Vec<TreasuryRecipient>,
+    pub auto_distribute: bool,
+}
+
+#[cw_serde]
+pub struct DenomFeeDistribution {
+    pub denom: String,
+    pub collected: Uint128,
+    pub distributed: Uint128,
+}
+
+#[cw_serde]
+pub struct FeeDistributionsResponse {
+    pub distributions: Vec<DenomFeeDistribution>,
+}
+
+#[cw_serde]
+pub enum ClaimCheck {
+    /// contract must not be paused
+    Paused,
+    /// name must be able to be minted as name nft
+    Name,
+    /// name must not be claimed, unless its registration has expired
+    Availability,
+    /// claim must reveal a commitment within the commitment period, if set
+    Commitment,
+    /// name must not be auctioned
+    Auction,
+    /// reserved name must be claimed by admins or its assignee
+    Reservation,
+    /// verifying msg must be valid for the claim
+    VerifyingMsg,
+    /// verifications must pass the threshold of the identity provider
+    Verifications,
+    /// referral must be an existing name
+    Referral,
+    /// funds must cover one of the accepted fees
+    Fee,
+    /// claim must not exceed the claim rate limit
+    RateLimit,
+}
+
+#[cw_serde]
+pub struct ClaimCheckFailure {
+    pub check: ClaimCheck,
+    /// error that the check fails with
+    pub error: String,
+}
+
+#[cw_serde]
+pub struct SimulateClaimResponse {
+    /// failed checks in the order they are run by `Claim`, empty if the claim would pass
+    pub failures: Vec<ClaimCheckFailure>,
+}
+
 #[cw_serde]
 pub struct FeeResponse {
-    pub fee: Option<Coin>,
+    pub fees: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct PriceResponse {
+    pub price: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct NameFee {
+    pub name: String,
+    pub fees: Option<Vec<Coin>>,
+}
+
+#[cw_serde]
+pub struct NameByIdentityResponse {
+    pub name: String,
 }
 
 #[cw_serde]
@@ -134,9 +622,33 @@ pub struct NameByTwitterIdResponse {
     pub name: String,
 }
 
+#[cw_serde]
+pub struct IdentityByNameResponse {
+    pub id: String,
+}
+
+#[cw_serde]
+pub struct TwitterIdByNameResponse {
+    pub twitter_id: String,
+}
+
+#[cw_serde]
+pub struct ExpiryResponse {
+    pub expiry: Option<Timestamp>,
+    pub expired: bool,
+}
+
+#[cw_serde]
+pub struct RegistrationPeriodResponse {
+    pub years: Option<u64>,
+}
+
 #[cw_serde]
 pub struct Verification {
-    pub public_key: Binary,
+    /// public key of the verifier. If omitted, the public key is recovered from the signature.
+    pub public_key: Option<Binary>,
+    /// compact signature, or recoverable signature with the recovery id appended
+    /// if the public key is omitted
     pub signature: Binary,
 }
 
@@ -146,5 +658,15 @@ pub struct VerifyingMsg {
     pub claimer: String,
     pub contract_address: String,
     pub chain_id: String,
-    pub unique_twitter_id: String,
+    /// identity provider that verifies the subject, e.g. "twitter", "github"
+    pub provider: String,
+    /// unique id of the subject in the identity provider
+    pub subject_id: String,
+    /// block height or time after which the verifications are no longer valid
+    pub expiry: Expiration,
+    /// unique value to prevent the verifications from being replayed
+    pub nonce: String,
+    /// address receiving the name nft if claimed on behalf of another owner
+    #[serde(default)]
+    pub owner: Option<String>,
 }
@@ -0,0 +1,146 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage};
+
+use crate::checks::check_admin;
+use crate::msg::{ClaimQuotaResponse, ClaimRateLimitResponse};
+use crate::state::{
+    BlockWindowLimit, ClaimRateLimit, ClaimWindow, Config, CLAIM_COUNTS, CLAIM_WINDOW, CONFIG,
+};
+use crate::ContractError;
+
+// execute_set_claim_rate_limit sets the limits on the number of names claimed without admin.
+pub fn execute_set_claim_rate_limit(
+    deps: DepsMut,
+    info: MessageInfo,
+    rate_limit: ClaimRateLimit,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    if let Some(window) = rate_limit.max_claims_per_window.as_ref() {
+        if window.blocks == 0 {
+            return Err(ContractError::InvalidClaimRateLimit {
+                msg: "window must be at least 1 block".to_string(),
+            });
+        }
+    }
+
+    let attrs = [
+        (
+            "max_claims_per_address",
+            rate_limit
+                .max_claims_per_address
+                .map(|limit| limit.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+        ),
+        (
+            "max_claims_per_window",
+            rate_limit
+                .max_claims_per_window
+                .as_ref()
+                .map(|window| format!("{}/{}", window.max_claims, window.blocks))
+                .unwrap_or_else(|| "none".to_string()),
+        ),
+    ];
+
+    CONFIG.update(deps.storage, |config| -> StdResult<_> {
+        Ok(Config {
+            claim_rate_limit: rate_limit,
+            ..config
+        })
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_claim_rate_limit")
+        .add_attributes(attrs))
+}
+
+// check_claim_rate_limit checks if claiming another name for the address exceeds the claim rate limit.
+pub fn check_claim_rate_limit(deps: Deps, env: &Env, address: &Addr) -> Result<(), ContractError> {
+    let rate_limit = CONFIG.load(deps.storage)?.claim_rate_limit;
+
+    if let Some(limit) = rate_limit.max_claims_per_address {
+        if claim_count(deps.storage, address)? >= limit {
+            return Err(ContractError::AddressClaimLimitExceeded {
+                address: address.to_string(),
+                limit,
+            });
+        }
+    }
+
+    if let Some(window) = rate_limit.max_claims_per_window {
+        if current_window(deps.storage, env, &window)?.claims >= window.max_claims {
+            return Err(ContractError::WindowClaimLimitExceeded {
+                limit: window.max_claims,
+                blocks: window.blocks,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+// record_claim counts the name claimed for the address towards the claim rate limit.
+pub fn record_claim(storage: &mut dyn Storage, env: &Env, address: &Addr) -> StdResult<()> {
+    let count = claim_count(storage, address)?;
+    CLAIM_COUNTS.save(storage, address, &(count + 1))?;
+
+    if let Some(window) = CONFIG.load(storage)?.claim_rate_limit.max_claims_per_window {
+        let mut claim_window = current_window(storage, env, &window)?;
+        claim_window.claims += 1;
+        CLAIM_WINDOW.save(storage, &claim_window)?;
+    }
+
+    Ok(())
+}
+
+pub fn query_claim_quota(deps: Deps, env: Env, address: String) -> StdResult<ClaimQuotaResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let rate_limit = CONFIG.load(deps.storage)?.claim_rate_limit;
+    let claimed = claim_count(deps.storage, &address)?;
+
+    let window = rate_limit
+        .max_claims_per_window
+        .map(|window| -> StdResult<_> {
+            let claim_window = current_window(deps.storage, &env, &window)?;
+            Ok((
+                window.max_claims.saturating_sub(claim_window.claims),
+                claim_window.start_height + window.blocks,
+            ))
+        })
+        .transpose()?;
+
+    Ok(ClaimQuotaResponse {
+        claimed,
+        remaining_for_address: rate_limit
+            .max_claims_per_address
+            .map(|limit| limit.saturating_sub(claimed)),
+        remaining_in_window: window.map(|(remaining, _)| remaining),
+        next_window_height: window.map(|(_, next_window_height)| next_window_height),
+    })
+}
+
+pub fn query_claim_rate_limit(deps: Deps) -> StdResult<ClaimRateLimitResponse> {
+    Ok(ClaimRateLimitResponse {
+        rate_limit: CONFIG.load(deps.storage)?.claim_rate_limit,
+    })
+}
+
+fn claim_count(storage: &dyn Storage, address: &Addr) -> StdResult<u64> {
+    Ok(CLAIM_COUNTS.may_load(storage, address)?.unwrap_or(0))
+}
+
+// current_window returns the window of blocks that the current block belongs to.
+// Claims of a previous window are not counted.
+fn current_window(
+    storage: &dyn Storage,
+    env: &Env,
+    window: &BlockWindowLimit,
+) -> StdResult<ClaimWindow> {
+    let start_height = env.block.height - env.block.height % window.blocks;
+    Ok(CLAIM_WINDOW
+        .may_load(storage)?
+        .filter(|claim_window| claim_window.start_height == start_height)
+        .unwrap_or(ClaimWindow {
+            start_height,
+            claims: 0,
+        }))
+}
@@ -0,0 +1,49 @@
+use cosmwasm_std::{Binary, Coin, Deps, Env, MessageInfo, StdError, StdResult};
+
+use crate::{
+    checks::{check_claim, ClaimChecker},
+    msg::{SimulateClaimResponse, Verification},
+};
+
+// query_simulate_claim runs the checks of claiming the name by the claimer,
+// and collects every check that would fail instead of stopping at the first one.
+#[allow(clippy::too_many_arguments)]
+pub fn query_simulate_claim(
+    deps: Deps,
+    env: Env,
+    name: String,
+    claimer: String,
+    verifying_msg_str: String,
+    verifications: Vec<Verification>,
+    referral: Option<String>,
+    secret: Option<Binary>,
+    owner: Option<String>,
+    funds: Vec<Coin>,
+) -> StdResult<SimulateClaimResponse> {
+    let info = MessageInfo {
+        sender: deps.api.addr_validate(&claimer)?,
+        funds,
+    };
+    let owner = owner
+        .map(|owner| deps.api.addr_validate(&owner))
+        .transpose()?;
+
+    let mut checker = ClaimChecker::collect_failures();
+    check_claim(
+        deps,
+        &env,
+        &info,
+        &name,
+        &verifying_msg_str,
+        &verifications,
+        referral.as_ref(),
+        secret.as_ref(),
+        owner.as_ref(),
+        &mut checker,
+    )
+    .map_err(|err| StdError::generic_err(err.to_string()))?;
+
+    Ok(SimulateClaimResponse {
+        failures: checker.failures(),
+    })
+}
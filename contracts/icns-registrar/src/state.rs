@@ -1,33 +1,169 @@
 use cosmwasm_schema::cw_serde;
 
-use cosmwasm_std::{Addr, Binary, Coin, Decimal};
-use cw_storage_plus::{Item, Map};
+use cosmwasm_std::{Addr, Binary, Coin, Decimal, StdResult, Storage, Timestamp, Uint128};
+use cw_storage_plus::{Index, IndexList, IndexedMap, Item, Map, MultiIndex};
 
 use crate::ContractError;
 pub static CONFIG_KEY: &[u8] = b"config";
 
+/// identity provider of the names claimed before identity providers were introduced
+pub const TWITTER_PROVIDER: &str = "twitter";
+
+/// number of seconds in a year of registration period
+pub const SECONDS_PER_YEAR: u64 = 365 * 24 * 60 * 60;
+
 #[cw_serde]
 pub struct Config {
     /// name_nft address to send msg to
     pub name_nft: Addr,
-    /// sec1 encoded pubkey bytes of verifier, used for signature verfication
+    /// accepted fees for claiming name, any one of them is required.
+    /// claiming is free if this is empty.
+    pub fees: Vec<Coin>,
+    /// number of years a claimed name is registered for before it expires.
+    /// names never expire if this is not set.
+    pub registration_years: Option<u64>,
+    /// fees for names up to a certain length, takes precedence over `fees`
+    #[serde(default)]
+    pub length_tier_fees: Vec<LengthTierFee>,
+    /// share of the claim fee credited to the referrer name
+    #[serde(default)]
+    pub referral_reward_share: Decimal,
+    /// blocks within which a claim must be revealed after its commitment.
+    /// claiming does not require commitment if this is not set.
+    #[serde(default)]
+    pub commitment_period: Option<CommitmentPeriod>,
+    /// recipients of the collected fees with their shares
+    #[serde(default)]
+    pub treasury: Vec<TreasuryRecipient>,
+    /// whether collected fees are distributed to the treasury as soon as they are paid
+    #[serde(default)]
+    pub auto_distribute_fees: bool,
+    /// limits on the number of names claimed without admin
+    #[serde(default)]
+    pub claim_rate_limit: ClaimRateLimit,
+    /// whether claiming is paused
+    #[serde(default)]
+    pub paused: bool,
+}
+
+#[cw_serde]
+pub struct IdentityProvider {
+    /// pubkey bytes of verifier, used for signature verfication
     pub verifier_pubkeys: Vec<Binary>,
-    /// number of verification that needs to pass in order to mint name
+    /// weight of verification that needs to pass in order to mint name,
+    /// as percentage of the total weight of all verifiers
     pub verification_threshold_percentage: Decimal,
-    /// fee required for claiming name
-    pub fee: Option<Coin>,
+    /// total weight of verification that needs to pass in order to mint name,
+    /// takes precedence over `verification_threshold_percentage` if set
+    pub absolute_verification_threshold: Option<u64>,
 }
 
-impl Config {
-    // check_pass_threshold checks if the passed verification is above the threshold set in config
+#[cw_serde]
+#[derive(Default)]
+pub enum KeyType {
+    /// sec1 encoded secp256k1 public key, verifying signature over sha256 of the message
+    #[default]
+    Secp256k1,
+    /// ed25519 public key, verifying signature over the message
+    Ed25519,
+}
+
+#[cw_serde]
+pub enum Recipient {
+    /// send fees to the address
+    Address { address: String },
+    /// fund the community pool of the chain with fees
+    CommunityPool {},
+}
+
+#[cw_serde]
+pub struct TreasuryRecipient {
+    pub recipient: Recipient,
+    /// share of the collected fees sent to the recipient
+    pub share: Decimal,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct FeeDistribution {
+    /// fees collected for the treasury, excluding referral rewards
+    pub collected: Uint128,
+    /// fees already distributed to the treasury recipients
+    pub distributed: Uint128,
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ClaimRateLimit {
+    /// maximum number of names claimed for an address, unlimited if not set
+    pub max_claims_per_address: Option<u64>,
+    /// maximum number of names claimed within each window of blocks, unlimited if not set
+    pub max_claims_per_window: Option<BlockWindowLimit>,
+}
+
+#[cw_serde]
+pub struct BlockWindowLimit {
+    /// number of blocks in each window, windows start at multiples of this number
+    pub blocks: u64,
+    pub max_claims: u64,
+}
+
+#[cw_serde]
+pub struct ClaimWindow {
+    /// height at which the window starts
+    pub start_height: u64,
+    /// number of names claimed within the window
+    pub claims: u64,
+}
+
+#[cw_serde]
+pub struct CommitmentPeriod {
+    /// minimum number of blocks after the commitment before the claim can be revealed
+    pub min_blocks: u64,
+    /// maximum number of blocks after the commitment for the claim to be revealed
+    pub max_blocks: u64,
+}
+
+#[cw_serde]
+pub struct LengthTierFee {
+    /// maximum number of characters of the name this tier applies to
+    pub max_length: u32,
+    /// accepted fees for claiming name in this tier, any one of them is required
+    pub fees: Vec<Coin>,
+}
+
+impl IdentityProvider {
+    // total_weight sums the weights of the verifiers of the provider
+    pub fn total_weight(&self, storage: &dyn Storage, provider: &str) -> StdResult<u64> {
+        self.verifier_pubkeys
+            .iter()
+            .map(|pubkey| verifier_weight(storage, provider, pubkey))
+            .sum()
+    }
+
+    // check_pass_threshold checks if the weight of passed verification is above the threshold set for the provider
     pub fn check_pass_threshold(
         &self,
-        passed_verification: impl Into<Decimal>,
+        passed_weight: u64,
+        total_weight: u64,
     ) -> Result<(), ContractError> {
-        let passed_verification: Decimal = passed_verification.into();
-        let pct = passed_verification
-            .checked_div(Decimal::new((self.verifier_pubkeys.len() as u64).into()))
-            .map_err(|e| match e {
+        if self.verifier_pubkeys.is_empty() {
+            return Err(ContractError::NoVerifier {});
+        }
+
+        if let Some(threshold) = self.absolute_verification_threshold {
+            if passed_weight < threshold {
+                return Err(ContractError::ValidVerificationWeightIsBelowThreshold {
+                    expected_at_least: threshold,
+                    actual: passed_weight,
+                });
+            }
+
+            return Ok(());
+        }
+
+        let pct =
+            Decimal::checked_from_ratio(passed_weight, total_weight).map_err(|e| match e {
                 cosmwasm_std::CheckedFromRatioError::DivideByZero => ContractError::NoVerifier {},
                 cosmwasm_std::CheckedFromRatioError::Overflow => {
                     panic!("check pass verification calculation overflowed")
@@ -45,9 +181,179 @@ impl Config {
     }
 }
 
+impl Config {
+    // length_tier_fees returns the fees of the tier with the smallest `max_length`
+    // that the name fits in, returns None if the name does not fit in any tier.
+    pub fn length_tier_fees(&self, name: &str) -> Option<Vec<Coin>> {
+        let length = name.chars().count() as u32;
+        self.length_tier_fees
+            .iter()
+            .filter(|tier| length <= tier.max_length)
+            .min_by_key(|tier| tier.max_length)
+            .map(|tier| tier.fees.clone())
+    }
+}
+
+#[cw_serde]
+#[derive(Default)]
+pub struct ReferralRewards {
+    /// rewards credited to the referrer name, not yet claimed
+    pub pending: Vec<Coin>,
+    /// rewards already claimed by the owners of the referrer name
+    pub paid: Vec<Coin>,
+}
+
+impl ReferralRewards {
+    // credit adds the given reward to the pending rewards
+    pub fn credit(&mut self, reward: Coin) {
+        add_coin(&mut self.pending, reward);
+    }
+
+    // claim moves all pending rewards to paid rewards and returns them
+    pub fn claim(&mut self) -> Vec<Coin> {
+        let rewards = std::mem::take(&mut self.pending);
+        for reward in rewards.iter() {
+            add_coin(&mut self.paid, reward.clone());
+        }
+        rewards
+    }
+}
+
+// add_coin adds the coin to the list, merging it with the coin of the same denom
+fn add_coin(coins: &mut Vec<Coin>, coin: Coin) {
+    match coins.iter_mut().find(|c| c.denom == coin.denom) {
+        Some(c) => c.amount += coin.amount,
+        None => coins.push(coin),
+    }
+}
+
+#[cw_serde]
+pub struct Auction {
+    /// denom of the bids
+    pub denom: String,
+    /// minimum amount of a bid
+    pub min_bid: Uint128,
+    /// time until which sealed bids can be placed
+    pub bid_end: Timestamp,
+    /// time until which sealed bids can be revealed, the auction can be settled afterwards
+    pub reveal_end: Timestamp,
+    /// highest revealed bid so far
+    pub highest_bid: Option<RevealedBid>,
+}
+
+#[cw_serde]
+pub struct RevealedBid {
+    pub bidder: Addr,
+    pub amount: Uint128,
+}
+
+#[cw_serde]
+pub struct SealedBid {
+    /// sha256(name || 0x00 || bidder || 0x00 || amount || 0x00 || salt)
+    pub sealed_bid: Binary,
+    /// deposit that must cover the bid amount, refunded if the bid does not win
+    pub deposit: Uint128,
+}
+
 pub const CONFIG: Item<Config> = Item::new("config");
 
-// Referrer's icns name => referral_count
-pub const REFERRAL: Map<String, u64> = Map::new("referral");
-// unique_twitter_id => claimed_name
-pub const UNIQUE_TWITTER_ID: Map<String, String> = Map::new("unique_twitter_id");
+pub struct ReferralIndexes<'a> {
+    pub count: MultiIndex<'a, u64, u64, String>,
+}
+
+impl<'a> IndexList<u64> for ReferralIndexes<'a> {
+    fn get_indexes(&'_ self) -> Box<dyn Iterator<Item = &'_ dyn Index<u64>> + '_> {
+        let v: Vec<&dyn Index<u64>> = vec![&self.count];
+        Box::new(v.into_iter())
+    }
+}
+
+// indexed map of referrer's icns name => referral_count, indexed by referral_count for the leaderboard
+pub fn referrals<'a>() -> IndexedMap<'a, String, u64, ReferralIndexes<'a>> {
+    let indexes = ReferralIndexes {
+        count: MultiIndex::new(|count: &u64| *count, "referral", "referral__count"),
+    };
+    IndexedMap::new("referral", indexes)
+}
+
+// (referrer's icns name, name claimed with the referral) => whether the name is referred
+pub const REFERRED_NAMES: Map<(&str, &str), bool> = Map::new("referred_names");
+// Referrer's icns name => referral rewards credited to the name
+pub const REFERRAL_REWARDS: Map<String, ReferralRewards> = Map::new("referral_rewards");
+// identity provider => verifiers and threshold of the provider
+pub const IDENTITY_PROVIDERS: Map<&str, IdentityProvider> = Map::new("identity_providers");
+// (identity provider, subject id) => claimed_name
+pub const IDENTITIES: Map<(&str, &str), String> = Map::new("identities");
+// (name, identity provider) => subject id bound to the name
+pub const NAME_IDENTITIES: Map<(&str, &str), String> = Map::new("name_identities");
+// nonce of the verifying msg => whether the nonce is used
+pub const USED_NONCES: Map<&str, bool> = Map::new("used_nonces");
+// (committer, commitment) => block height at which the commitment is made
+pub const COMMITMENTS: Map<(&Addr, &[u8]), u64> = Map::new("commitments");
+// reserved name => address the reservation is assigned to, if any
+pub const RESERVED_NAMES: Map<&str, Option<Addr>> = Map::new("reserved_names");
+// (identity provider, verifier pubkey) => weight of the verification signed by the verifier
+pub const VERIFIER_WEIGHTS: Map<(&str, &[u8]), u64> = Map::new("provider_verifier_weights");
+// (identity provider, verifier pubkey) => key type of the verifier pubkey
+pub const VERIFIER_KEY_TYPES: Map<(&str, &[u8]), KeyType> = Map::new("provider_verifier_key_types");
+
+// name => auction of the name, removed once settled
+pub const AUCTIONS: Map<&str, Auction> = Map::new("auctions");
+// (name, bidder) => sealed bid of the bidder, removed once refunded
+pub const SEALED_BIDS: Map<(&str, &Addr), SealedBid> = Map::new("sealed_bids");
+// name => time at which the name registration expires
+pub const NAME_EXPIRY: Map<String, Timestamp> = Map::new("name_expiry");
+// address => number of names claimed for the address without admin
+pub const CLAIM_COUNTS: Map<&Addr, u64> = Map::new("claim_counts");
+// names claimed without admin within the current block window
+pub const CLAIM_WINDOW: Item<ClaimWindow> = Item::new("claim_window");
+// denom => fees collected and distributed in the denom
+pub const FEE_DISTRIBUTIONS: Map<&str, FeeDistribution> = Map::new("fee_distributions");
+// name => accepted fees for claiming the name, takes precedence over length tier fees
+pub const NAME_FEE: Map<String, Vec<Coin>> = Map::new("name_fee");
+
+// verifier_weight returns the weight of the verifier of the provider, verifiers without weight set weigh 1
+pub fn verifier_weight(storage: &dyn Storage, provider: &str, pubkey: &[u8]) -> StdResult<u64> {
+    Ok(VERIFIER_WEIGHTS
+        .may_load(storage, (provider, pubkey))?
+        .unwrap_or(1))
+}
+
+// verifier_key_type returns the key type of the verifier of the provider, verifiers without key type set are secp256k1
+pub fn verifier_key_type(
+    storage: &dyn Storage,
+    provider: &str,
+    pubkey: &[u8],
+) -> StdResult<KeyType> {
+    Ok(VERIFIER_KEY_TYPES
+        .may_load(storage, (provider, pubkey))?
+        .unwrap_or_default())
+}
+
+// bind_identity binds the subject id of the provider to the name.
+// Identity of the provider previously bound to the name is released.
+pub fn bind_identity(
+    storage: &mut dyn Storage,
+    provider: &str,
+    subject_id: &str,
+    name: &str,
+) -> StdResult<()> {
+    release_identity(storage, provider, name)?;
+    IDENTITIES.save(storage, (provider, subject_id), &name.to_string())?;
+    NAME_IDENTITIES.save(storage, (name, provider), &subject_id.to_string())
+}
+
+// release_identity removes the binding between the name and its identity of the provider,
+// returning the subject id that was bound to the name.
+pub fn release_identity(
+    storage: &mut dyn Storage,
+    provider: &str,
+    name: &str,
+) -> StdResult<Option<String>> {
+    let subject_id = NAME_IDENTITIES.may_load(storage, (name, provider))?;
+    if let Some(subject_id) = subject_id.as_ref() {
+        IDENTITIES.remove(storage, (provider, subject_id));
+        NAME_IDENTITIES.remove(storage, (name, provider));
+    }
+    Ok(subject_id)
+}
@@ -0,0 +1,230 @@
+#![cfg(test)]
+
+use cosmwasm_std::{Addr, Coin, StdResult};
+use cw721::{NftInfoResponse, OwnerOfResponse};
+use cw_multi_test::{BasicApp, Executor};
+use icns_name_nft::msg::{IdentityRef, Metadata};
+
+use crate::{
+    msg::{AdminClaimEntry, ExecuteMsg, Identity, NameByIdentityResponse, QueryMsg, ReservedName},
+    tests::helpers::{default_contracts_setup, name_nft_contract, registrar_contract},
+    ContractError,
+};
+
+#[test]
+fn admin_batch_claim_mints_names_without_fee() {
+    let bob = Addr::unchecked("bobaddr");
+    let alice = Addr::unchecked("aliceaddr");
+    let mut app = BasicApp::default();
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+    let admin = Addr::unchecked(&admins[0]);
+
+    let (name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![Coin::new(1_000, "uosmo")],
+    );
+
+    let owner = |app: &BasicApp, name: &str| -> StdResult<String> {
+        let OwnerOfResponse { owner, .. } = app.wrap().query_wasm_smart(
+            name_nft_contract_addr.clone(),
+            &icns_name_nft::QueryMsg::OwnerOf {
+                token_id: name.to_string(),
+                include_expired: None,
+            },
+        )?;
+        Ok(owner)
+    };
+
+    let entry = |name: &str, owner: &Addr, identity: Option<(&str, &str)>| AdminClaimEntry {
+        name: name.to_string(),
+        owner: owner.to_string(),
+        identity: identity.map(|(provider, id)| Identity {
+            provider: provider.to_string(),
+            id: id.to_string(),
+        }),
+    };
+
+    // reserve a partner name to be airdropped
+    app.execute_contract(
+        admin.clone(),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::ReserveNames {
+            names: vec![ReservedName {
+                name: "osmosis".to_string(),
+                assignee: None,
+            }],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let entries = vec![
+        entry("osmosis", &bob, None),
+        entry("alice", &alice, Some(("twitter", "1234"))),
+    ];
+
+    // only admin can batch claim
+    let err = app
+        .execute_contract(
+            bob.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::AdminBatchClaim {
+                entries: entries.clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Unauthorized {}
+    );
+
+    // identity of unknown provider is rejected
+    let err = app
+        .execute_contract(
+            admin.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::AdminBatchClaim {
+                entries: vec![entry("alice", &alice, Some(("github", "1234")))],
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::UnknownIdentityProvider {
+            provider: "github".to_string()
+        }
+    );
+
+    let res = app
+        .execute_contract(
+            admin.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::AdminBatchClaim { entries },
+            &[],
+        )
+        .unwrap();
+
+    // one event per claimed name
+    let claims = res
+        .events
+        .iter()
+        .filter(|event| event.ty == "wasm-admin_claim")
+        .map(|event| {
+            event
+                .attributes
+                .iter()
+                .filter(|attr| attr.key != "_contract_addr")
+                .map(|attr| (attr.key.as_str(), attr.value.as_str()))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        claims,
+        vec![
+            vec![("name", "osmosis"), ("owner", "bobaddr")],
+            vec![
+                ("name", "alice"),
+                ("owner", "aliceaddr"),
+                ("provider", "twitter"),
+                ("id", "1234")
+            ],
+        ]
+    );
+
+    assert_eq!(owner(&app, "osmosis").unwrap(), bob.to_string());
+    assert_eq!(owner(&app, "alice").unwrap(), alice.to_string());
+
+    // admin is recorded as the original claimer, along with the identity of the entry
+    let metadata = |app: &BasicApp, name: &str| -> Metadata {
+        let NftInfoResponse { extension, .. }: NftInfoResponse<Metadata> = app
+            .wrap()
+            .query_wasm_smart(
+                name_nft_contract_addr.clone(),
+                &icns_name_nft::QueryMsg::NftInfo {
+                    token_id: name.to_string(),
+                },
+            )
+            .unwrap();
+        extension
+    };
+    assert_eq!(
+        metadata(&app, "osmosis"),
+        Metadata {
+            minted_at: Some(app.block_info().time),
+            original_claimer: Some(admin.to_string()),
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        metadata(&app, "alice"),
+        Metadata {
+            minted_at: Some(app.block_info().time),
+            original_claimer: Some(admin.to_string()),
+            identity: Some(IdentityRef {
+                provider: "twitter".to_string(),
+                subject_id: "1234".to_string(),
+            }),
+            ..Default::default()
+        }
+    );
+
+    let NameByIdentityResponse { name } = app
+        .wrap()
+        .query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::NameByIdentity {
+                provider: "twitter".to_string(),
+                id: "1234".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(name, "alice");
+
+    // claimed names and identities can not be claimed again
+    let err = app
+        .execute_contract(
+            admin.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::AdminBatchClaim {
+                entries: vec![entry("alice", &bob, None)],
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NameAlreadyClaimed {
+            name: "alice".to_string()
+        }
+    );
+
+    // the whole batch fails if any entry fails
+    let err = app
+        .execute_contract(
+            admin,
+            registrar_contract_addr,
+            &ExecuteMsg::AdminBatchClaim {
+                entries: vec![
+                    entry("carol", &bob, None),
+                    entry("dave", &bob, Some(("twitter", "1234"))),
+                ],
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::DuplicatedIdentity {
+            provider: "twitter".to_string(),
+            id: "1234".to_string()
+        }
+    );
+    assert!(owner(&app, "carol").is_err());
+}
@@ -0,0 +1,486 @@
+#![cfg(test)]
+
+use cosmwasm_std::{Addr, Binary, Coin, StdResult, Uint128};
+use cw721::{NftInfoResponse, OwnerOfResponse};
+use cw_multi_test::{AppBuilder, BasicApp, Executor};
+use icns_name_nft::msg::{ICNSNameExecuteMsg, Metadata, NameViolation};
+
+use crate::{
+    auction::sealed_bid,
+    msg::{AuctionResponse, BidResponse, ExecuteMsg, QueryMsg},
+    state::{RevealedBid, SealedBid},
+    tests::helpers::{
+        default_contracts_setup, fixtures::*, name_nft_contract, registrar_contract, verify_all,
+    },
+    ContractError,
+};
+
+#[test]
+fn sealed_bid_auction() {
+    let bob = Addr::unchecked("bobaddr");
+    let alice = Addr::unchecked("aliceaddr");
+    let carol = Addr::unchecked("caroladdr");
+    let mut app = AppBuilder::default().build(|router, _, storage| {
+        for addr in [&bob, &alice, &carol] {
+            router
+                .bank
+                .init_balance(storage, addr, vec![Coin::new(10_000, "uosmo")])
+                .unwrap();
+        }
+    });
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+
+    let (name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![],
+    );
+
+    let owner = |app: &BasicApp, name: &str| -> StdResult<_> {
+        let OwnerOfResponse { owner, .. } = app.wrap().query_wasm_smart(
+            name_nft_contract_addr.clone(),
+            &icns_name_nft::QueryMsg::OwnerOf {
+                token_id: name.to_string(),
+                include_expired: None,
+            },
+        )?;
+
+        Ok(owner)
+    };
+
+    let metadata = |app: &BasicApp, name: &str| -> Metadata {
+        let NftInfoResponse { extension, .. }: NftInfoResponse<Metadata> = app
+            .wrap()
+            .query_wasm_smart(
+                name_nft_contract_addr.clone(),
+                &icns_name_nft::QueryMsg::NftInfo {
+                    token_id: name.to_string(),
+                },
+            )
+            .unwrap();
+        extension
+    };
+
+    let balance = |app: &BasicApp, addr: &Addr| -> u128 {
+        app.wrap()
+            .query_balance(addr, "uosmo")
+            .unwrap()
+            .amount
+            .u128()
+    };
+
+    let start_auction = |app: &mut BasicApp, sender: &Addr, name: &str| {
+        app.execute_contract(
+            sender.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::StartAuction {
+                name: name.to_string(),
+                denom: "uosmo".to_string(),
+                min_bid: Uint128::new(100),
+                bid_duration: 100,
+                reveal_duration: 100,
+            },
+            &[],
+        )
+    };
+
+    let place_bid = |app: &mut BasicApp, bidder: &Addr, amount: u128, deposit: u128| {
+        app.execute_contract(
+            bidder.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::PlaceBid {
+                name: "icns".to_string(),
+                sealed_bid: Binary(sealed_bid("icns", bidder, Uint128::new(amount), b"salt")),
+            },
+            &[Coin::new(deposit, "uosmo")],
+        )
+    };
+
+    let reveal_bid = |app: &mut BasicApp, bidder: &Addr, amount: u128, salt: &[u8]| {
+        app.execute_contract(
+            bidder.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::RevealBid {
+                name: "icns".to_string(),
+                amount: Uint128::new(amount),
+                salt: Binary::from(salt),
+            },
+            &[],
+        )
+    };
+
+    let settle = |app: &mut BasicApp| {
+        app.execute_contract(
+            bob.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::SettleAuction {
+                name: "icns".to_string(),
+            },
+            &[],
+        )
+    };
+
+    let auction = |app: &BasicApp| -> AuctionResponse {
+        app.wrap()
+            .query_wasm_smart(
+                registrar_contract_addr.clone(),
+                &QueryMsg::Auction {
+                    name: "icns".to_string(),
+                },
+            )
+            .unwrap()
+    };
+
+    let next_seconds = |app: &mut BasicApp, seconds: u64| {
+        app.update_block(|block| block.time = block.time.plus_seconds(seconds));
+    };
+
+    // only admin can start auction
+    let err = start_auction(&mut app, &bob, "icns").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Unauthorized {}
+    );
+
+    let admin = Addr::unchecked(&admins[0]);
+    start_auction(&mut app, &admin, "icns").unwrap();
+
+    let err = start_auction(&mut app, &admin, "icns").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NameInAuction {
+            name: "icns".to_string()
+        }
+    );
+
+    // auctioned name can not be claimed
+    let verifying_msg = format!(
+        r#"{{"name":"icns","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"1","expiry":{{"at_height":1000000}},"nonce":"1"}}"#,
+    );
+    let err = app
+        .execute_contract(
+            bob.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: "icns".to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
+                referral: None,
+                secret: None,
+                owner: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NameInAuction {
+            name: "icns".to_string()
+        }
+    );
+
+    // claimed name can not be auctioned
+    let verifying_msg = format!(
+        r#"{{"name":"bob","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"1","expiry":{{"at_height":1000000}},"nonce":"1"}}"#,
+    );
+    app.execute_contract(
+        bob.clone(),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::Claim {
+            name: "bob".to_string(),
+            verifying_msg: verifying_msg.clone(),
+            verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
+            referral: None,
+            secret: None,
+            owner: None,
+        },
+        &[],
+    )
+    .unwrap();
+    let err = start_auction(&mut app, &admin, "bob").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NameAlreadyClaimed {
+            name: "bob".to_string()
+        }
+    );
+
+    // place sealed bids with deposits hiding the bid amounts
+    place_bid(&mut app, &bob, 500, 1_000).unwrap();
+    place_bid(&mut app, &alice, 700, 800).unwrap();
+    place_bid(&mut app, &carol, 300, 300).unwrap();
+
+    let err = place_bid(&mut app, &bob, 600, 1_000).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::BidAlreadyPlaced {
+            name: "icns".to_string()
+        }
+    );
+
+    let BidResponse { bid } = app
+        .wrap()
+        .query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::Bid {
+                name: "icns".to_string(),
+                bidder: bob.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        bid,
+        Some(SealedBid {
+            sealed_bid: Binary(sealed_bid("icns", &bob, Uint128::new(500), b"salt")),
+            deposit: Uint128::new(1_000),
+        })
+    );
+
+    // bids can not be revealed while bidding
+    let err = reveal_bid(&mut app, &bob, 500, b"salt").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NotRevealPeriod {
+            name: "icns".to_string()
+        }
+    );
+
+    next_seconds(&mut app, 100);
+
+    // bids can not be placed after bidding ends
+    let err = place_bid(&mut app, &bob, 100, 100).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::BiddingEnded {
+            name: "icns".to_string()
+        }
+    );
+
+    // revealed bid must match the sealed bid
+    let err = reveal_bid(&mut app, &bob, 500, b"wrong").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidBid {
+            msg: "revealed bid does not match the sealed bid".to_string()
+        }
+    );
+
+    // the highest bid gets deposit exceeding the bid amount refunded
+    reveal_bid(&mut app, &bob, 500, b"salt").unwrap();
+    assert_eq!(balance(&app, &bob), 9_500);
+
+    // outbid bid gets refunded in full
+    reveal_bid(&mut app, &alice, 700, b"salt").unwrap();
+    assert_eq!(balance(&app, &bob), 10_000);
+    assert_eq!(balance(&app, &alice), 9_300);
+    assert_eq!(
+        auction(&app).auction.unwrap().highest_bid,
+        Some(RevealedBid {
+            bidder: alice.clone(),
+            amount: Uint128::new(700),
+        })
+    );
+
+    // auction can not be settled before reveal ends
+    let err = settle(&mut app).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::AuctionNotEnded {
+            name: "icns".to_string()
+        }
+    );
+
+    next_seconds(&mut app, 100);
+
+    // carol's unrevealed bid is refunded once settled
+    assert_eq!(balance(&app, &carol), 9_700);
+    settle(&mut app).unwrap();
+    assert_eq!(owner(&app, "icns").unwrap(), alice);
+    // the winner is recorded as the original claimer
+    assert_eq!(
+        metadata(&app, "icns").original_claimer,
+        Some(alice.to_string())
+    );
+    assert_eq!(balance(&app, &carol), 10_000);
+    assert_eq!(balance(&app, &alice), 9_300);
+    assert_eq!(balance(&app, &registrar_contract_addr), 700);
+    assert_eq!(auction(&app).auction, None);
+}
+
+#[test]
+fn deposits_can_be_recovered_when_auction_can_not_be_settled() {
+    let bob = Addr::unchecked("bobaddr");
+    let alice = Addr::unchecked("aliceaddr");
+    let carol = Addr::unchecked("caroladdr");
+    let mut app = AppBuilder::default().build(|router, _, storage| {
+        for addr in [&bob, &alice, &carol] {
+            router
+                .bank
+                .init_balance(storage, addr, vec![Coin::new(10_000, "uosmo")])
+                .unwrap();
+        }
+    });
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+    let admin = Addr::unchecked(&admins[0]);
+
+    let (name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![],
+    );
+
+    let balance = |app: &BasicApp, addr: &Addr| -> u128 {
+        app.wrap()
+            .query_balance(addr, "uosmo")
+            .unwrap()
+            .amount
+            .u128()
+    };
+
+    let execute = |app: &mut BasicApp, sender: &Addr, msg: ExecuteMsg, funds: &[Coin]| {
+        app.execute_contract(sender.clone(), registrar_contract_addr.clone(), &msg, funds)
+    };
+
+    let start_auction = |name: &str| ExecuteMsg::StartAuction {
+        name: name.to_string(),
+        denom: "uosmo".to_string(),
+        min_bid: Uint128::new(100),
+        bid_duration: 100,
+        reveal_duration: 100,
+    };
+
+    let place_bid = |bidder: &Addr, amount: u128| ExecuteMsg::PlaceBid {
+        name: "icns".to_string(),
+        sealed_bid: Binary(sealed_bid("icns", bidder, Uint128::new(amount), b"salt")),
+    };
+
+    let reveal_bid = |amount: u128| ExecuteMsg::RevealBid {
+        name: "icns".to_string(),
+        amount: Uint128::new(amount),
+        salt: Binary::from(b"salt"),
+    };
+
+    let withdraw_bid = || ExecuteMsg::WithdrawBid {
+        name: "icns".to_string(),
+    };
+
+    // name rejected by the name nft contract can not be auctioned
+    let err = execute(&mut app, &admin, start_auction("ICNS"), &[]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NameNotAllowed {
+            name: "ICNS".to_string(),
+            violation: NameViolation::NotNormalized {
+                normalized: "icns".to_string()
+            }
+        }
+    );
+
+    execute(&mut app, &admin, start_auction("icns"), &[]).unwrap();
+    execute(
+        &mut app,
+        &bob,
+        place_bid(&bob, 500),
+        &[Coin::new(1_000, "uosmo")],
+    )
+    .unwrap();
+    execute(
+        &mut app,
+        &alice,
+        place_bid(&alice, 700),
+        &[Coin::new(700, "uosmo")],
+    )
+    .unwrap();
+    execute(
+        &mut app,
+        &carol,
+        place_bid(&carol, 300),
+        &[Coin::new(300, "uosmo")],
+    )
+    .unwrap();
+
+    // bids can not be withdrawn before reveal ends
+    let err = execute(&mut app, &carol, withdraw_bid(), &[]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::BidNotWithdrawable {
+            name: "icns".to_string()
+        }
+    );
+
+    app.update_block(|block| block.time = block.time.plus_seconds(100));
+    execute(&mut app, &bob, reveal_bid(500), &[]).unwrap();
+    execute(&mut app, &alice, reveal_bid(700), &[]).unwrap();
+    app.update_block(|block| block.time = block.time.plus_seconds(100));
+
+    // minting fails once the name nft contract is paused, so the auction can not be settled
+    app.execute_contract(
+        admin.clone(),
+        name_nft_contract_addr,
+        &icns_name_nft::ExecuteMsg::Extension {
+            msg: ICNSNameExecuteMsg::SetPaused { paused: true },
+        },
+        &[],
+    )
+    .unwrap();
+    execute(
+        &mut app,
+        &bob,
+        ExecuteMsg::SettleAuction {
+            name: "icns".to_string(),
+        },
+        &[],
+    )
+    .unwrap_err();
+
+    // deposits of the other bids can still be withdrawn
+    execute(&mut app, &carol, withdraw_bid(), &[]).unwrap();
+    assert_eq!(balance(&app, &carol), 10_000);
+    let err = execute(&mut app, &carol, withdraw_bid(), &[]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::BidNotFound {
+            name: "icns".to_string()
+        }
+    );
+    let err = execute(&mut app, &alice, withdraw_bid(), &[]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::HighestBidNotWithdrawable {
+            name: "icns".to_string()
+        }
+    );
+
+    // only admin can cancel auction, which refunds the highest bid as well
+    let cancel_auction = || ExecuteMsg::CancelAuction {
+        name: "icns".to_string(),
+    };
+    let err = execute(&mut app, &bob, cancel_auction(), &[]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Unauthorized {}
+    );
+    execute(&mut app, &admin, cancel_auction(), &[]).unwrap();
+    assert_eq!(balance(&app, &alice), 10_000);
+    assert_eq!(balance(&app, &bob), 10_000);
+    assert_eq!(balance(&app, &registrar_contract_addr), 0);
+
+    let AuctionResponse { auction } = app
+        .wrap()
+        .query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::Auction {
+                name: "icns".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(auction, None);
+}
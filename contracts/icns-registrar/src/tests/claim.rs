@@ -2,16 +2,18 @@
 
 use crate::{
     msg::{NameByTwitterIdResponse, QueryMsg},
-    tests::helpers::{default_contracts_setup, fixtures::*, ToBinary},
+    tests::helpers::{
+        default_contracts_setup, fixtures::*, recover_all, verifier_pubkey, verify_all, ToBinary,
+    },
 };
 use cosmrs::crypto::secp256k1::SigningKey;
 use cosmwasm_std::{Addr, Binary, Coin, Decimal, StdError, StdResult};
 use cw721::{NftInfoResponse, OwnerOfResponse};
 use cw_multi_test::{AppBuilder, BasicApp, Executor};
-use icns_name_nft::msg::{ICNSNameExecuteMsg, Metadata};
+use icns_name_nft::msg::{ICNSNameExecuteMsg, IdentityRef, Metadata, Profile};
 
 use crate::{
-    msg::{ExecuteMsg, InstantiateMsg, Verification},
+    msg::{ExecuteMsg, IdentityProviderInfo, InstantiateMsg, Verification},
     tests::helpers::{name_nft_contract, registrar_contract},
     ContractError,
 };
@@ -25,8 +27,13 @@ fn claim_name() {
     let admins = vec!["admin1".to_string(), "admin2".to_string()];
 
     // setup name nft contract
-    let (name_nft_contract_addr, registrar_contract_addr) =
-        default_contracts_setup(&mut app, name_nft_code_id, registrar_code_id, admins, None);
+    let (name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins,
+        vec![],
+    );
 
     let owner = |app: &BasicApp, name: String| -> StdResult<_> {
         let OwnerOfResponse { owner, .. } = app.wrap().query_wasm_smart(
@@ -45,7 +52,7 @@ fn claim_name() {
         verifiers
             .iter()
             .map(|verifier| Verification {
-                public_key: verifier.to_binary(),
+                public_key: Some(verifier.to_binary()),
                 signature: verifier.sign(verifying_msg.as_bytes()).unwrap().to_binary(),
             })
             .collect()
@@ -93,6 +100,8 @@ fn claim_name() {
                 verifying_msg: verifying_msg.clone(),
                 verifications: verify_all(&verifying_msg, vec![verifier1(), verifier3()]),
                 referral: None,
+                secret: None,
+                owner: None,
             },
             &[],
         )
@@ -108,7 +117,7 @@ fn claim_name() {
 
     // execute claim with wrong verifying msg info
     let verifying_msg = format!(
-        r#"{{"name":"alice","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        r#"{{"name":"alice","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
     );
 
     let err = app
@@ -120,6 +129,8 @@ fn claim_name() {
                 verifying_msg: verifying_msg.clone(),
                 verifications: verify_all(&verifying_msg, vec![verifier1(), verifier3()]),
                 referral: None,
+                secret: None,
+                owner: None,
             },
             &[],
         )
@@ -134,7 +145,7 @@ fn claim_name() {
 
     // execute claim with verification from non-verifier
     let verifying_msg = format!(
-        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
     );
 
     let err = app
@@ -146,6 +157,8 @@ fn claim_name() {
                 verifying_msg: verifying_msg.clone(),
                 verifications: verify_all(&verifying_msg, vec![verifier1(), non_verifier()]),
                 referral: None,
+                secret: None,
+                owner: None,
             },
             &[],
         )
@@ -160,7 +173,7 @@ fn claim_name() {
 
     // execute claim with non passing verification below threshold
     let verifying_msg = format!(
-        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
     );
 
     let err = app
@@ -172,6 +185,8 @@ fn claim_name() {
                 verifying_msg: verifying_msg.clone(),
                 verifications: verify_all(&verifying_msg, vec![verifier1()]),
                 referral: None,
+                secret: None,
+                owner: None,
             },
             &[],
         )
@@ -188,7 +203,7 @@ fn claim_name() {
     // execute claim with . in name
     let bob_name_with_dot = "bob.dylan";
     let verifying_msg = format!(
-        r#"{{"name":"{bob_name_with_dot}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        r#"{{"name":"{bob_name_with_dot}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
     );
 
     let err = app
@@ -200,20 +215,23 @@ fn claim_name() {
                 verifying_msg: verifying_msg.clone(),
                 verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
                 referral: None,
+                secret: None,
+                owner: None,
             },
             &[],
         )
         .unwrap_err();
 
     assert_eq!(
-        err.downcast_ref::<icns_name_nft::error::ContractError>()
-            .unwrap(),
-        &icns_name_nft::error::ContractError::InvalidName {}
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidName {
+            name: bob_name_with_dot.to_string(),
+        }
     );
 
     // execute claim with passing verification
     let verifying_msg = format!(
-        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
     );
 
     app.execute_contract(
@@ -224,6 +242,8 @@ fn claim_name() {
             verifying_msg: verifying_msg.clone(),
             verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
             referral: None,
+            secret: None,
+            owner: None,
         },
         &[],
     )
@@ -238,7 +258,7 @@ fn claim_name() {
     // execute claim with passing but same name -> should error
     let new_twitter_id = "11111";
     let verifying_msg = format!(
-        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"{new_twitter_id}"}}"#,
+        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{new_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{new_twitter_id}"}}"#,
     );
 
     let err = app
@@ -250,21 +270,24 @@ fn claim_name() {
                 verifying_msg: verifying_msg.clone(),
                 verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
                 referral: None,
+                secret: None,
+                owner: None,
             },
             &[],
         )
         .unwrap_err();
 
     assert_eq!(
-        err.downcast_ref::<icns_name_nft::error::ContractError>()
-            .unwrap(),
-        &(cw721_base::ContractError::Claimed {}.into())
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NameAlreadyClaimed {
+            name: bob_name.to_string()
+        }
     );
 
     // execute claim with passing(different name) but with same unique twitter id -> should error
     let new_name = "new_name".to_string();
     let verifying_msg = format!(
-        r#"{{"name":"{new_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        r#"{{"name":"{new_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
     );
 
     let err = app
@@ -276,6 +299,8 @@ fn claim_name() {
                 verifying_msg: verifying_msg.clone(),
                 verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
                 referral: None,
+                secret: None,
+                owner: None,
             },
             &[],
         )
@@ -283,8 +308,9 @@ fn claim_name() {
 
     assert_eq!(
         err.downcast_ref::<ContractError>().unwrap(),
-        &ContractError::DuplicatedTwitterId {
-            msg: format!("unique twitter id `{}` is already used", unique_twitter_id)
+        &ContractError::DuplicatedIdentity {
+            provider: "twitter".to_string(),
+            id: unique_twitter_id.to_string()
         }
     );
 }
@@ -303,7 +329,7 @@ fn admin_bypass_verifications() {
         name_nft_code_id,
         registrar_code_id,
         admins.clone(),
-        None,
+        vec![],
     );
 
     // execute claim with passing verification
@@ -312,7 +338,7 @@ fn admin_bypass_verifications() {
     let multitest_chain_id = "cosmos-testnet-14002";
     let unique_twitter_id = "1234567890";
     let verifying_msg = format!(
-        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
     );
 
     // try claiming with non admin with no verifications, this should error
@@ -324,6 +350,8 @@ fn admin_bypass_verifications() {
             verifying_msg: verifying_msg.clone(),
             verifications: Vec::new(),
             referral: None,
+            secret: None,
+            owner: None,
         },
         &[],
     )
@@ -338,12 +366,90 @@ fn admin_bypass_verifications() {
             verifying_msg,
             verifications: Vec::new(),
             referral: None,
+            secret: None,
+            owner: None,
         },
         &[],
     )
     .unwrap();
 }
 
+#[test]
+fn admin_can_not_claim_with_bound_identity() {
+    // setup contracts
+    let mut app = BasicApp::default();
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+
+    let (name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![],
+    );
+
+    let bob = Addr::unchecked("bobaddr");
+    let multitest_chain_id = "cosmos-testnet-14002";
+    let unique_twitter_id = "1234567890";
+    let verifying_msg = |name: &str| {
+        format!(
+            r#"{{"name":"{name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{name}"}}"#,
+        )
+    };
+    let admin_claim = |app: &mut BasicApp, name: &str| {
+        app.execute_contract(
+            Addr::unchecked(admins[0].clone()),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg(name),
+                verifications: Vec::new(),
+                referral: None,
+                secret: None,
+                owner: None,
+            },
+            &[],
+        )
+    };
+
+    admin_claim(&mut app, "bob").unwrap();
+
+    // claiming another name with the same identity should error, even for admin
+    let err = admin_claim(&mut app, "bobby").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::DuplicatedIdentity {
+            provider: "twitter".to_string(),
+            id: unique_twitter_id.to_string(),
+        }
+    );
+
+    // identity stays bound to the first name
+    let res: NameByTwitterIdResponse = app
+        .wrap()
+        .query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::NameByTwitterId {
+                twitter_id: unique_twitter_id.to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(res.name, "bob");
+
+    // the other name is not minted
+    app.wrap()
+        .query_wasm_smart::<OwnerOfResponse>(
+            name_nft_contract_addr,
+            &icns_name_nft::QueryMsg::OwnerOf {
+                token_id: "bobby".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap_err();
+}
+
 #[test]
 fn claim_name_with_fee() {
     let bob = Addr::unchecked("bobaddr");
@@ -372,7 +478,7 @@ fn claim_name_with_fee() {
         name_nft_code_id,
         registrar_code_id,
         admins,
-        Some(fee.clone()),
+        vec![fee.clone()],
     );
 
     let owner = |app: &BasicApp, name: String| -> StdResult<_> {
@@ -392,7 +498,7 @@ fn claim_name_with_fee() {
         verifiers
             .iter()
             .map(|verifier| Verification {
-                public_key: verifier.to_binary(),
+                public_key: Some(verifier.to_binary()),
                 signature: verifier.sign(verifying_msg.as_bytes()).unwrap().to_binary(),
             })
             .collect()
@@ -412,7 +518,7 @@ fn claim_name_with_fee() {
 
     // execute claim invalid fee
     let verifying_msg = format!(
-        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
     );
 
     // no funds
@@ -425,6 +531,8 @@ fn claim_name_with_fee() {
                 verifying_msg: verifying_msg.clone(),
                 verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
                 referral: None,
+                secret: None,
+                owner: None,
             },
             &[],
         )
@@ -433,7 +541,7 @@ fn claim_name_with_fee() {
     assert_eq!(
         err.downcast_ref::<ContractError>().unwrap(),
         &ContractError::InvalidFee {
-            fee_required: fee.clone()
+            fee_required: vec![fee.clone()]
         }
     );
 
@@ -447,6 +555,8 @@ fn claim_name_with_fee() {
                 verifying_msg: verifying_msg.clone(),
                 verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
                 referral: None,
+                secret: None,
+                owner: None,
             },
             &[Coin::new(100, "uion")],
         )
@@ -455,29 +565,7 @@ fn claim_name_with_fee() {
     assert_eq!(
         err.downcast_ref::<ContractError>().unwrap(),
         &ContractError::InvalidFee {
-            fee_required: fee.clone()
-        }
-    );
-
-    // over paid
-    let err = app
-        .execute_contract(
-            bob.clone(),
-            registrar_contract_addr.clone(),
-            &ExecuteMsg::Claim {
-                name: bob_name.to_string(),
-                verifying_msg: verifying_msg.clone(),
-                verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
-                referral: None,
-            },
-            &[Coin::new(100_000_000_000, "uosmo")],
-        )
-        .unwrap_err();
-
-    assert_eq!(
-        err.downcast_ref::<ContractError>().unwrap(),
-        &ContractError::InvalidFee {
-            fee_required: fee.clone()
+            fee_required: vec![fee.clone()]
         }
     );
 
@@ -491,6 +579,8 @@ fn claim_name_with_fee() {
                 verifying_msg: verifying_msg.clone(),
                 verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
                 referral: None,
+                secret: None,
+                owner: None,
             },
             &[Coin::new(1, "uosmo")],
         )
@@ -499,25 +589,33 @@ fn claim_name_with_fee() {
     assert_eq!(
         err.downcast_ref::<ContractError>().unwrap(),
         &ContractError::InvalidFee {
-            fee_required: fee.clone()
+            fee_required: vec![fee.clone()]
         }
     );
 
-    // exact fee
+    // over paid, the rest is refunded
     app.execute_contract(
         bob.clone(),
-        registrar_contract_addr,
+        registrar_contract_addr.clone(),
         &ExecuteMsg::Claim {
             name: bob_name.to_string(),
             verifying_msg: verifying_msg.clone(),
             verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
             referral: None,
+            secret: None,
+            owner: None,
         },
-        &[fee],
+        &[Coin::new(100_000_000_000, "uosmo"), Coin::new(100, "uion")],
     )
     .unwrap();
 
     assert_eq!(owner(&app, bob_name.to_string()).unwrap(), bob);
+    assert_eq!(
+        app.wrap()
+            .query_all_balances(&registrar_contract_addr)
+            .unwrap(),
+        vec![fee]
+    );
 }
 
 #[test]
@@ -543,7 +641,7 @@ fn claim_name_without_fee() {
         verifiers
             .iter()
             .map(|verifier| Verification {
-                public_key: verifier.to_binary(),
+                public_key: Some(verifier.to_binary()),
                 signature: verifier.sign(verifying_msg.as_bytes()).unwrap().to_binary(),
             })
             .collect()
@@ -554,14 +652,19 @@ fn claim_name_without_fee() {
     let admins = vec!["admin1".to_string(), "admin2".to_string()];
 
     // setup name nft contract
-    let (_name_nft_contract_addr, registrar_contract_addr) =
-        default_contracts_setup(&mut app, name_nft_code_id, registrar_code_id, admins, None);
+    let (_name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins,
+        vec![],
+    );
 
     let bob_name = "bob";
     let multitest_chain_id = "cosmos-testnet-14002";
     let unique_twitter_id = "1234567890";
     let verifying_msg = format!(
-        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
     );
 
     app.execute_contract(
@@ -572,6 +675,8 @@ fn claim_name_without_fee() {
             verifying_msg: verifying_msg.clone(),
             verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
             referral: None,
+            secret: None,
+            owner: None,
         },
         &[],
     )
@@ -580,7 +685,7 @@ fn claim_name_without_fee() {
     let alice_name = "alice";
     let unique_twitter_id = "1234567891";
     let verifying_msg = format!(
-        r#"{{"name":"{alice_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        r#"{{"name":"{alice_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
     );
 
     // try sending fund when fee is not set, it should not error
@@ -592,6 +697,8 @@ fn claim_name_without_fee() {
             verifying_msg: verifying_msg.clone(),
             verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
             referral: None,
+            secret: None,
+            owner: None,
         },
         &[Coin::new(100, "uion")],
     )
@@ -648,7 +755,7 @@ fn claim_name_with_referral() {
         verifiers
             .iter()
             .map(|verifier| Verification {
-                public_key: verifier.to_binary(),
+                public_key: Some(verifier.to_binary()),
                 signature: verifier.sign(verifying_msg.as_bytes()).unwrap().to_binary(),
             })
             .collect()
@@ -661,12 +768,15 @@ fn claim_name_with_referral() {
             Addr::unchecked(admins[0].clone()),
             &InstantiateMsg {
                 name_nft_addr: name_nft_contract_addr.to_string(),
-                verifier_pubkeys: vec![verifier1(), verifier2(), verifier3(), verifier4()]
-                    .iter()
-                    .map(|v| v.to_binary())
-                    .collect(),
-                verification_threshold: Decimal::percent(50),
-                fee: None,
+                identity_providers: vec![IdentityProviderInfo {
+                    provider: "twitter".to_string(),
+                    verifier_pubkeys: [verifier1(), verifier2(), verifier3(), verifier4()]
+                        .iter()
+                        .map(|v| verifier_pubkey(v, 1))
+                        .collect(),
+                    verification_threshold: Decimal::percent(50),
+                }],
+                fees: vec![],
             },
             &[],
             "registar",
@@ -702,7 +812,7 @@ fn claim_name_with_referral() {
 
     // execute claim with passing verification
     let verifying_msg = format!(
-        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
     );
 
     // no referral for setting up valid referral
@@ -714,6 +824,8 @@ fn claim_name_with_referral() {
             verifying_msg: verifying_msg.clone(),
             verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
             referral: None,
+            secret: None,
+            owner: None,
         },
         &[],
     )
@@ -722,13 +834,23 @@ fn claim_name_with_referral() {
     assert_eq!(owner(&app, bob_name.to_string()).unwrap(), bob);
     assert_eq!(
         metadata(&app, bob_name.to_string()).unwrap(),
-        Metadata { referral: None }
+        Metadata {
+            referral: None,
+            expiry: None,
+            minted_at: Some(app.block_info().time),
+            original_claimer: Some(bob.to_string()),
+            identity: Some(IdentityRef {
+                provider: "twitter".to_string(),
+                subject_id: unique_twitter_id.to_string(),
+            }),
+            profile: Profile::default(),
+        }
     );
 
     let bobby_name = "bobby";
     // execute claim with passing verification
     let verifying_msg = format!(
-        r#"{{"name":"{bobby_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"2222"}}"#,
+        r#"{{"name":"{bobby_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"2222","expiry":{{"at_height":1000000}},"nonce":"2222"}}"#,
     );
 
     // referral as non-existing icns name
@@ -741,6 +863,8 @@ fn claim_name_with_referral() {
                 verifying_msg: verifying_msg.clone(),
                 verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
                 referral: Some("wrong_bobby".to_string()),
+                secret: None,
+                owner: None,
             },
             &[],
         )
@@ -762,6 +886,8 @@ fn claim_name_with_referral() {
             verifying_msg: verifying_msg.clone(),
             verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
             referral: Some(bob_name.to_string()),
+            secret: None,
+            owner: None,
         },
         &[],
     )
@@ -770,7 +896,15 @@ fn claim_name_with_referral() {
     assert_eq!(
         metadata(&app, bobby_name.to_string()).unwrap(),
         Metadata {
-            referral: Some(bob_name.to_string())
+            referral: Some(bob_name.to_string()),
+            expiry: None,
+            minted_at: Some(app.block_info().time),
+            original_claimer: Some(bob.to_string()),
+            identity: Some(IdentityRef {
+                provider: "twitter".to_string(),
+                subject_id: "2222".to_string(),
+            }),
+            profile: Profile::default(),
         }
     );
 }
@@ -784,15 +918,20 @@ fn try_claiming_with_unpassed_threshold() {
     let admins = vec!["admin1".to_string(), "admin2".to_string()];
 
     // setup name nft contract
-    let (_name_nft_contract_addr, registrar_contract_addr) =
-        default_contracts_setup(&mut app, name_nft_code_id, registrar_code_id, admins, None);
+    let (_name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins,
+        vec![],
+    );
 
     // set up verifiers
     let verify_all = |verifying_msg: &str, verifiers: Vec<SigningKey>| -> Vec<Verification> {
         verifiers
             .iter()
             .map(|verifier| Verification {
-                public_key: verifier.to_binary(),
+                public_key: Some(verifier.to_binary()),
                 signature: verifier.sign(verifying_msg.as_bytes()).unwrap().to_binary(),
             })
             .collect()
@@ -803,7 +942,7 @@ fn try_claiming_with_unpassed_threshold() {
     let unique_twitter_id = "1234567890";
 
     let verifying_msg = format!(
-        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
     );
 
     let err = app
@@ -815,6 +954,8 @@ fn try_claiming_with_unpassed_threshold() {
                 verifying_msg: verifying_msg.clone(),
                 verifications: verify_all(&verifying_msg, vec![verifier3()]),
                 referral: None,
+                secret: None,
+                owner: None,
             },
             &[],
         )
@@ -828,3 +969,209 @@ fn try_claiming_with_unpassed_threshold() {
         }
     );
 }
+
+#[test]
+fn claim_name_with_recoverable_signatures() {
+    let mut app = BasicApp::default();
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+    let bob = Addr::unchecked("bobaddr");
+
+    let (name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins,
+        vec![],
+    );
+
+    let verifying_msg = |name: &str| {
+        format!(
+            r#"{{"name":"{name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"{name}","expiry":{{"at_height":1000000}},"nonce":"{name}"}}"#,
+        )
+    };
+    let claim = |app: &mut BasicApp, name: &str, verifications: Vec<Verification>| {
+        app.execute_contract(
+            bob.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg(name),
+                verifications,
+                referral: None,
+                secret: None,
+                owner: None,
+            },
+            &[],
+        )
+    };
+
+    // signature without public key must be recoverable
+    let err = claim(
+        &mut app,
+        "alice",
+        verify_all(&verifying_msg("alice"), vec![verifier1()])
+            .into_iter()
+            .map(|verification| Verification {
+                public_key: None,
+                ..verification
+            })
+            .collect(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidRecoverableSignatureLength {}
+    );
+
+    // the same verifier can not be counted twice with a different signature
+    let verification = verify_all(&verifying_msg("alice"), vec![verifier1()]).remove(0);
+    let malleated = Verification {
+        signature: Binary(high_s(&verification.signature)),
+        ..verification.clone()
+    };
+    let err = claim(&mut app, "alice", vec![verification, malleated]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::DuplicatedVerifier {
+            public_key: verifier1().to_binary()
+        }
+    );
+
+    // recovered signers must be verifiers
+    let err = claim(
+        &mut app,
+        "alice",
+        recover_all(&verifying_msg("alice"), vec![verifier1(), non_verifier()]),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NotAVerifierPublicKey {
+            public_key: non_verifier().to_binary()
+        }
+    );
+
+    // recoverable and regular verifications can be mixed
+    claim(
+        &mut app,
+        "alice",
+        [
+            recover_all(&verifying_msg("alice"), vec![verifier1()]),
+            verify_all(&verifying_msg("alice"), vec![verifier2()]),
+        ]
+        .concat(),
+    )
+    .unwrap();
+
+    let OwnerOfResponse { owner, .. } = app
+        .wrap()
+        .query_wasm_smart(
+            name_nft_contract_addr,
+            &icns_name_nft::QueryMsg::OwnerOf {
+                token_id: "alice".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(owner, bob.to_string());
+}
+
+// high_s returns the other valid signature of the same signer for the same message, with s negated
+fn high_s(signature: &[u8]) -> Vec<u8> {
+    // order of the secp256k1 curve
+    const N: [u8; 32] = [
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xfe, 0xba, 0xae, 0xdc, 0xe6, 0xaf, 0x48, 0xa0, 0x3b, 0xbf, 0xd2, 0x5e, 0x8c, 0xd0, 0x36,
+        0x41, 0x41,
+    ];
+    let mut s = [0u8; 32];
+    let mut borrow = 0i16;
+    for i in (0..32).rev() {
+        let diff = N[i] as i16 - signature[32 + i] as i16 - borrow;
+        borrow = (diff < 0) as i16;
+        s[i] = (diff + 256 * borrow) as u8;
+    }
+    [&signature[..32], &s].concat()
+}
+
+#[test]
+fn claim_name_on_behalf_of_owner() {
+    let relayer = Addr::unchecked("relayeraddr");
+    let alice = Addr::unchecked("aliceaddr");
+    let mut app = AppBuilder::default().build(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &relayer, vec![Coin::new(10_000, "uosmo")])
+            .unwrap();
+    });
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+
+    let (name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins,
+        vec![Coin::new(1_000, "uosmo")],
+    );
+
+    // relayer claims the name verified for alice, who receives the name
+    let verifying_msg = format!(
+        r#"{{"name":"alice","claimer":"{relayer}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"alice","expiry":{{"at_height":1000000}},"nonce":"alice","owner":"{alice}"}}"#,
+    );
+    let claim = |app: &mut BasicApp, owner: Option<&Addr>| {
+        app.execute_contract(
+            relayer.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: "alice".to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
+                referral: None,
+                secret: None,
+                owner: owner.map(|owner| owner.to_string()),
+            },
+            &[Coin::new(1_000, "uosmo")],
+        )
+    };
+
+    // owner must match the owner verified in the verifying msg
+    let err = claim(&mut app, None).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidVerifyingMessage {
+            msg: format!("owner mismatched: expected `none` but got `{alice}`")
+        }
+    );
+
+    let err = claim(&mut app, Some(&relayer)).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidVerifyingMessage {
+            msg: format!("owner mismatched: expected `{relayer}` but got `{alice}`")
+        }
+    );
+
+    claim(&mut app, Some(&alice)).unwrap();
+
+    let OwnerOfResponse { owner, .. } = app
+        .wrap()
+        .query_wasm_smart(
+            name_nft_contract_addr,
+            &icns_name_nft::QueryMsg::OwnerOf {
+                token_id: "alice".to_string(),
+                include_expired: None,
+            },
+        )
+        .unwrap();
+    assert_eq!(owner, alice.to_string());
+
+    // fee is paid by the relayer
+    assert_eq!(
+        app.wrap().query_balance(&relayer, "uosmo").unwrap(),
+        Coin::new(9_000, "uosmo")
+    );
+}
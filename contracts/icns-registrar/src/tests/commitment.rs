@@ -0,0 +1,260 @@
+#![cfg(test)]
+
+use cosmwasm_std::{Addr, Binary, StdResult};
+use cw721::OwnerOfResponse;
+use cw_multi_test::{BasicApp, Executor};
+
+use crate::{
+    checks::claim_commitment,
+    msg::{CommitmentPeriodResponse, CommitmentResponse, ExecuteMsg, QueryMsg},
+    state::CommitmentPeriod,
+    tests::helpers::{
+        default_contracts_setup, fixtures::*, name_nft_contract, registrar_contract,
+        test_only_admin, verify_all,
+    },
+    ContractError,
+};
+
+#[test]
+fn only_admin_can_set_commitment_period() {
+    test_only_admin(
+        ExecuteMsg::SetCommitmentPeriod {
+            period: Some(CommitmentPeriod {
+                min_blocks: 1,
+                max_blocks: 10,
+            }),
+        },
+        QueryMsg::CommitmentPeriod {},
+        CommitmentPeriodResponse { period: None },
+        CommitmentPeriodResponse {
+            period: Some(CommitmentPeriod {
+                min_blocks: 1,
+                max_blocks: 10,
+            }),
+        },
+    );
+}
+
+#[test]
+fn claim_requires_commitment_within_period() {
+    let bob = Addr::unchecked("bobaddr");
+    let alice = Addr::unchecked("aliceaddr");
+    let mut app = BasicApp::default();
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+
+    let (name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![],
+    );
+
+    let owner = |app: &BasicApp, name: &str| -> StdResult<_> {
+        let OwnerOfResponse { owner, .. } = app.wrap().query_wasm_smart(
+            name_nft_contract_addr.clone(),
+            &icns_name_nft::QueryMsg::OwnerOf {
+                token_id: name.to_string(),
+                include_expired: None,
+            },
+        )?;
+
+        Ok(owner)
+    };
+
+    let commit = |app: &mut BasicApp, committer: &Addr, commitment: Vec<u8>| {
+        app.execute_contract(
+            committer.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::CommitClaim {
+                commitment: Binary(commitment),
+            },
+            &[],
+        )
+    };
+
+    let claim = |app: &mut BasicApp,
+                 claimer: &Addr,
+                 name: &str,
+                 twitter_id: &str,
+                 secret: Option<&[u8]>| {
+        let verifying_msg = format!(
+            r#"{{"name":"{name}","claimer":"{claimer}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"{twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{twitter_id}"}}"#,
+        );
+        app.execute_contract(
+            claimer.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
+                referral: None,
+                secret: secret.map(Binary::from),
+                owner: None,
+            },
+            &[],
+        )
+    };
+
+    let next_blocks = |app: &mut BasicApp, blocks: u64| {
+        app.update_block(|block| block.height += blocks);
+    };
+
+    // invalid period is rejected
+    let err = app
+        .execute_contract(
+            Addr::unchecked(&admins[0]),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::SetCommitmentPeriod {
+                period: Some(CommitmentPeriod {
+                    min_blocks: 10,
+                    max_blocks: 1,
+                }),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidCommitmentPeriod {
+            msg: "max blocks must not be less than min blocks".to_string()
+        }
+    );
+
+    app.execute_contract(
+        Addr::unchecked(&admins[0]),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::SetCommitmentPeriod {
+            period: Some(CommitmentPeriod {
+                min_blocks: 2,
+                max_blocks: 10,
+            }),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // claim without secret is rejected
+    let err = claim(&mut app, &bob, "bob", "1", None).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::CommitmentRequired {}
+    );
+
+    // claim without commitment is rejected
+    let err = claim(&mut app, &bob, "bob", "1", Some(b"bobsecret")).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::CommitmentNotFound {
+            name: "bob".to_string()
+        }
+    );
+
+    let commit_height = app.block_info().height;
+    commit(&mut app, &bob, claim_commitment("bob", &bob, b"bobsecret")).unwrap();
+
+    let CommitmentResponse { height } = app
+        .wrap()
+        .query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::Commitment {
+                committer: bob.to_string(),
+                commitment: Binary(claim_commitment("bob", &bob, b"bobsecret")),
+            },
+        )
+        .unwrap();
+    assert_eq!(height, Some(commit_height));
+
+    // revealing before min delay is rejected
+    next_blocks(&mut app, 1);
+    let err = claim(&mut app, &bob, "bob", "1", Some(b"bobsecret")).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::CommitmentNotRevealable {
+            reveal_height: commit_height + 2
+        }
+    );
+
+    // others can not reveal with bob's commitment, even with the same secret
+    next_blocks(&mut app, 1);
+    let err = claim(&mut app, &alice, "bob", "2", Some(b"bobsecret")).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::CommitmentNotFound {
+            name: "bob".to_string()
+        }
+    );
+
+    // wrong secret does not match the commitment
+    claim(&mut app, &bob, "bob", "1", Some(b"wrongsecret")).unwrap_err();
+
+    claim(&mut app, &bob, "bob", "1", Some(b"bobsecret")).unwrap();
+    assert_eq!(owner(&app, "bob").unwrap(), bob);
+
+    // commitment can only be used once
+    let CommitmentResponse { height } = app
+        .wrap()
+        .query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::Commitment {
+                committer: bob.to_string(),
+                commitment: Binary(claim_commitment("bob", &bob, b"bobsecret")),
+            },
+        )
+        .unwrap();
+    assert_eq!(height, None);
+
+    // revealing after max age is rejected
+    let commit_height = app.block_info().height;
+    commit(
+        &mut app,
+        &alice,
+        claim_commitment("alice", &alice, b"alicesecret"),
+    )
+    .unwrap();
+    next_blocks(&mut app, 11);
+    let err = claim(&mut app, &alice, "alice", "2", Some(b"alicesecret")).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::CommitmentExpired {
+            expired_height: commit_height + 10
+        }
+    );
+
+    // committing again restarts the period
+    commit(
+        &mut app,
+        &alice,
+        claim_commitment("alice", &alice, b"alicesecret"),
+    )
+    .unwrap();
+    next_blocks(&mut app, 10);
+    claim(&mut app, &alice, "alice", "2", Some(b"alicesecret")).unwrap();
+    assert_eq!(owner(&app, "alice").unwrap(), alice);
+
+    // admin claim also requires commitment
+    let admin = Addr::unchecked(&admins[0]);
+    let err = app
+        .execute_contract(
+            admin.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: "admin".to_string(),
+                verifying_msg: "".to_string(),
+                verifications: vec![],
+                referral: None,
+                secret: Some(Binary::from(b"adminsecret")),
+                owner: None,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::CommitmentNotFound {
+            name: "admin".to_string()
+        }
+    );
+}
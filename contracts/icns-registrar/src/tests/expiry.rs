@@ -0,0 +1,305 @@
+#![cfg(test)]
+
+use cosmwasm_std::{Addr, Coin, StdResult};
+use cw721::OwnerOfResponse;
+use cw_multi_test::{AppBuilder, BasicApp, Executor};
+use icns_name_nft::msg::{Metadata, NftInfoResponse};
+
+use crate::{
+    msg::{ExecuteMsg, ExpiryResponse, QueryMsg, RegistrationPeriodResponse},
+    state::SECONDS_PER_YEAR,
+    tests::helpers::{
+        default_contracts_setup, fixtures::*, name_nft_contract, registrar_contract,
+        test_only_admin, verify_all,
+    },
+    ContractError,
+};
+
+#[test]
+fn only_admin_can_set_registration_period() {
+    test_only_admin(
+        ExecuteMsg::SetRegistrationPeriod { years: Some(1) },
+        QueryMsg::RegistrationPeriod {},
+        RegistrationPeriodResponse { years: None },
+        RegistrationPeriodResponse { years: Some(1) },
+    );
+}
+
+#[test]
+fn claimed_name_expires_and_can_be_renewed() {
+    let bob = Addr::unchecked("bobaddr");
+    let alice = Addr::unchecked("aliceaddr");
+    let mut app = AppBuilder::default().build(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &bob, vec![Coin::new(100_000_000_000, "uosmo")])
+            .unwrap();
+    });
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+    let fee = Coin::new(1_000_000, "uosmo");
+
+    let (name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![fee.clone()],
+    );
+
+    let owner = |app: &BasicApp, name: &str| -> StdResult<_> {
+        let OwnerOfResponse { owner, .. } = app.wrap().query_wasm_smart(
+            name_nft_contract_addr.clone(),
+            &icns_name_nft::QueryMsg::OwnerOf {
+                token_id: name.to_string(),
+                include_expired: None,
+            },
+        )?;
+
+        Ok(owner)
+    };
+
+    let metadata = |app: &BasicApp, name: &str| -> StdResult<Metadata> {
+        let NftInfoResponse { extension, .. } = app.wrap().query_wasm_smart(
+            name_nft_contract_addr.clone(),
+            &icns_name_nft::QueryMsg::NftInfo {
+                token_id: name.to_string(),
+            },
+        )?;
+
+        Ok(extension)
+    };
+
+    let expiry = |app: &BasicApp, name: &str| -> StdResult<ExpiryResponse> {
+        app.wrap().query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::Expiry {
+                name: name.to_string(),
+            },
+        )
+    };
+
+    let claim = |app: &mut BasicApp, claimer: &Addr, name: &str, twitter_id: &str| {
+        let verifying_msg = format!(
+            r#"{{"name":"{name}","claimer":"{claimer}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"{twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{twitter_id}"}}"#,
+        );
+        app.execute_contract(
+            claimer.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
+                referral: None,
+                secret: None,
+                owner: None,
+            },
+            &[Coin::new(1_000_000, "uosmo")],
+        )
+    };
+
+    let renew = |app: &mut BasicApp, name: &str, years: u64, funds: &[Coin]| {
+        app.execute_contract(
+            bob.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Renew {
+                name: name.to_string(),
+                years,
+            },
+            funds,
+        )
+    };
+
+    // names claimed before registration period is set never expire
+    claim(&mut app, &bob, "bobby", "1").unwrap();
+    assert_eq!(
+        expiry(&app, "bobby").unwrap(),
+        ExpiryResponse {
+            expiry: None,
+            expired: false
+        }
+    );
+    assert_eq!(metadata(&app, "bobby").unwrap().expiry, None);
+
+    // names that never expire can not be renewed
+    let err = renew(&mut app, "bobby", 1, std::slice::from_ref(&fee)).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NotRenewable {
+            name: "bobby".to_string()
+        }
+    );
+
+    // set registration period to 1 year
+    app.execute_contract(
+        Addr::unchecked(&admins[0]),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::SetRegistrationPeriod { years: Some(1) },
+        &[],
+    )
+    .unwrap();
+
+    let start = app.block_info().time;
+    claim(&mut app, &bob, "bob", "2").unwrap();
+
+    let one_year_later = start.plus_seconds(SECONDS_PER_YEAR);
+    assert_eq!(
+        expiry(&app, "bob").unwrap(),
+        ExpiryResponse {
+            expiry: Some(one_year_later),
+            expired: false
+        }
+    );
+    assert_eq!(metadata(&app, "bob").unwrap().expiry, Some(one_year_later));
+
+    // renewal requires fee for each year
+    let err = renew(&mut app, "bob", 2, &[fee]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidFee {
+            fee_required: vec![Coin::new(2_000_000, "uosmo")]
+        }
+    );
+
+    // renewal for 0 year is not allowed
+    let err = renew(&mut app, "bob", 0, &[]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidRenewalYears {}
+    );
+
+    renew(&mut app, "bob", 2, &[Coin::new(2_000_000, "uosmo")]).unwrap();
+
+    let three_years_later = start.plus_seconds(3 * SECONDS_PER_YEAR);
+    assert_eq!(expiry(&app, "bob").unwrap().expiry, Some(three_years_later));
+    assert_eq!(
+        metadata(&app, "bob").unwrap().expiry,
+        Some(three_years_later)
+    );
+
+    // name can not be claimed by others before it expires
+    app.send_tokens(
+        bob.clone(),
+        alice.clone(),
+        &[Coin::new(10_000_000, "uosmo")],
+    )
+    .unwrap();
+    claim(&mut app, &alice, "bob", "3").unwrap_err();
+    assert_eq!(owner(&app, "bob").unwrap(), bob);
+
+    // after expiry, name can not be renewed but can be claimed by others
+    app.update_block(|block| block.time = three_years_later);
+    assert!(expiry(&app, "bob").unwrap().expired);
+
+    let err = renew(&mut app, "bob", 1, &[Coin::new(1_000_000, "uosmo")]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NameExpired {
+            name: "bob".to_string()
+        }
+    );
+
+    claim(&mut app, &alice, "bob", "3").unwrap();
+    assert_eq!(owner(&app, "bob").unwrap(), alice);
+    assert_eq!(
+        expiry(&app, "bob").unwrap().expiry,
+        Some(three_years_later.plus_seconds(SECONDS_PER_YEAR))
+    );
+}
+
+#[test]
+fn name_claimed_again_without_registration_period_never_expires() {
+    let bob = Addr::unchecked("bobaddr");
+    let alice = Addr::unchecked("aliceaddr");
+    let mut app = BasicApp::default();
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+    let admin = Addr::unchecked(&admins[0]);
+
+    let (_name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![],
+    );
+
+    let claim = |app: &mut BasicApp, claimer: &Addr, twitter_id: &str| {
+        let verifying_msg = format!(
+            r#"{{"name":"bob","claimer":"{claimer}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"{twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{twitter_id}"}}"#,
+        );
+        app.execute_contract(
+            claimer.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: "bob".to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
+                referral: None,
+                secret: None,
+                owner: None,
+            },
+            &[],
+        )
+    };
+
+    let set_registration_period = |app: &mut BasicApp, years: Option<u64>| {
+        app.execute_contract(
+            admin.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::SetRegistrationPeriod { years },
+            &[],
+        )
+        .unwrap();
+    };
+
+    set_registration_period(&mut app, Some(1));
+    claim(&mut app, &bob, "1").unwrap();
+
+    // name expires, then registration period is unset before the name is claimed again
+    let expiry = app.block_info().time.plus_seconds(SECONDS_PER_YEAR);
+    app.update_block(|block| block.time = expiry);
+    set_registration_period(&mut app, None);
+    claim(&mut app, &alice, "2").unwrap();
+
+    let res: ExpiryResponse = app
+        .wrap()
+        .query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::Expiry {
+                name: "bob".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        res,
+        ExpiryResponse {
+            expiry: None,
+            expired: false
+        }
+    );
+
+    // name is not available for auction anymore
+    let err = app
+        .execute_contract(
+            admin,
+            registrar_contract_addr,
+            &ExecuteMsg::StartAuction {
+                name: "bob".to_string(),
+                denom: "uosmo".to_string(),
+                min_bid: 1u128.into(),
+                bid_duration: 100,
+                reveal_duration: 100,
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NameAlreadyClaimed {
+            name: "bob".to_string()
+        }
+    );
+}
@@ -2,10 +2,15 @@
 
 use super::helpers::test_only_admin;
 use crate::{
-    msg::{ExecuteMsg, FeeResponse, InstantiateMsg, QueryMsg, Verification},
+    msg::{
+        DenomFeeDistribution, ExecuteMsg, FeeDistributionsResponse, FeeResponse,
+        IdentityProviderInfo, InstantiateMsg, NameFee, PriceResponse, QueryMsg, Verification,
+    },
+    state::LengthTierFee,
     tests::helpers::{
+        default_contracts_setup,
         fixtures::{verifier3, verifier4},
-        name_nft_contract, registrar_contract, ToBinary,
+        name_nft_contract, registrar_contract, verifier_pubkey, verify_all, ToBinary,
     },
     ContractError,
 };
@@ -16,15 +21,16 @@ use cw_multi_test::{AppBuilder, BasicApp, Executor};
 use icns_name_nft::msg::ICNSNameExecuteMsg;
 
 #[test]
-fn only_admin_can_set_fee() {
+fn only_admin_can_update_accepted_fees() {
     test_only_admin(
-        ExecuteMsg::SetMintingFee {
-            minting_fee: Some(Coin::new(999999999, "uosmo")),
+        ExecuteMsg::UpdateAcceptedFees {
+            add: vec![Coin::new(999999999, "uosmo")],
+            remove: vec![],
         },
         QueryMsg::Fee {},
-        FeeResponse { fee: None },
+        FeeResponse { fees: vec![] },
         FeeResponse {
-            fee: Some(Coin::new(999999999, "uosmo")),
+            fees: vec![Coin::new(999999999, "uosmo")],
         },
     );
 }
@@ -82,7 +88,7 @@ fn only_admin_can_withdraw_collected_fees() {
         verifiers
             .iter()
             .map(|verifier| Verification {
-                public_key: verifier.to_binary(),
+                public_key: Some(verifier.to_binary()),
                 signature: verifier.sign(verifying_msg.as_bytes()).unwrap().to_binary(),
             })
             .collect()
@@ -97,12 +103,15 @@ fn only_admin_can_withdraw_collected_fees() {
             Addr::unchecked(admins[0].clone()),
             &InstantiateMsg {
                 name_nft_addr: name_nft_contract_addr.to_string(),
-                verifier_pubkeys: vec![verifier3(), verifier3(), verifier3(), verifier4()]
-                    .iter()
-                    .map(|v| v.to_binary())
-                    .collect(),
-                verification_threshold: Decimal::percent(50),
-                fee: Some(fee.clone()),
+                identity_providers: vec![IdentityProviderInfo {
+                    provider: "twitter".to_string(),
+                    verifier_pubkeys: [verifier3(), verifier3(), verifier3(), verifier4()]
+                        .iter()
+                        .map(|v| verifier_pubkey(v, 1))
+                        .collect(),
+                    verification_threshold: Decimal::percent(50),
+                }],
+                fees: vec![fee.clone()],
             },
             &[],
             "registar",
@@ -129,7 +138,7 @@ fn only_admin_can_withdraw_collected_fees() {
 
     // execute claim invalid fee
     let verifying_msg = format!(
-        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","unique_twitter_id":"{unique_twitter_id}"}}"#,
+        r#"{{"name":"{bob_name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"{multitest_chain_id}","provider":"twitter","subject_id":"{unique_twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{unique_twitter_id}"}}"#,
     );
 
     // at first, contract should have no funds
@@ -152,8 +161,10 @@ fn only_admin_can_withdraw_collected_fees() {
             verifying_msg: verifying_msg.clone(),
             verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
             referral: None,
+            secret: None,
+            owner: None,
         },
-        &[fee.clone()],
+        std::slice::from_ref(&fee),
     )
     .unwrap();
 
@@ -188,10 +199,31 @@ fn only_admin_can_withdraw_collected_fees() {
         &ContractError::Unauthorized {}
     );
 
+    // admin can not withdraw more than the collected fees
+    let err = app
+        .execute_contract(
+            Addr::unchecked(&admins[0]),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::WithdrawFunds {
+                amount: vec![Coin::new(fee.amount.u128() + 1, &fee.denom)],
+                to_address: admins[0].clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InsufficientPendingFees {
+            requested: Coin::new(fee.amount.u128() + 1, &fee.denom),
+            pending: fee.clone(),
+        }
+    );
+
     // admin should be able to withdraw
     app.execute_contract(
         Addr::unchecked(&admins[0]),
-        registrar_contract_addr,
+        registrar_contract_addr.clone(),
         &ExecuteMsg::WithdrawFunds {
             amount: vec![fee.clone()],
             to_address: admins[0].clone(),
@@ -200,6 +232,23 @@ fn only_admin_can_withdraw_collected_fees() {
     )
     .unwrap();
 
+    // withdrawn fees are no longer pending for the treasury
+    let FeeDistributionsResponse { distributions } = app
+        .wrap()
+        .query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::FeeDistributions {},
+        )
+        .unwrap();
+    assert_eq!(
+        distributions,
+        vec![DenomFeeDistribution {
+            denom: fee.denom.clone(),
+            collected: fee.amount,
+            distributed: fee.amount,
+        }]
+    );
+
     // destined addr should get funds
     assert_eq!(
         app.wrap()
@@ -211,3 +260,295 @@ fn only_admin_can_withdraw_collected_fees() {
         vec![fee]
     );
 }
+
+#[test]
+fn only_admin_can_set_length_tier_fees() {
+    test_only_admin(
+        ExecuteMsg::SetLengthTierFees {
+            tiers: vec![LengthTierFee {
+                max_length: 3,
+                fees: vec![Coin::new(100, "uosmo")],
+            }],
+        },
+        QueryMsg::Price {
+            name: "bob".to_string(),
+        },
+        PriceResponse { price: vec![] },
+        PriceResponse {
+            price: vec![Coin::new(100, "uosmo")],
+        },
+    );
+}
+
+#[test]
+fn only_admin_can_set_name_fees() {
+    test_only_admin(
+        ExecuteMsg::SetNameFees {
+            fees: vec![NameFee {
+                name: "bob".to_string(),
+                fees: Some(vec![Coin::new(100, "uosmo")]),
+            }],
+        },
+        QueryMsg::Price {
+            name: "bob".to_string(),
+        },
+        PriceResponse { price: vec![] },
+        PriceResponse {
+            price: vec![Coin::new(100, "uosmo")],
+        },
+    );
+}
+
+#[test]
+fn claim_name_with_tiered_and_name_fees() {
+    let bob = Addr::unchecked("bobaddr");
+    let mut app = AppBuilder::default().build(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &bob, vec![Coin::new(100_000_000_000, "uosmo")])
+            .unwrap();
+    });
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+    let fee = Coin::new(100, "uosmo");
+
+    let (_, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![fee.clone()],
+    );
+
+    let price = |app: &BasicApp, name: &str| -> Vec<Coin> {
+        let PriceResponse { price } = app
+            .wrap()
+            .query_wasm_smart(
+                registrar_contract_addr.clone(),
+                &QueryMsg::Price {
+                    name: name.to_string(),
+                },
+            )
+            .unwrap();
+        price
+    };
+
+    let claim = |app: &mut BasicApp, name: &str, twitter_id: &str, funds: &[Coin]| {
+        let verifying_msg = format!(
+            r#"{{"name":"{name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"{twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{twitter_id}"}}"#,
+        );
+        app.execute_contract(
+            bob.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
+                referral: None,
+                secret: None,
+                owner: None,
+            },
+            funds,
+        )
+    };
+
+    // tiers are matched by the smallest max length that fits, regardless of order
+    app.execute_contract(
+        Addr::unchecked(&admins[0]),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::SetLengthTierFees {
+            tiers: vec![
+                LengthTierFee {
+                    max_length: 5,
+                    fees: vec![Coin::new(1_000, "uosmo")],
+                },
+                LengthTierFee {
+                    max_length: 3,
+                    fees: vec![Coin::new(10_000, "uosmo")],
+                },
+            ],
+        },
+        &[],
+    )
+    .unwrap();
+
+    app.execute_contract(
+        Addr::unchecked(&admins[0]),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::SetNameFees {
+            fees: vec![NameFee {
+                name: "osmosis".to_string(),
+                fees: Some(vec![Coin::new(1_000_000, "uosmo")]),
+            }],
+        },
+        &[],
+    )
+    .unwrap();
+
+    assert_eq!(price(&app, "bob"), vec![Coin::new(10_000, "uosmo")]);
+    assert_eq!(price(&app, "bobby"), vec![Coin::new(1_000, "uosmo")]);
+    assert_eq!(price(&app, "bobbybob"), vec![fee.clone()]);
+    assert_eq!(price(&app, "osmosis"), vec![Coin::new(1_000_000, "uosmo")]);
+
+    // length is counted by characters
+    assert_eq!(price(&app, "밥밥밥"), vec![Coin::new(10_000, "uosmo")]);
+
+    let err = claim(&mut app, "bob", "1", std::slice::from_ref(&fee)).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidFee {
+            fee_required: vec![Coin::new(10_000, "uosmo")]
+        }
+    );
+    claim(&mut app, "bob", "1", &[Coin::new(10_000, "uosmo")]).unwrap();
+    claim(&mut app, "bobbybob", "2", &[fee]).unwrap();
+
+    let err = claim(&mut app, "osmosis", "3", &[Coin::new(1_000, "uosmo")]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidFee {
+            fee_required: vec![Coin::new(1_000_000, "uosmo")]
+        }
+    );
+
+    // removing name fee falls back to length tier fee
+    app.execute_contract(
+        Addr::unchecked(&admins[0]),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::SetNameFees {
+            fees: vec![NameFee {
+                name: "osmosis".to_string(),
+                fees: None,
+            }],
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(price(&app, "osmosis"), vec![Coin::new(100, "uosmo")]);
+
+    // invalid tiers are rejected
+    let err = app
+        .execute_contract(
+            Addr::unchecked(&admins[0]),
+            registrar_contract_addr,
+            &ExecuteMsg::SetLengthTierFees {
+                tiers: vec![
+                    LengthTierFee {
+                        max_length: 3,
+                        fees: vec![Coin::new(1_000, "uosmo")],
+                    },
+                    LengthTierFee {
+                        max_length: 3,
+                        fees: vec![Coin::new(10_000, "uosmo")],
+                    },
+                ],
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidLengthTier {
+            msg: "max length `3` is duplicated".to_string()
+        }
+    );
+}
+
+#[test]
+fn claim_name_with_any_accepted_fee() {
+    let bob = Addr::unchecked("bobaddr");
+    let mut app = AppBuilder::default().build(|router, _, storage| {
+        router
+            .bank
+            .init_balance(
+                storage,
+                &bob,
+                vec![
+                    Coin::new(100_000_000_000, "uosmo"),
+                    Coin::new(100_000_000_000, "uion"),
+                ],
+            )
+            .unwrap();
+    });
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+
+    let (_, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![Coin::new(1_000, "uosmo"), Coin::new(10, "uion")],
+    );
+
+    let claim = |app: &mut BasicApp, name: &str, twitter_id: &str, funds: &[Coin]| {
+        let verifying_msg = format!(
+            r#"{{"name":"{name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"{twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{twitter_id}"}}"#,
+        );
+        app.execute_contract(
+            bob.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, vec![verifier4(), verifier3()]),
+                referral: None,
+                secret: None,
+                owner: None,
+            },
+            funds,
+        )
+    };
+
+    let balances = |app: &BasicApp| app.wrap().query_all_balances(&registrar_contract_addr);
+
+    // any of the accepted fees can be paid
+    claim(&mut app, "alice", "1", &[Coin::new(1_000, "uosmo")]).unwrap();
+    claim(&mut app, "bob", "2", &[Coin::new(10, "uion")]).unwrap();
+    assert_eq!(
+        balances(&app).unwrap(),
+        vec![Coin::new(10, "uion"), Coin::new(1_000, "uosmo")]
+    );
+
+    // when both are given, only one of them is taken and the rest is refunded
+    claim(
+        &mut app,
+        "carol",
+        "3",
+        &[Coin::new(1_000, "uosmo"), Coin::new(100, "uion")],
+    )
+    .unwrap();
+    assert_eq!(
+        balances(&app).unwrap(),
+        vec![Coin::new(10, "uion"), Coin::new(2_000, "uosmo")]
+    );
+
+    // replace uion fee amount and remove uosmo from accepted fees
+    app.execute_contract(
+        Addr::unchecked(&admins[0]),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::UpdateAcceptedFees {
+            add: vec![Coin::new(20, "uion")],
+            remove: vec!["uosmo".to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let FeeResponse { fees } = app
+        .wrap()
+        .query_wasm_smart(registrar_contract_addr.clone(), &QueryMsg::Fee {})
+        .unwrap();
+    assert_eq!(fees, vec![Coin::new(20, "uion")]);
+
+    let err = claim(&mut app, "dave", "4", &[Coin::new(1_000, "uosmo")]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidFee {
+            fee_required: vec![Coin::new(20, "uion")]
+        }
+    );
+    claim(&mut app, "dave", "4", &[Coin::new(20, "uion")]).unwrap();
+}
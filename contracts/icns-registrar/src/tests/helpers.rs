@@ -1,12 +1,16 @@
 use cosmrs::{bip32, crypto::secp256k1::SigningKey, tendermint::signature::Secp256k1Signature};
-use cosmwasm_std::{Addr, Binary, Coin, Decimal, Empty};
+use cosmwasm_std::{testing::MockApi, Addr, Api, Binary, Coin, Decimal, Empty};
 use cw_multi_test::{BasicApp, Contract, ContractWrapper, Executor};
 use icns_name_nft::msg::ICNSNameExecuteMsg;
 use serde::de::DeserializeOwned;
+use sha2::Digest;
 use std::fmt::Debug;
 
 use crate::{
-    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    msg::{
+        ExecuteMsg, IdentityProviderInfo, InstantiateMsg, QueryMsg, Verification, VerifierPubKey,
+    },
+    state::KeyType,
     ContractError,
 };
 
@@ -35,7 +39,7 @@ pub fn default_contracts_setup(
     name_nft_code_id: u64,
     registrar_code_id: u64,
     admins: Vec<String>,
-    fee: Option<Coin>,
+    fees: Vec<Coin>,
 ) -> (Addr, Addr) {
     // setup name nft contract
     let name_nft_contract_addr = app
@@ -58,12 +62,15 @@ pub fn default_contracts_setup(
             Addr::unchecked(admins[0].clone()),
             &InstantiateMsg {
                 name_nft_addr: name_nft_contract_addr.to_string(),
-                verifier_pubkeys: vec![verifier1(), verifier2(), verifier3(), verifier4()]
-                    .iter()
-                    .map(|v| v.to_binary())
-                    .collect(),
-                verification_threshold: Decimal::percent(50),
-                fee,
+                identity_providers: vec![IdentityProviderInfo {
+                    provider: "twitter".to_string(),
+                    verifier_pubkeys: [verifier1(), verifier2(), verifier3(), verifier4()]
+                        .iter()
+                        .map(|v| verifier_pubkey(v, 1))
+                        .collect(),
+                    verification_threshold: Decimal::percent(50),
+                }],
+                fees,
             },
             &[],
             "registar",
@@ -118,9 +125,12 @@ where
             Addr::unchecked(admins[0].clone()),
             &InstantiateMsg {
                 name_nft_addr: name_nft_contract_addr.to_string(),
-                verifier_pubkeys: vec![verifier2().to_binary()],
-                verification_threshold: Decimal::percent(50),
-                fee: None,
+                identity_providers: vec![IdentityProviderInfo {
+                    provider: "twitter".to_string(),
+                    verifier_pubkeys: vec![verifier_pubkey(&verifier2(), 1)],
+                    verification_threshold: Decimal::percent(50),
+                }],
+                fees: vec![],
             },
             &[],
             "registar",
@@ -182,6 +192,75 @@ pub fn from_mnemonic(phrase: &str, derivation_path: &str) -> SigningKey {
     xprv.into()
 }
 
+pub fn verify_all(verifying_msg: &str, verifiers: Vec<SigningKey>) -> Vec<Verification> {
+    verifiers
+        .iter()
+        .map(|verifier| Verification {
+            public_key: Some(verifier.to_binary()),
+            signature: verifier.sign(verifying_msg.as_bytes()).unwrap().to_binary(),
+        })
+        .collect()
+}
+
+// recover_all signs the verifying message with recoverable signatures, omitting the public keys
+pub fn recover_all(verifying_msg: &str, verifiers: Vec<SigningKey>) -> Vec<Verification> {
+    let msg_hash = sha2::Sha256::digest(verifying_msg);
+    verifiers
+        .iter()
+        .map(|verifier| {
+            let signature = verifier.sign(verifying_msg.as_bytes()).unwrap().to_vec();
+            // find the recovery id that recovers the uncompressed public key of the verifier
+            let recovery_id = (0..2)
+                .find(|recovery_id| {
+                    MockApi::default()
+                        .secp256k1_recover_pubkey(&msg_hash, &signature, *recovery_id)
+                        .map(|pubkey| {
+                            [&[0x02 | (pubkey[64] & 1)], &pubkey[1..33]].concat()
+                                == verifier.to_binary().to_vec()
+                        })
+                        .unwrap_or(false)
+                })
+                .unwrap();
+            Verification {
+                public_key: None,
+                signature: Binary([signature, vec![recovery_id]].concat()),
+            }
+        })
+        .collect()
+}
+
+pub fn verifier_pubkey(verifier: &SigningKey, weight: u64) -> VerifierPubKey {
+    VerifierPubKey {
+        pubkey: verifier.to_binary(),
+        weight,
+        key_type: KeyType::Secp256k1,
+    }
+}
+
+pub fn ed25519_verify_all(
+    verifying_msg: &str,
+    verifiers: Vec<ed25519_zebra::SigningKey>,
+) -> Vec<Verification> {
+    verifiers
+        .iter()
+        .map(|verifier| Verification {
+            public_key: Some(verifier.to_binary()),
+            signature: Binary(<[u8; 64]>::from(verifier.sign(verifying_msg.as_bytes())).to_vec()),
+        })
+        .collect()
+}
+
+pub fn ed25519_verifier_pubkey(
+    verifier: &ed25519_zebra::SigningKey,
+    weight: u64,
+) -> VerifierPubKey {
+    VerifierPubKey {
+        pubkey: verifier.to_binary(),
+        weight,
+        key_type: KeyType::Ed25519,
+    }
+}
+
 pub trait ToBinary {
     fn to_binary(&self) -> Binary;
 }
@@ -192,6 +271,12 @@ impl ToBinary for SigningKey {
     }
 }
 
+impl ToBinary for ed25519_zebra::SigningKey {
+    fn to_binary(&self) -> Binary {
+        Binary(<[u8; 32]>::from(ed25519_zebra::VerificationKey::from(self)).to_vec())
+    }
+}
+
 impl ToBinary for Secp256k1Signature {
     fn to_binary(&self) -> Binary {
         Binary(self.to_vec())
@@ -218,4 +303,11 @@ pub mod fixtures {
     pub fn non_verifier() -> SigningKey {
         from_mnemonic("prefer forget visit mistake mixture feel eyebrow autumn shop pair address airport diesel street pass vague innocent poem method awful require hurry unhappy shoulder", DERIVATION_PATH)
     }
+
+    pub fn ed25519_verifier1() -> ed25519_zebra::SigningKey {
+        ed25519_zebra::SigningKey::from([1u8; 32])
+    }
+    pub fn ed25519_verifier2() -> ed25519_zebra::SigningKey {
+        ed25519_zebra::SigningKey::from([2u8; 32])
+    }
 }
@@ -0,0 +1,351 @@
+#![cfg(test)]
+
+use cosmrs::crypto::secp256k1::SigningKey;
+use cosmwasm_std::{Addr, Binary, Decimal, StdResult};
+use cw721::OwnerOfResponse;
+use cw_multi_test::{BasicApp, Executor};
+
+use crate::{
+    msg::{
+        ExecuteMsg, IdentityProviderInfo, IdentityProvidersResponse, NameByIdentityResponse,
+        NameByTwitterIdResponse, QueryMsg, TwitterIdByNameResponse,
+    },
+    tests::helpers::{
+        default_contracts_setup, fixtures::*, name_nft_contract, registrar_contract,
+        test_only_admin, verifier_pubkey, verify_all,
+    },
+    ContractError,
+};
+
+#[test]
+fn only_admin_can_add_identity_provider() {
+    test_only_admin(
+        ExecuteMsg::AddIdentityProvider {
+            identity_provider: IdentityProviderInfo {
+                provider: "github".to_string(),
+                verifier_pubkeys: vec![verifier_pubkey(&verifier3(), 1)],
+                verification_threshold: Decimal::percent(100),
+            },
+        },
+        QueryMsg::IdentityProviders {},
+        IdentityProvidersResponse {
+            providers: vec!["twitter".to_string()],
+        },
+        IdentityProvidersResponse {
+            providers: vec!["github".to_string(), "twitter".to_string()],
+        },
+    );
+}
+
+#[test]
+fn claim_name_with_identity_providers() {
+    let bob = Addr::unchecked("bobaddr");
+    let alice = Addr::unchecked("aliceaddr");
+    let mut app = BasicApp::default();
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+    let admin = Addr::unchecked(&admins[0]);
+
+    let (name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![],
+    );
+
+    let owner = |app: &BasicApp, name: &str| -> StdResult<_> {
+        let OwnerOfResponse { owner, .. } = app.wrap().query_wasm_smart(
+            name_nft_contract_addr.clone(),
+            &icns_name_nft::QueryMsg::OwnerOf {
+                token_id: name.to_string(),
+                include_expired: None,
+            },
+        )?;
+
+        Ok(owner)
+    };
+
+    let claim = |app: &mut BasicApp,
+                 claimer: &Addr,
+                 name: &str,
+                 provider: &str,
+                 subject_id: &str,
+                 verifiers: Vec<SigningKey>| {
+        let verifying_msg = format!(
+            r#"{{"name":"{name}","claimer":"{claimer}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"{provider}","subject_id":"{subject_id}","expiry":{{"at_height":1000000}},"nonce":"{provider}/{subject_id}"}}"#,
+        );
+        app.execute_contract(
+            claimer.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, verifiers),
+                referral: None,
+                secret: None,
+                owner: None,
+            },
+            &[],
+        )
+    };
+
+    let name_by_identity = |app: &BasicApp, provider: &str, id: &str| -> StdResult<String> {
+        let NameByIdentityResponse { name } = app.wrap().query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::NameByIdentity {
+                provider: provider.to_string(),
+                id: id.to_string(),
+            },
+        )?;
+        Ok(name)
+    };
+
+    // unknown provider can not be claimed with
+    let err = claim(&mut app, &bob, "bob", "github", "1", vec![verifier3()]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::UnknownIdentityProvider {
+            provider: "github".to_string()
+        }
+    );
+
+    let add_github = |app: &mut BasicApp| {
+        app.execute_contract(
+            admin.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::AddIdentityProvider {
+                identity_provider: IdentityProviderInfo {
+                    provider: "github".to_string(),
+                    verifier_pubkeys: vec![verifier_pubkey(&verifier3(), 1)],
+                    verification_threshold: Decimal::percent(100),
+                },
+            },
+            &[],
+        )
+    };
+    add_github(&mut app).unwrap();
+
+    let err = add_github(&mut app).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::IdentityProviderAlreadyExists {
+            provider: "github".to_string()
+        }
+    );
+
+    // only verifiers of the provider can verify its identities
+    let err = claim(
+        &mut app,
+        &bob,
+        "bob",
+        "github",
+        "1",
+        vec![verifier1(), verifier2()],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NotAVerifierPublicKey {
+            public_key: Binary(verifier1().public_key().to_bytes())
+        }
+    );
+
+    claim(&mut app, &bob, "bob", "github", "1", vec![verifier3()]).unwrap();
+    assert_eq!(owner(&app, "bob").unwrap(), bob);
+    assert_eq!(name_by_identity(&app, "github", "1").unwrap(), "bob");
+
+    // same subject id of another provider is a different identity
+    claim(
+        &mut app,
+        &alice,
+        "alice",
+        "twitter",
+        "1",
+        vec![verifier1(), verifier2()],
+    )
+    .unwrap();
+    assert_eq!(owner(&app, "alice").unwrap(), alice);
+    assert_eq!(name_by_identity(&app, "twitter", "1").unwrap(), "alice");
+
+    // identity can only be used once
+    let err = claim(&mut app, &alice, "alice2", "github", "1", vec![verifier3()]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::DuplicatedIdentity {
+            provider: "github".to_string(),
+            id: "1".to_string()
+        }
+    );
+
+    // removed provider can no longer be claimed with, but its identities are kept
+    app.execute_contract(
+        admin,
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::RemoveIdentityProvider {
+            provider: "github".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+    let err = claim(&mut app, &alice, "alice2", "github", "2", vec![verifier3()]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::UnknownIdentityProvider {
+            provider: "github".to_string()
+        }
+    );
+    assert_eq!(name_by_identity(&app, "github", "1").unwrap(), "bob");
+}
+
+#[test]
+fn release_and_rebind_identity() {
+    let mut app = BasicApp::default();
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+    let admin = Addr::unchecked(&admins[0]);
+    let alice = Addr::unchecked("aliceaddr");
+    let bob = Addr::unchecked("bobaddr");
+
+    let (_name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![],
+    );
+
+    let verifying_msg = |name: &str, twitter_id: &str, nonce: &str| {
+        format!(
+            r#"{{"name":"{name}","claimer":"{alice}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"{twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{nonce}"}}"#,
+        )
+    };
+    let claim = |app: &mut BasicApp, name: &str, twitter_id: &str, nonce: &str| {
+        let verifying_msg = verifying_msg(name, twitter_id, nonce);
+        app.execute_contract(
+            alice.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
+                referral: None,
+                secret: None,
+                owner: None,
+            },
+            &[],
+        )
+    };
+    let rebind = |app: &mut BasicApp, sender: &Addr, name: &str, twitter_id: &str, nonce: &str| {
+        let verifying_msg = verifying_msg(name, twitter_id, nonce);
+        app.execute_contract(
+            sender.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::RebindIdentity {
+                name: name.to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
+            },
+            &[],
+        )
+    };
+    let twitter_id_by_name = |app: &BasicApp, name: &str| -> StdResult<String> {
+        let TwitterIdByNameResponse { twitter_id } = app.wrap().query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::TwitterIdByName {
+                name: name.to_string(),
+            },
+        )?;
+        Ok(twitter_id)
+    };
+    let name_by_twitter_id = |app: &BasicApp, twitter_id: &str| -> StdResult<String> {
+        let NameByTwitterIdResponse { name } = app.wrap().query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::NameByTwitterId {
+                twitter_id: twitter_id.to_string(),
+            },
+        )?;
+        Ok(name)
+    };
+
+    claim(&mut app, "alice", "1", "1").unwrap();
+    assert_eq!(twitter_id_by_name(&app, "alice").unwrap(), "1");
+
+    // only the owner of the name can release its identity
+    let release = |app: &mut BasicApp, sender: &Addr| {
+        app.execute_contract(
+            sender.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::ReleaseIdentity {
+                name: "alice".to_string(),
+                provider: "twitter".to_string(),
+            },
+            &[],
+        )
+    };
+    let err = release(&mut app, &bob).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Unauthorized {}
+    );
+    release(&mut app, &alice).unwrap();
+    assert!(twitter_id_by_name(&app, "alice").is_err());
+    assert!(name_by_twitter_id(&app, "1").is_err());
+
+    let err = release(&mut app, &alice).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NoIdentityBound {
+            name: "alice".to_string(),
+            provider: "twitter".to_string()
+        }
+    );
+
+    // released identity can claim another name
+    claim(&mut app, "alice2", "1", "2").unwrap();
+    assert_eq!(name_by_twitter_id(&app, "1").unwrap(), "alice2");
+
+    // recovered account attested by the verifiers replaces the bound identity
+    let err = rebind(&mut app, &bob, "alice2", "2", "3").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Unauthorized {}
+    );
+    let err = rebind(&mut app, &alice, "alice2", "1", "3").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::DuplicatedIdentity {
+            provider: "twitter".to_string(),
+            id: "1".to_string()
+        }
+    );
+    rebind(&mut app, &alice, "alice2", "2", "3").unwrap();
+    assert_eq!(twitter_id_by_name(&app, "alice2").unwrap(), "2");
+    assert_eq!(name_by_twitter_id(&app, "2").unwrap(), "alice2");
+    assert!(name_by_twitter_id(&app, "1").is_err());
+
+    // verifications can not be replayed
+    let err = rebind(&mut app, &alice, "alice2", "4", "3").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NonceAlreadyUsed {
+            nonce: "3".to_string()
+        }
+    );
+
+    // admin can rebind identity without verifications
+    app.execute_contract(
+        admin,
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::RebindIdentity {
+            name: "alice".to_string(),
+            verifying_msg: verifying_msg("alice", "5", "5"),
+            verifications: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(twitter_id_by_name(&app, "alice").unwrap(), "5");
+}
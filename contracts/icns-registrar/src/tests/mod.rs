@@ -11,3 +11,25 @@ mod set_threshold;
 mod name_nft_address;
 
 mod fee;
+
+mod expiry;
+
+mod referral;
+
+mod commitment;
+
+mod auction;
+
+mod reserved;
+
+mod identity;
+
+mod simulate_claim;
+
+mod treasury;
+
+mod admin_batch_claim;
+
+mod rate_limit;
+
+mod pause;
@@ -4,11 +4,11 @@ use cosmwasm_std::{Addr, Decimal};
 use cw_multi_test::{BasicApp, Executor};
 
 use crate::{
-    msg::{ExecuteMsg, InstantiateMsg, NameNftAddressResponse, QueryMsg},
+    msg::{ExecuteMsg, IdentityProviderInfo, InstantiateMsg, NameNftAddressResponse, QueryMsg},
     ContractError,
 };
 
-use super::helpers::{fixtures::*, name_nft_contract, registrar_contract, ToBinary};
+use super::helpers::{fixtures::*, name_nft_contract, registrar_contract, verifier_pubkey};
 
 #[test]
 fn only_admin_can_set_name_nft_address() {
@@ -39,9 +39,12 @@ fn only_admin_can_set_name_nft_address() {
             Addr::unchecked(admins[0].clone()),
             &InstantiateMsg {
                 name_nft_addr: name_nft_contract_addr.to_string(),
-                verifier_pubkeys: vec![verifier2().to_binary()],
-                verification_threshold: Decimal::percent(50),
-                fee: None,
+                identity_providers: vec![IdentityProviderInfo {
+                    provider: "twitter".to_string(),
+                    verifier_pubkeys: vec![verifier_pubkey(&verifier2(), 1)],
+                    verification_threshold: Decimal::percent(50),
+                }],
+                fees: vec![],
             },
             &[],
             "registar",
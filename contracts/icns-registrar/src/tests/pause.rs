@@ -0,0 +1,139 @@
+#![cfg(test)]
+
+use cosmwasm_std::Addr;
+use cw_multi_test::{BasicApp, Executor};
+use icns_name_nft::msg::ICNSNameExecuteMsg;
+
+use crate::{
+    msg::{
+        ClaimCheck, ClaimCheckFailure, ExecuteMsg, PausedResponse, QueryMsg, SimulateClaimResponse,
+    },
+    tests::helpers::{
+        default_contracts_setup, fixtures::*, name_nft_contract, registrar_contract, verify_all,
+    },
+    ContractError,
+};
+
+#[test]
+fn claims_are_blocked_while_paused() {
+    let bob = Addr::unchecked("bobaddr");
+    let guardian = Addr::unchecked("guardian");
+    let mut app = BasicApp::default();
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+    let admin = Addr::unchecked(&admins[0]);
+
+    let (name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![],
+    );
+
+    // guardians are kept by the name nft contract
+    app.execute_contract(
+        admin.clone(),
+        name_nft_contract_addr,
+        &icns_name_nft::ExecuteMsg::Extension {
+            msg: ICNSNameExecuteMsg::AddGuardian {
+                guardian_address: guardian.to_string(),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    let verifying_msg = format!(
+        r#"{{"name":"bob","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"1234","expiry":{{"at_height":1000000}},"nonce":"1234"}}"#,
+    );
+    let verifications = verify_all(&verifying_msg, vec![verifier1(), verifier2()]);
+
+    let claim = |app: &mut BasicApp| {
+        app.execute_contract(
+            bob.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: "bob".to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verifications.clone(),
+                referral: None,
+                secret: None,
+                owner: None,
+            },
+            &[],
+        )
+    };
+
+    let set_paused = |app: &mut BasicApp, sender: &Addr, paused: bool| {
+        app.execute_contract(
+            sender.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::SetPaused { paused },
+            &[],
+        )
+    };
+
+    let paused = |app: &BasicApp| {
+        let PausedResponse { paused } = app
+            .wrap()
+            .query_wasm_smart(registrar_contract_addr.clone(), &QueryMsg::Paused {})
+            .unwrap();
+        paused
+    };
+
+    // non guardian can not pause
+    let err = set_paused(&mut app, &bob, true).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Unauthorized {}
+    );
+
+    // guardian can pause
+    set_paused(&mut app, &guardian, true).unwrap();
+    assert!(paused(&app));
+
+    let err = claim(&mut app).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Paused {}
+    );
+
+    // queries keep working
+    let SimulateClaimResponse { failures } = app
+        .wrap()
+        .query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::SimulateClaim {
+                name: "bob".to_string(),
+                claimer: bob.to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verifications.clone(),
+                referral: None,
+                secret: None,
+                owner: None,
+                funds: vec![],
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        failures,
+        vec![ClaimCheckFailure {
+            check: ClaimCheck::Paused,
+            error: ContractError::Paused {}.to_string(),
+        }]
+    );
+
+    // guardian can not unpause
+    let err = set_paused(&mut app, &guardian, false).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Unauthorized {}
+    );
+
+    // admin can unpause
+    set_paused(&mut app, &admin, false).unwrap();
+    assert!(!paused(&app));
+    claim(&mut app).unwrap();
+}
@@ -0,0 +1,215 @@
+#![cfg(test)]
+
+use cosmwasm_std::Addr;
+use cw_multi_test::{BasicApp, Executor};
+
+use crate::{
+    msg::{ClaimQuotaResponse, ClaimRateLimitResponse, ExecuteMsg, QueryMsg},
+    state::{BlockWindowLimit, ClaimRateLimit},
+    tests::helpers::{
+        default_contracts_setup, fixtures::*, name_nft_contract, registrar_contract,
+        test_only_admin, verify_all,
+    },
+    ContractError,
+};
+
+#[test]
+fn only_admin_can_set_claim_rate_limit() {
+    test_only_admin(
+        ExecuteMsg::SetClaimRateLimit {
+            rate_limit: ClaimRateLimit {
+                max_claims_per_address: Some(3),
+                max_claims_per_window: Some(BlockWindowLimit {
+                    blocks: 100,
+                    max_claims: 10,
+                }),
+            },
+        },
+        QueryMsg::ClaimRateLimit {},
+        ClaimRateLimitResponse {
+            rate_limit: ClaimRateLimit::default(),
+        },
+        ClaimRateLimitResponse {
+            rate_limit: ClaimRateLimit {
+                max_claims_per_address: Some(3),
+                max_claims_per_window: Some(BlockWindowLimit {
+                    blocks: 100,
+                    max_claims: 10,
+                }),
+            },
+        },
+    );
+}
+
+#[test]
+fn claims_are_limited_per_address_and_per_window() {
+    let bob = Addr::unchecked("bobaddr");
+    let alice = Addr::unchecked("aliceaddr");
+    let mut app = BasicApp::default();
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+    let admin = Addr::unchecked(&admins[0]);
+
+    let (_name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![],
+    );
+
+    let claim = |app: &mut BasicApp, claimer: &Addr, name: &str| {
+        let verifying_msg = format!(
+            r#"{{"name":"{name}","claimer":"{claimer}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"{name}","expiry":{{"at_height":1000000}},"nonce":"{name}"}}"#,
+        );
+        app.execute_contract(
+            claimer.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
+                referral: None,
+                secret: None,
+                owner: None,
+            },
+            &[],
+        )
+    };
+
+    let claim_quota = |app: &BasicApp, address: &Addr| -> ClaimQuotaResponse {
+        app.wrap()
+            .query_wasm_smart(
+                registrar_contract_addr.clone(),
+                &QueryMsg::ClaimQuota {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap()
+    };
+
+    // window must not be empty
+    let err = app
+        .execute_contract(
+            admin.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::SetClaimRateLimit {
+                rate_limit: ClaimRateLimit {
+                    max_claims_per_address: None,
+                    max_claims_per_window: Some(BlockWindowLimit {
+                        blocks: 0,
+                        max_claims: 1,
+                    }),
+                },
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidClaimRateLimit {
+            msg: "window must be at least 1 block".to_string()
+        }
+    );
+
+    app.execute_contract(
+        admin.clone(),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::SetClaimRateLimit {
+            rate_limit: ClaimRateLimit {
+                max_claims_per_address: Some(2),
+                max_claims_per_window: Some(BlockWindowLimit {
+                    blocks: 10,
+                    max_claims: 2,
+                }),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    let window_start = app.block_info().height / 10 * 10;
+    assert_eq!(
+        claim_quota(&app, &bob),
+        ClaimQuotaResponse {
+            claimed: 0,
+            remaining_for_address: Some(2),
+            remaining_in_window: Some(2),
+            next_window_height: Some(window_start + 10),
+        }
+    );
+
+    // claims within the window are limited globally
+    claim(&mut app, &bob, "bob").unwrap();
+    claim(&mut app, &alice, "alice").unwrap();
+    let err = claim(&mut app, &bob, "bobby").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::WindowClaimLimitExceeded {
+            limit: 2,
+            blocks: 10
+        }
+    );
+    assert_eq!(
+        claim_quota(&app, &bob),
+        ClaimQuotaResponse {
+            claimed: 1,
+            remaining_for_address: Some(1),
+            remaining_in_window: Some(0),
+            next_window_height: Some(window_start + 10),
+        }
+    );
+
+    // quota of the window is restored in the next window
+    app.update_block(|block| block.height = window_start + 10);
+    assert_eq!(
+        claim_quota(&app, &bob),
+        ClaimQuotaResponse {
+            claimed: 1,
+            remaining_for_address: Some(1),
+            remaining_in_window: Some(2),
+            next_window_height: Some(window_start + 20),
+        }
+    );
+    claim(&mut app, &bob, "bobby").unwrap();
+
+    // claims per address are limited regardless of the window
+    app.update_block(|block| block.height = window_start + 20);
+    let err = claim(&mut app, &bob, "bobbie").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::AddressClaimLimitExceeded {
+            address: bob.to_string(),
+            limit: 2
+        }
+    );
+
+    // admin claims are not limited
+    let verifying_msg = format!(
+        r#"{{"name":"bobbie","claimer":"{admin}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"bobbie","expiry":{{"at_height":1000000}},"nonce":"bobbie"}}"#,
+    );
+    app.execute_contract(
+        admin.clone(),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::Claim {
+            name: "bobbie".to_string(),
+            verifying_msg,
+            verifications: vec![],
+            referral: None,
+            secret: None,
+            owner: Some(bob.to_string()),
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        claim_quota(&app, &bob),
+        ClaimQuotaResponse {
+            claimed: 2,
+            remaining_for_address: Some(0),
+            remaining_in_window: Some(2),
+            next_window_height: Some(window_start + 30),
+        }
+    );
+}
@@ -0,0 +1,403 @@
+#![cfg(test)]
+
+use cosmwasm_std::{Addr, Coin, Decimal, StdResult};
+use cw_multi_test::{AppBuilder, BasicApp, Executor};
+use icns_name_nft::msg::ICNSNameExecuteMsg;
+
+use crate::{
+    msg::{
+        ExecuteMsg, QueryMsg, ReferralCount, ReferralLeaderboardResponse,
+        ReferralRewardShareResponse, ReferralRewardsResponse, ReferredNamesResponse,
+    },
+    tests::helpers::{
+        default_contracts_setup, fixtures::*, name_nft_contract, registrar_contract,
+        test_only_admin, verify_all,
+    },
+    ContractError,
+};
+
+#[test]
+fn only_admin_can_set_referral_reward_share() {
+    test_only_admin(
+        ExecuteMsg::SetReferralRewardShare {
+            share: Decimal::percent(10),
+        },
+        QueryMsg::ReferralRewardShare {},
+        ReferralRewardShareResponse {
+            share: Decimal::zero(),
+        },
+        ReferralRewardShareResponse {
+            share: Decimal::percent(10),
+        },
+    );
+}
+
+#[test]
+fn referral_rewards_are_claimable_by_referrer_name_owner() {
+    let bob = Addr::unchecked("bobaddr");
+    let alice = Addr::unchecked("aliceaddr");
+    let carol = Addr::unchecked("caroladdr");
+    let mut app = AppBuilder::default().build(|router, _, storage| {
+        for addr in [&bob, &alice] {
+            router
+                .bank
+                .init_balance(
+                    storage,
+                    addr,
+                    vec![
+                        Coin::new(100_000_000_000, "uion"),
+                        Coin::new(100_000_000_000, "uosmo"),
+                    ],
+                )
+                .unwrap();
+        }
+    });
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+
+    let (name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![Coin::new(1_000, "uosmo"), Coin::new(100, "uion")],
+    );
+
+    let claim = |app: &mut BasicApp,
+                 claimer: &Addr,
+                 name: &str,
+                 twitter_id: &str,
+                 referral: Option<&str>,
+                 funds: &[Coin]| {
+        let verifying_msg = format!(
+            r#"{{"name":"{name}","claimer":"{claimer}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"{twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{twitter_id}"}}"#,
+        );
+        app.execute_contract(
+            claimer.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
+                referral: referral.map(|referral| referral.to_string()),
+                secret: None,
+                owner: None,
+            },
+            funds,
+        )
+    };
+
+    let rewards = |app: &BasicApp, name: &str| -> StdResult<ReferralRewardsResponse> {
+        app.wrap().query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::ReferralRewards {
+                name: name.to_string(),
+            },
+        )
+    };
+
+    let claim_rewards = |app: &mut BasicApp, sender: &Addr, name: &str| {
+        app.execute_contract(
+            sender.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::ClaimReferralRewards {
+                name: name.to_string(),
+            },
+            &[],
+        )
+    };
+
+    claim(
+        &mut app,
+        &bob,
+        "bob",
+        "1",
+        None,
+        &[Coin::new(1_000, "uosmo")],
+    )
+    .unwrap();
+
+    // no rewards are credited before the share is set
+    claim(
+        &mut app,
+        &alice,
+        "alice",
+        "2",
+        Some("bob"),
+        &[Coin::new(1_000, "uosmo")],
+    )
+    .unwrap();
+    assert_eq!(
+        rewards(&app, "bob").unwrap(),
+        ReferralRewardsResponse {
+            pending: vec![],
+            paid: vec![]
+        }
+    );
+
+    // share above 100% is rejected
+    let err = app
+        .execute_contract(
+            Addr::unchecked(&admins[0]),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::SetReferralRewardShare {
+                share: Decimal::percent(101),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidReferralRewardShare {}
+    );
+
+    app.execute_contract(
+        Addr::unchecked(&admins[0]),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::SetReferralRewardShare {
+            share: Decimal::percent(10),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // share is taken from the fee actually paid, not the overpaid amount
+    claim(
+        &mut app,
+        &alice,
+        "alice2",
+        "3",
+        Some("bob"),
+        &[Coin::new(5_000, "uosmo")],
+    )
+    .unwrap();
+    claim(
+        &mut app,
+        &alice,
+        "alice3",
+        "4",
+        Some("bob"),
+        &[Coin::new(100, "uion")],
+    )
+    .unwrap();
+    claim(
+        &mut app,
+        &alice,
+        "alice4",
+        "5",
+        Some("bob"),
+        &[Coin::new(1_000, "uosmo")],
+    )
+    .unwrap();
+    assert_eq!(
+        rewards(&app, "bob").unwrap(),
+        ReferralRewardsResponse {
+            pending: vec![Coin::new(200, "uosmo"), Coin::new(10, "uion")],
+            paid: vec![]
+        }
+    );
+
+    // only the owner of the referrer name can claim the rewards
+    let err = claim_rewards(&mut app, &alice, "bob").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Unauthorized {}
+    );
+
+    claim_rewards(&mut app, &bob, "bob").unwrap();
+    assert_eq!(
+        rewards(&app, "bob").unwrap(),
+        ReferralRewardsResponse {
+            pending: vec![],
+            paid: vec![Coin::new(200, "uosmo"), Coin::new(10, "uion")]
+        }
+    );
+    assert_eq!(
+        app.wrap().query_all_balances(&bob).unwrap(),
+        vec![
+            Coin::new(100_000_000_010, "uion"),
+            Coin::new(99_999_999_200, "uosmo"),
+        ]
+    );
+
+    let err = claim_rewards(&mut app, &bob, "bob").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NoReferralRewards {
+            name: "bob".to_string()
+        }
+    );
+
+    // rewards credited after the name is transferred go to the new owner
+    app.execute_contract(
+        Addr::unchecked(&admins[0]),
+        name_nft_contract_addr.clone(),
+        &icns_name_nft::msg::ExecuteMsg::Extension {
+            msg: ICNSNameExecuteMsg::SetTransferrable {
+                transferrable: true,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        bob.clone(),
+        name_nft_contract_addr,
+        &icns_name_nft::msg::ExecuteMsg::TransferNft {
+            recipient: carol.to_string(),
+            token_id: "bob".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    claim(
+        &mut app,
+        &alice,
+        "alice5",
+        "6",
+        Some("bob"),
+        &[Coin::new(1_000, "uosmo")],
+    )
+    .unwrap();
+
+    let err = claim_rewards(&mut app, &bob, "bob").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Unauthorized {}
+    );
+
+    claim_rewards(&mut app, &carol, "bob").unwrap();
+    assert_eq!(
+        app.wrap().query_all_balances(&carol).unwrap(),
+        vec![Coin::new(100, "uosmo")]
+    );
+    assert_eq!(
+        rewards(&app, "bob").unwrap(),
+        ReferralRewardsResponse {
+            pending: vec![],
+            paid: vec![Coin::new(300, "uosmo"), Coin::new(10, "uion")]
+        }
+    );
+}
+
+#[test]
+fn referral_leaderboard_is_ordered_by_count() {
+    let mut app = BasicApp::default();
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+
+    let (_name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![],
+    );
+
+    let claim = |app: &mut BasicApp, name: &str, referral: Option<&str>| {
+        let claimer = Addr::unchecked(format!("{name}addr"));
+        let verifying_msg = format!(
+            r#"{{"name":"{name}","claimer":"{claimer}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"{name}","expiry":{{"at_height":1000000}},"nonce":"{name}"}}"#,
+        );
+        app.execute_contract(
+            claimer,
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
+                referral: referral.map(|referral| referral.to_string()),
+                secret: None,
+                owner: None,
+            },
+            &[],
+        )
+        .unwrap();
+    };
+
+    let leaderboard = |app: &BasicApp, start_after: Option<&str>, limit: Option<u32>| {
+        let ReferralLeaderboardResponse { referrals } = app
+            .wrap()
+            .query_wasm_smart(
+                registrar_contract_addr.clone(),
+                &QueryMsg::ReferralLeaderboard {
+                    start_after: start_after.map(|name| name.to_string()),
+                    limit,
+                },
+            )
+            .unwrap();
+        referrals
+            .into_iter()
+            .map(|ReferralCount { name, count }| (name, count))
+            .collect::<Vec<_>>()
+    };
+
+    let referred_names =
+        |app: &BasicApp, referrer: &str, start_after: Option<&str>, limit: Option<u32>| {
+            let ReferredNamesResponse { names } = app
+                .wrap()
+                .query_wasm_smart(
+                    registrar_contract_addr.clone(),
+                    &QueryMsg::ReferredNames {
+                        referrer: referrer.to_string(),
+                        start_after: start_after.map(|name| name.to_string()),
+                        limit,
+                    },
+                )
+                .unwrap();
+            names
+        };
+
+    claim(&mut app, "bob", None);
+    claim(&mut app, "alice", None);
+    claim(&mut app, "carol", None);
+    claim(&mut app, "dave", Some("alice"));
+    claim(&mut app, "erin", Some("bob"));
+    claim(&mut app, "frank", Some("alice"));
+    claim(&mut app, "grace", Some("carol"));
+    claim(&mut app, "heidi", Some("alice"));
+
+    let all = vec![
+        ("alice".to_string(), 3),
+        ("carol".to_string(), 1),
+        ("bob".to_string(), 1),
+    ];
+    assert_eq!(leaderboard(&app, None, None), all);
+
+    // paginate through the leaderboard
+    assert_eq!(leaderboard(&app, None, Some(2)), all[..2]);
+    assert_eq!(leaderboard(&app, Some("carol"), Some(2)), all[2..]);
+
+    // leaderboard is reordered as referral counts change
+    claim(&mut app, "ivan", Some("bob"));
+    claim(&mut app, "judy", Some("bob"));
+    claim(&mut app, "mallory", Some("bob"));
+    assert_eq!(
+        leaderboard(&app, None, None),
+        vec![
+            ("bob".to_string(), 4),
+            ("alice".to_string(), 3),
+            ("carol".to_string(), 1),
+        ]
+    );
+
+    let alice_referred = vec!["dave".to_string(), "frank".to_string(), "heidi".to_string()];
+    assert_eq!(referred_names(&app, "alice", None, None), alice_referred);
+    assert_eq!(
+        referred_names(&app, "dave", None, None),
+        Vec::<String>::new()
+    );
+
+    // paginate through the referred names
+    assert_eq!(
+        referred_names(&app, "alice", None, Some(2)),
+        alice_referred[..2]
+    );
+    assert_eq!(
+        referred_names(&app, "alice", Some("frank"), Some(2)),
+        alice_referred[2..]
+    );
+}
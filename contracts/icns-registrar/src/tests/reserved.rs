@@ -0,0 +1,205 @@
+#![cfg(test)]
+
+use cosmwasm_std::{Addr, StdResult};
+use cw721::OwnerOfResponse;
+use cw_multi_test::{BasicApp, Executor};
+
+use crate::{
+    msg::{ExecuteMsg, IsReservedResponse, QueryMsg, ReservedName, ReservedNamesResponse},
+    tests::helpers::{
+        default_contracts_setup, fixtures::*, name_nft_contract, registrar_contract,
+        test_only_admin, verify_all,
+    },
+    ContractError,
+};
+
+#[test]
+fn only_admin_can_reserve_names() {
+    test_only_admin(
+        ExecuteMsg::ReserveNames {
+            names: vec![ReservedName {
+                name: "osmosis".to_string(),
+                assignee: Some("osmosisaddr".to_string()),
+            }],
+        },
+        QueryMsg::IsReserved {
+            name: "osmosis".to_string(),
+        },
+        IsReservedResponse {
+            reserved: false,
+            assignee: None,
+        },
+        IsReservedResponse {
+            reserved: true,
+            assignee: Some("osmosisaddr".to_string()),
+        },
+    );
+}
+
+#[test]
+fn reserved_names_can_only_be_claimed_by_admin_or_assignee() {
+    let bob = Addr::unchecked("bobaddr");
+    let alice = Addr::unchecked("aliceaddr");
+    let mut app = BasicApp::default();
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+    let admin = Addr::unchecked(&admins[0]);
+
+    let (name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![],
+    );
+
+    let owner = |app: &BasicApp, name: &str| -> StdResult<_> {
+        let OwnerOfResponse { owner, .. } = app.wrap().query_wasm_smart(
+            name_nft_contract_addr.clone(),
+            &icns_name_nft::QueryMsg::OwnerOf {
+                token_id: name.to_string(),
+                include_expired: None,
+            },
+        )?;
+
+        Ok(owner)
+    };
+
+    let claim = |app: &mut BasicApp, claimer: &Addr, name: &str, twitter_id: &str| {
+        let verifying_msg = format!(
+            r#"{{"name":"{name}","claimer":"{claimer}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"{twitter_id}","expiry":{{"at_height":1000000}},"nonce":"{twitter_id}"}}"#,
+        );
+        app.execute_contract(
+            claimer.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
+                referral: None,
+                secret: None,
+                owner: None,
+            },
+            &[],
+        )
+    };
+
+    let reserved_names = |app: &BasicApp, start_after: Option<&str>, limit: Option<u32>| {
+        let ReservedNamesResponse { names } = app
+            .wrap()
+            .query_wasm_smart(
+                registrar_contract_addr.clone(),
+                &QueryMsg::ReservedNames {
+                    start_after: start_after.map(|name| name.to_string()),
+                    limit,
+                },
+            )
+            .unwrap();
+        names
+    };
+
+    app.execute_contract(
+        admin.clone(),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::ReserveNames {
+            names: vec![
+                ReservedName {
+                    name: "osmosis".to_string(),
+                    assignee: Some(bob.to_string()),
+                },
+                ReservedName {
+                    name: "keplr".to_string(),
+                    assignee: None,
+                },
+                ReservedName {
+                    name: "icns".to_string(),
+                    assignee: None,
+                },
+            ],
+        },
+        &[],
+    )
+    .unwrap();
+
+    // reserved names are listed in order
+    assert_eq!(
+        reserved_names(&app, None, None),
+        vec![
+            ReservedName {
+                name: "icns".to_string(),
+                assignee: None,
+            },
+            ReservedName {
+                name: "keplr".to_string(),
+                assignee: None,
+            },
+            ReservedName {
+                name: "osmosis".to_string(),
+                assignee: Some(bob.to_string()),
+            },
+        ]
+    );
+    assert_eq!(
+        reserved_names(&app, Some("icns"), Some(1)),
+        vec![ReservedName {
+            name: "keplr".to_string(),
+            assignee: None,
+        }]
+    );
+
+    // reserved names can not be claimed by others, even with verifications
+    for name in ["osmosis", "keplr"] {
+        let err = claim(&mut app, &alice, name, "1").unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<ContractError>().unwrap(),
+            &ContractError::NameReserved {
+                name: name.to_string()
+            }
+        );
+    }
+
+    // assignee can claim the reserved name
+    claim(&mut app, &bob, "osmosis", "2").unwrap();
+    assert_eq!(owner(&app, "osmosis").unwrap(), bob);
+
+    // admin can claim the reserved name
+    let verifying_msg = format!(
+        r#"{{"name":"keplr","claimer":"{admin}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"4","expiry":{{"at_height":1000000}},"nonce":"4"}}"#,
+    );
+    app.execute_contract(
+        admin.clone(),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::Claim {
+            name: "keplr".to_string(),
+            verifying_msg,
+            verifications: vec![],
+            referral: None,
+            secret: None,
+            owner: None,
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(owner(&app, "keplr").unwrap(), admin);
+
+    // unreserved name can be claimed by anyone
+    let err = claim(&mut app, &alice, "icns", "3").unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NameReserved {
+            name: "icns".to_string()
+        }
+    );
+    app.execute_contract(
+        admin,
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::UnreserveNames {
+            names: vec!["icns".to_string()],
+        },
+        &[],
+    )
+    .unwrap();
+    claim(&mut app, &alice, "icns", "3").unwrap();
+    assert_eq!(owner(&app, "icns").unwrap(), alice);
+}
@@ -4,11 +4,15 @@ use cosmwasm_std::{Addr, Decimal};
 use cw_multi_test::{BasicApp, Executor};
 
 use crate::{
-    msg::{ExecuteMsg, InstantiateMsg, QueryMsg, VerificationThresholdResponse},
+    msg::{
+        ExecuteMsg, IdentityProviderInfo, InstantiateMsg, QueryMsg, VerificationThresholdResponse,
+    },
     ContractError,
 };
 
-use super::helpers::{fixtures::*, name_nft_contract, registrar_contract, ToBinary};
+use super::helpers::{
+    fixtures::*, name_nft_contract, registrar_contract, test_only_admin, verifier_pubkey,
+};
 
 #[test]
 fn only_admin_can_set_threshold() {
@@ -39,9 +43,12 @@ fn only_admin_can_set_threshold() {
             Addr::unchecked(admins[0].clone()),
             &InstantiateMsg {
                 name_nft_addr: name_nft_contract_addr.to_string(),
-                verifier_pubkeys: vec![verifier2().to_binary()],
-                verification_threshold: Decimal::percent(50),
-                fee: None,
+                identity_providers: vec![IdentityProviderInfo {
+                    provider: "twitter".to_string(),
+                    verifier_pubkeys: vec![verifier_pubkey(&verifier2(), 1)],
+                    verification_threshold: Decimal::percent(50),
+                }],
+                fees: vec![],
             },
             &[],
             "registar",
@@ -50,11 +57,14 @@ fn only_admin_can_set_threshold() {
         .unwrap();
     let VerificationThresholdResponse {
         verification_threshold_percentage,
+        ..
     } = app
         .wrap()
         .query_wasm_smart(
             registrar_contract_addr.clone(),
-            &QueryMsg::VerificationThreshold {},
+            &QueryMsg::VerificationThreshold {
+                provider: "twitter".to_string(),
+            },
         )
         .unwrap();
 
@@ -66,6 +76,7 @@ fn only_admin_can_set_threshold() {
             Addr::unchecked("random_guy"),
             registrar_contract_addr.clone(),
             &ExecuteMsg::SetVerificationThreshold {
+                provider: "twitter".to_string(),
                 threshold: Decimal::percent(10),
             },
             &[],
@@ -82,6 +93,7 @@ fn only_admin_can_set_threshold() {
         Addr::unchecked(admins[0].clone()),
         registrar_contract_addr.clone(),
         &ExecuteMsg::SetVerificationThreshold {
+            provider: "twitter".to_string(),
             threshold: Decimal::percent(10),
         },
         &[],
@@ -90,10 +102,37 @@ fn only_admin_can_set_threshold() {
 
     let VerificationThresholdResponse {
         verification_threshold_percentage,
+        ..
     } = app
         .wrap()
-        .query_wasm_smart(registrar_contract_addr, &QueryMsg::VerificationThreshold {})
+        .query_wasm_smart(
+            registrar_contract_addr,
+            &QueryMsg::VerificationThreshold {
+                provider: "twitter".to_string(),
+            },
+        )
         .unwrap();
 
     assert_eq!(verification_threshold_percentage, Decimal::percent(10));
 }
+
+#[test]
+fn only_admin_can_set_absolute_threshold() {
+    test_only_admin(
+        ExecuteMsg::SetAbsoluteVerificationThreshold {
+            provider: "twitter".to_string(),
+            threshold: Some(1),
+        },
+        QueryMsg::VerificationThreshold {
+            provider: "twitter".to_string(),
+        },
+        VerificationThresholdResponse {
+            verification_threshold_percentage: Decimal::percent(50),
+            absolute_verification_threshold: None,
+        },
+        VerificationThresholdResponse {
+            verification_threshold_percentage: Decimal::percent(50),
+            absolute_verification_threshold: Some(1),
+        },
+    );
+}
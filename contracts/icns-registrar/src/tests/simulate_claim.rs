@@ -0,0 +1,195 @@
+#![cfg(test)]
+
+use cosmrs::crypto::secp256k1::SigningKey;
+use cosmwasm_std::{Addr, Coin, Decimal};
+use cw_multi_test::{AppBuilder, BasicApp, Executor};
+use icns_name_nft::msg::NameViolation;
+
+use crate::{
+    msg::{ClaimCheck, ClaimCheckFailure, ExecuteMsg, QueryMsg, SimulateClaimResponse},
+    tests::helpers::{
+        default_contracts_setup, fixtures::*, name_nft_contract, registrar_contract, verify_all,
+    },
+    ContractError,
+};
+
+#[test]
+fn simulate_claim_reports_every_failing_check() {
+    let bob = Addr::unchecked("bobaddr");
+    let alice = Addr::unchecked("aliceaddr");
+    let mut app = AppBuilder::default().build(|router, _, storage| {
+        router
+            .bank
+            .init_balance(storage, &bob, vec![Coin::new(1000, "uosmo")])
+            .unwrap();
+    });
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+
+    let (_name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins,
+        vec![Coin::new(100, "uosmo")],
+    );
+
+    let verifying_msg = |name: &str, claimer: &Addr| {
+        format!(
+            r#"{{"name":"{name}","claimer":"{claimer}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"1","expiry":{{"at_height":1000000}},"nonce":"1"}}"#,
+        )
+    };
+
+    let simulate = |app: &BasicApp,
+                    name: &str,
+                    verifying_msg: &str,
+                    verifiers: Vec<SigningKey>,
+                    referral: Option<&str>,
+                    funds: Vec<Coin>| {
+        let SimulateClaimResponse { failures } = app
+            .wrap()
+            .query_wasm_smart(
+                registrar_contract_addr.clone(),
+                &QueryMsg::SimulateClaim {
+                    name: name.to_string(),
+                    claimer: bob.to_string(),
+                    verifying_msg: verifying_msg.to_string(),
+                    verifications: verify_all(verifying_msg, verifiers),
+                    referral: referral.map(|referral| referral.to_string()),
+                    secret: None,
+                    owner: None,
+                    funds,
+                },
+            )
+            .unwrap();
+        failures
+    };
+
+    // every failing check is reported, not just the first one
+    assert_eq!(
+        simulate(
+            &app,
+            "bob.dylan",
+            &verifying_msg("bob.dylan", &alice),
+            vec![verifier1()],
+            Some("nobody"),
+            vec![],
+        ),
+        vec![
+            ClaimCheckFailure {
+                check: ClaimCheck::Name,
+                error: ContractError::InvalidName {
+                    name: "bob.dylan".to_string()
+                }
+                .to_string(),
+            },
+            ClaimCheckFailure {
+                check: ClaimCheck::VerifyingMsg,
+                error: ContractError::InvalidVerifyingMessage {
+                    msg: format!("claimer mismatched: expected `{bob}` but got `{alice}`"),
+                }
+                .to_string(),
+            },
+            ClaimCheckFailure {
+                check: ClaimCheck::Verifications,
+                error: ContractError::ValidVerificationIsBelowThreshold {
+                    expected_over: Decimal::percent(50),
+                    actual: Decimal::percent(25),
+                }
+                .to_string(),
+            },
+            ClaimCheckFailure {
+                check: ClaimCheck::Referral,
+                error: ContractError::InvalidReferral {
+                    referral: "nobody".to_string()
+                }
+                .to_string(),
+            },
+            ClaimCheckFailure {
+                check: ClaimCheck::Fee,
+                error: ContractError::InvalidFee {
+                    fee_required: vec![Coin::new(100, "uosmo")]
+                }
+                .to_string(),
+            },
+        ]
+    );
+
+    // passing claim has no failure
+    let bob_verifying_msg = verifying_msg("bob", &bob);
+    assert_eq!(
+        simulate(
+            &app,
+            "bob",
+            &bob_verifying_msg,
+            vec![verifier1(), verifier2()],
+            None,
+            vec![Coin::new(100, "uosmo")],
+        ),
+        vec![]
+    );
+
+    app.execute_contract(
+        bob.clone(),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::Claim {
+            name: "bob".to_string(),
+            verifying_msg: bob_verifying_msg.clone(),
+            verifications: verify_all(&bob_verifying_msg, vec![verifier1(), verifier2()]),
+            referral: None,
+            secret: None,
+            owner: None,
+        },
+        &[Coin::new(100, "uosmo")],
+    )
+    .unwrap();
+
+    // claimed name and used identity are reported
+    assert_eq!(
+        simulate(
+            &app,
+            "bob",
+            &bob_verifying_msg,
+            vec![verifier1(), verifier2()],
+            None,
+            vec![Coin::new(100, "uosmo")],
+        ),
+        vec![
+            ClaimCheckFailure {
+                check: ClaimCheck::Availability,
+                error: ContractError::NameAlreadyClaimed {
+                    name: "bob".to_string()
+                }
+                .to_string(),
+            },
+            ClaimCheckFailure {
+                check: ClaimCheck::VerifyingMsg,
+                error: ContractError::DuplicatedIdentity {
+                    provider: "twitter".to_string(),
+                    id: "1".to_string()
+                }
+                .to_string(),
+            },
+        ]
+    );
+    // name violating the name policy of the name nft contract is reported
+    assert_eq!(
+        simulate(
+            &app,
+            "-bob",
+            &verifying_msg("-bob", &bob),
+            vec![verifier1(), verifier2()],
+            None,
+            vec![Coin::new(100, "uosmo")],
+        )[0],
+        ClaimCheckFailure {
+            check: ClaimCheck::Name,
+            error: ContractError::NameNotAllowed {
+                name: "-bob".to_string(),
+                violation: NameViolation::LeadingHyphen,
+            }
+            .to_string(),
+        }
+    );
+}
@@ -0,0 +1,271 @@
+#![cfg(test)]
+
+use cosmwasm_std::{Addr, Coin, Decimal, Uint128};
+use cw_multi_test::{AppBuilder, BasicApp, Executor};
+
+use crate::{
+    msg::{DenomFeeDistribution, ExecuteMsg, FeeDistributionsResponse, QueryMsg, TreasuryResponse},
+    state::{Recipient, TreasuryRecipient},
+    tests::helpers::{
+        default_contracts_setup, fixtures::*, name_nft_contract, registrar_contract,
+        test_only_admin, verify_all,
+    },
+    ContractError,
+};
+
+fn address_recipient(address: &str, percent: u64) -> TreasuryRecipient {
+    TreasuryRecipient {
+        recipient: Recipient::Address {
+            address: address.to_string(),
+        },
+        share: Decimal::percent(percent),
+    }
+}
+
+#[test]
+fn only_admin_can_set_treasury() {
+    test_only_admin(
+        ExecuteMsg::SetTreasury {
+            recipients: vec![
+                address_recipient("treasury", 60),
+                TreasuryRecipient {
+                    recipient: Recipient::CommunityPool {},
+                    share: Decimal::percent(40),
+                },
+            ],
+            auto_distribute: false,
+        },
+        QueryMsg::Treasury {},
+        TreasuryResponse {
+            recipients: vec![],
+            auto_distribute: false,
+        },
+        TreasuryResponse {
+            recipients: vec![
+                address_recipient("treasury", 60),
+                TreasuryRecipient {
+                    recipient: Recipient::CommunityPool {},
+                    share: Decimal::percent(40),
+                },
+            ],
+            auto_distribute: false,
+        },
+    );
+}
+
+#[test]
+fn collected_fees_are_distributed_to_treasury() {
+    let bob = Addr::unchecked("bobaddr");
+    let alice = Addr::unchecked("aliceaddr");
+    let carol = Addr::unchecked("caroladdr");
+    let mut app = AppBuilder::default().build(|router, _, storage| {
+        for addr in [&bob, &alice, &carol] {
+            router
+                .bank
+                .init_balance(storage, addr, vec![Coin::new(100_000_000_000, "uosmo")])
+                .unwrap();
+        }
+    });
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+    let admin = Addr::unchecked(&admins[0]);
+
+    let (_name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![Coin::new(1_000, "uosmo")],
+    );
+
+    let claim = |app: &mut BasicApp, claimer: &Addr, name: &str, referral: Option<&str>| {
+        let verifying_msg = format!(
+            r#"{{"name":"{name}","claimer":"{claimer}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"{name}","expiry":{{"at_height":1000000}},"nonce":"{name}"}}"#,
+        );
+        app.execute_contract(
+            claimer.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, vec![verifier1(), verifier2()]),
+                referral: referral.map(|referral| referral.to_string()),
+                secret: None,
+                owner: None,
+            },
+            &[Coin::new(1_000, "uosmo")],
+        )
+    };
+
+    let set_treasury =
+        |app: &mut BasicApp, recipients: Vec<TreasuryRecipient>, auto_distribute: bool| {
+            app.execute_contract(
+                admin.clone(),
+                registrar_contract_addr.clone(),
+                &ExecuteMsg::SetTreasury {
+                    recipients,
+                    auto_distribute,
+                },
+                &[],
+            )
+        };
+
+    let distribute_fees = |app: &mut BasicApp| {
+        app.execute_contract(
+            carol.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::DistributeFees {},
+            &[],
+        )
+    };
+
+    let fee_distributions = |app: &BasicApp| -> Vec<DenomFeeDistribution> {
+        let FeeDistributionsResponse { distributions } = app
+            .wrap()
+            .query_wasm_smart(
+                registrar_contract_addr.clone(),
+                &QueryMsg::FeeDistributions {},
+            )
+            .unwrap();
+        distributions
+    };
+
+    let balance =
+        |app: &BasicApp, address: &str| app.wrap().query_balance(address, "uosmo").unwrap().amount;
+
+    // shares must add up to 1
+    let err = set_treasury(
+        &mut app,
+        vec![
+            address_recipient("treasury", 60),
+            address_recipient("dev", 30),
+        ],
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidTreasury {
+            msg: "shares must add up to 1".to_string()
+        }
+    );
+
+    // shares overflowing their sum do not add up to 1
+    let err = set_treasury(
+        &mut app,
+        vec![
+            TreasuryRecipient {
+                recipient: Recipient::Address {
+                    address: "treasury".to_string(),
+                },
+                share: Decimal::MAX,
+            },
+            address_recipient("dev", 100),
+        ],
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidTreasury {
+            msg: "shares must add up to 1".to_string()
+        }
+    );
+
+    // zero share is not allowed
+    let err = set_treasury(
+        &mut app,
+        vec![
+            address_recipient("treasury", 100),
+            address_recipient("dev", 0),
+        ],
+        false,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidTreasury {
+            msg: "share must be greater than 0".to_string()
+        }
+    );
+
+    // fees can not be distributed without treasury
+    let err = distribute_fees(&mut app).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NoTreasury {}
+    );
+
+    // referral reward is excluded from the collected fees
+    app.execute_contract(
+        admin.clone(),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::SetReferralRewardShare {
+            share: Decimal::percent(10),
+        },
+        &[],
+    )
+    .unwrap();
+    claim(&mut app, &bob, "bob", None).unwrap();
+    claim(&mut app, &alice, "alice", Some("bob")).unwrap();
+    assert_eq!(
+        fee_distributions(&app),
+        vec![DenomFeeDistribution {
+            denom: "uosmo".to_string(),
+            collected: Uint128::new(1_900),
+            distributed: Uint128::zero(),
+        }]
+    );
+
+    // anyone can distribute the collected fees
+    set_treasury(
+        &mut app,
+        vec![
+            address_recipient("treasury", 70),
+            address_recipient("dev", 30),
+        ],
+        false,
+    )
+    .unwrap();
+    distribute_fees(&mut app).unwrap();
+    assert_eq!(balance(&app, "treasury"), Uint128::new(1_330));
+    assert_eq!(balance(&app, "dev"), Uint128::new(570));
+    assert_eq!(
+        fee_distributions(&app),
+        vec![DenomFeeDistribution {
+            denom: "uosmo".to_string(),
+            collected: Uint128::new(1_900),
+            distributed: Uint128::new(1_900),
+        }]
+    );
+
+    // nothing left to distribute
+    let err = distribute_fees(&mut app).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::NoPendingFees {}
+    );
+
+    // fees are distributed on claim when auto distribution is enabled
+    set_treasury(
+        &mut app,
+        vec![
+            address_recipient("treasury", 70),
+            address_recipient("dev", 30),
+        ],
+        true,
+    )
+    .unwrap();
+    claim(&mut app, &carol, "carol", None).unwrap();
+    assert_eq!(balance(&app, "treasury"), Uint128::new(2_030));
+    assert_eq!(balance(&app, "dev"), Uint128::new(870));
+    assert_eq!(
+        fee_distributions(&app),
+        vec![DenomFeeDistribution {
+            denom: "uosmo".to_string(),
+            collected: Uint128::new(2_900),
+            distributed: Uint128::new(2_900),
+        }]
+    );
+}
@@ -1,14 +1,22 @@
 #![cfg(test)]
 
+use cosmrs::crypto::secp256k1::SigningKey;
 use cosmwasm_std::{Addr, Binary, Decimal};
 use cw_multi_test::{BasicApp, Executor};
 
 use crate::{
-    msg::{ExecuteMsg, InstantiateMsg, QueryMsg, VerifierPubKeysResponse},
+    msg::{
+        ExecuteMsg, IdentityProviderInfo, InstantiateMsg, QueryMsg, Verification, VerifierPubKey,
+        VerifierPubKeysResponse,
+    },
+    state::KeyType,
     ContractError,
 };
 
-use super::helpers::{fixtures::*, name_nft_contract, registrar_contract, ToBinary};
+use super::helpers::{
+    default_contracts_setup, ed25519_verifier_pubkey, ed25519_verify_all, fixtures::*,
+    name_nft_contract, registrar_contract, verifier_pubkey, verify_all, ToBinary,
+};
 
 #[test]
 fn only_admin_can_update_verifier() {
@@ -39,9 +47,12 @@ fn only_admin_can_update_verifier() {
             Addr::unchecked(admins[0].clone()),
             &InstantiateMsg {
                 name_nft_addr: name_nft_contract_addr.to_string(),
-                verifier_pubkeys: vec![verifier2().to_binary()],
-                verification_threshold: Decimal::percent(50),
-                fee: None,
+                identity_providers: vec![IdentityProviderInfo {
+                    provider: "twitter".to_string(),
+                    verifier_pubkeys: vec![verifier_pubkey(&verifier2(), 1)],
+                    verification_threshold: Decimal::percent(50),
+                }],
+                fees: vec![],
             },
             &[],
             "registar",
@@ -55,7 +66,8 @@ fn only_admin_can_update_verifier() {
             Addr::unchecked("random_guy"),
             registrar_contract_addr.clone(),
             &ExecuteMsg::UpdateVerifierPubkeys {
-                add: vec![verifier1().to_binary()],
+                provider: "twitter".to_string(),
+                add: vec![verifier_pubkey(&verifier1(), 1)],
                 remove: vec![verifier2().to_binary()],
             },
             &[],
@@ -71,18 +83,21 @@ fn only_admin_can_update_verifier() {
         .wrap()
         .query_wasm_smart(
             registrar_contract_addr.clone(),
-            &QueryMsg::VerifierPubKeys {},
+            &QueryMsg::VerifierPubKeys {
+                provider: "twitter".to_string(),
+            },
         )
         .unwrap();
 
-    assert_eq!(verifier_pubkeys, vec![verifier2().to_binary()]);
+    assert_eq!(verifier_pubkeys, vec![verifier_pubkey(&verifier2(), 1)]);
 
     // authorized if admin
     app.execute_contract(
         Addr::unchecked(admins[0].clone()),
         registrar_contract_addr.clone(),
         &ExecuteMsg::UpdateVerifierPubkeys {
-            add: vec![verifier1().to_binary()],
+            provider: "twitter".to_string(),
+            add: vec![verifier_pubkey(&verifier1(), 1)],
             remove: vec![verifier2().to_binary()],
         },
         &[],
@@ -91,10 +106,15 @@ fn only_admin_can_update_verifier() {
 
     let VerifierPubKeysResponse { verifier_pubkeys } = app
         .wrap()
-        .query_wasm_smart(registrar_contract_addr, &QueryMsg::VerifierPubKeys {})
+        .query_wasm_smart(
+            registrar_contract_addr,
+            &QueryMsg::VerifierPubKeys {
+                provider: "twitter".to_string(),
+            },
+        )
         .unwrap();
 
-    assert_eq!(verifier_pubkeys, vec![verifier1().to_binary()]);
+    assert_eq!(verifier_pubkeys, vec![verifier_pubkey(&verifier1(), 1)]);
 }
 
 #[test]
@@ -126,9 +146,12 @@ fn update_verifier_must_keep_verifier_state_unique() {
             Addr::unchecked(admins[0].clone()),
             &InstantiateMsg {
                 name_nft_addr: name_nft_contract_addr.to_string(),
-                verifier_pubkeys: vec![],
-                verification_threshold: Decimal::percent(50),
-                fee: None,
+                identity_providers: vec![IdentityProviderInfo {
+                    provider: "twitter".to_string(),
+                    verifier_pubkeys: vec![],
+                    verification_threshold: Decimal::percent(50),
+                }],
+                fees: vec![],
             },
             &[],
             "registar",
@@ -141,11 +164,12 @@ fn update_verifier_must_keep_verifier_state_unique() {
         Addr::unchecked(admins[0].clone()),
         registrar_contract_addr.clone(),
         &ExecuteMsg::UpdateVerifierPubkeys {
+            provider: "twitter".to_string(),
             add: vec![
-                verifier1().to_binary(), // dup 1
-                verifier1().to_binary(), // dup 1
-                verifier2().to_binary(), // to remove 2
-                verifier3().to_binary(), // -> kept
+                verifier_pubkey(&verifier1(), 1), // dup 1
+                verifier_pubkey(&verifier1(), 1), // dup 1
+                verifier_pubkey(&verifier2(), 1), // to remove 2
+                verifier_pubkey(&verifier3(), 1), // -> kept
             ],
             remove: vec![verifier2().to_binary()], // remove 2
         },
@@ -157,21 +181,27 @@ fn update_verifier_must_keep_verifier_state_unique() {
         .wrap()
         .query_wasm_smart(
             registrar_contract_addr.clone(),
-            &QueryMsg::VerifierPubKeys {},
+            &QueryMsg::VerifierPubKeys {
+                provider: "twitter".to_string(),
+            },
         )
         .unwrap();
 
     assert_eq!(
         verifier_pubkeys,
-        vec![verifier1().to_binary(), verifier3().to_binary()]
+        vec![
+            verifier_pubkey(&verifier1(), 1),
+            verifier_pubkey(&verifier3(), 1)
+        ]
     );
 
-    // add existing pubkey should not change anything
+    // add existing pubkey should not change anything but its weight
     app.execute_contract(
         Addr::unchecked(admins[0].clone()),
         registrar_contract_addr.clone(),
         &ExecuteMsg::UpdateVerifierPubkeys {
-            add: vec![verifier3().to_binary()],
+            provider: "twitter".to_string(),
+            add: vec![verifier_pubkey(&verifier3(), 1)],
             remove: vec![],
         },
         &[],
@@ -180,12 +210,20 @@ fn update_verifier_must_keep_verifier_state_unique() {
 
     let VerifierPubKeysResponse { verifier_pubkeys } = app
         .wrap()
-        .query_wasm_smart(registrar_contract_addr, &QueryMsg::VerifierPubKeys {})
+        .query_wasm_smart(
+            registrar_contract_addr,
+            &QueryMsg::VerifierPubKeys {
+                provider: "twitter".to_string(),
+            },
+        )
         .unwrap();
 
     assert_eq!(
         verifier_pubkeys,
-        vec![verifier1().to_binary(), verifier3().to_binary()]
+        vec![
+            verifier_pubkey(&verifier1(), 1),
+            verifier_pubkey(&verifier3(), 1)
+        ]
     );
 }
 
@@ -218,9 +256,12 @@ fn adding_invalid_pubkeys_is_not_allowed() {
             Addr::unchecked(admins[0].clone()),
             &InstantiateMsg {
                 name_nft_addr: name_nft_contract_addr.to_string(),
-                verifier_pubkeys: vec![],
-                verification_threshold: Decimal::percent(50),
-                fee: None,
+                identity_providers: vec![IdentityProviderInfo {
+                    provider: "twitter".to_string(),
+                    verifier_pubkeys: vec![],
+                    verification_threshold: Decimal::percent(50),
+                }],
+                fees: vec![],
             },
             &[],
             "registar",
@@ -233,7 +274,12 @@ fn adding_invalid_pubkeys_is_not_allowed() {
             Addr::unchecked(admins[0].clone()),
             registrar_contract_addr,
             &ExecuteMsg::UpdateVerifierPubkeys {
-                add: vec![Binary(vec![10, 2, 1])],
+                provider: "twitter".to_string(),
+                add: vec![VerifierPubKey {
+                    pubkey: Binary(vec![10, 2, 1]),
+                    weight: 1,
+                    key_type: KeyType::Secp256k1,
+                }],
                 remove: vec![verifier2().to_binary()],
             },
             &[],
@@ -245,3 +291,307 @@ fn adding_invalid_pubkeys_is_not_allowed() {
         &ContractError::InvalidPublicKeyLength {}
     );
 }
+
+#[test]
+fn verification_threshold_is_weighted() {
+    let mut app = BasicApp::default();
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+    let admin = Addr::unchecked(&admins[0]);
+    let bob = Addr::unchecked("bobaddr");
+
+    let (_name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![],
+    );
+
+    let claim = |app: &mut BasicApp, name: &str, verifiers: Vec<SigningKey>| {
+        let verifying_msg = format!(
+            r#"{{"name":"{name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"{name}","expiry":{{"at_height":1000000}},"nonce":"{name}"}}"#,
+        );
+        app.execute_contract(
+            bob.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg.clone(),
+                verifications: verify_all(&verifying_msg, verifiers),
+                referral: None,
+                secret: None,
+                owner: None,
+            },
+            &[],
+        )
+    };
+
+    // zero weight is not allowed
+    let err = app
+        .execute_contract(
+            admin.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::UpdateVerifierPubkeys {
+                provider: "twitter".to_string(),
+                add: vec![verifier_pubkey(&verifier1(), 0)],
+                remove: vec![],
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidVerifierWeight {}
+    );
+
+    // verifier1 weighs 4 out of 7, passing the 50% threshold alone
+    app.execute_contract(
+        admin.clone(),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::UpdateVerifierPubkeys {
+            provider: "twitter".to_string(),
+            add: vec![verifier_pubkey(&verifier1(), 4)],
+            remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err = claim(
+        &mut app,
+        "alice",
+        vec![verifier2(), verifier3(), verifier4()],
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::ValidVerificationIsBelowThreshold {
+            expected_over: Decimal::percent(50),
+            actual: Decimal::from_ratio(3u64, 7u64)
+        }
+    );
+    claim(&mut app, "bob", vec![verifier1()]).unwrap();
+
+    // absolute threshold takes precedence over the percentage
+    let err = app
+        .execute_contract(
+            admin.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::SetAbsoluteVerificationThreshold {
+                provider: "twitter".to_string(),
+                threshold: Some(0),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidAbsoluteThreshold {}
+    );
+
+    // absolute threshold can not exceed the total weight of the verifiers
+    let err = app
+        .execute_contract(
+            admin.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::SetAbsoluteVerificationThreshold {
+                provider: "twitter".to_string(),
+                threshold: Some(8),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::AbsoluteThresholdAboveTotalWeight {
+            threshold: 8,
+            total_weight: 7
+        }
+    );
+
+    app.execute_contract(
+        admin.clone(),
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::SetAbsoluteVerificationThreshold {
+            provider: "twitter".to_string(),
+            threshold: Some(5),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let err = claim(&mut app, "carol", vec![verifier1()]).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::ValidVerificationWeightIsBelowThreshold {
+            expected_at_least: 5,
+            actual: 4
+        }
+    );
+    claim(&mut app, "carol", vec![verifier1(), verifier2()]).unwrap();
+
+    // removing verifiers can not drop the total weight below the absolute threshold
+    let err = app
+        .execute_contract(
+            admin,
+            registrar_contract_addr,
+            &ExecuteMsg::UpdateVerifierPubkeys {
+                provider: "twitter".to_string(),
+                add: vec![],
+                remove: vec![verifier1().to_binary()],
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::AbsoluteThresholdAboveTotalWeight {
+            threshold: 5,
+            total_weight: 3
+        }
+    );
+}
+
+#[test]
+fn mixed_key_type_verifications_reach_threshold_together() {
+    let mut app = BasicApp::default();
+    let name_nft_code_id = app.store_code(name_nft_contract());
+    let registrar_code_id = app.store_code(registrar_contract());
+    let admins = vec!["admin1".to_string(), "admin2".to_string()];
+    let admin = Addr::unchecked(&admins[0]);
+    let bob = Addr::unchecked("bobaddr");
+
+    let (_name_nft_contract_addr, registrar_contract_addr) = default_contracts_setup(
+        &mut app,
+        name_nft_code_id,
+        registrar_code_id,
+        admins.clone(),
+        vec![],
+    );
+
+    let verifying_msg = |name: &str| {
+        format!(
+            r#"{{"name":"{name}","claimer":"{bob}","contract_address":"{registrar_contract_addr}","chain_id":"cosmos-testnet-14002","provider":"twitter","subject_id":"{name}","expiry":{{"at_height":1000000}},"nonce":"{name}"}}"#,
+        )
+    };
+    let claim = |app: &mut BasicApp, name: &str, verifications: Vec<Verification>| {
+        app.execute_contract(
+            bob.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::Claim {
+                name: name.to_string(),
+                verifying_msg: verifying_msg(name),
+                verifications,
+                referral: None,
+                secret: None,
+                owner: None,
+            },
+            &[],
+        )
+    };
+
+    // ed25519 pubkey has no sec1 tag
+    let err = app
+        .execute_contract(
+            admin.clone(),
+            registrar_contract_addr.clone(),
+            &ExecuteMsg::UpdateVerifierPubkeys {
+                provider: "twitter".to_string(),
+                add: vec![VerifierPubKey {
+                    key_type: KeyType::Ed25519,
+                    ..verifier_pubkey(&verifier1(), 1)
+                }],
+                remove: vec![],
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidPublicKeyLength {}
+    );
+
+    app.execute_contract(
+        admin,
+        registrar_contract_addr.clone(),
+        &ExecuteMsg::UpdateVerifierPubkeys {
+            provider: "twitter".to_string(),
+            add: vec![
+                ed25519_verifier_pubkey(&ed25519_verifier1(), 1),
+                ed25519_verifier_pubkey(&ed25519_verifier2(), 1),
+            ],
+            remove: vec![],
+        },
+        &[],
+    )
+    .unwrap();
+
+    let VerifierPubKeysResponse { verifier_pubkeys } = app
+        .wrap()
+        .query_wasm_smart(
+            registrar_contract_addr.clone(),
+            &QueryMsg::VerifierPubKeys {
+                provider: "twitter".to_string(),
+            },
+        )
+        .unwrap();
+    assert_eq!(
+        verifier_pubkeys[4..],
+        [
+            ed25519_verifier_pubkey(&ed25519_verifier1(), 1),
+            ed25519_verifier_pubkey(&ed25519_verifier2(), 1)
+        ]
+    );
+
+    // ed25519 signature of another message is invalid
+    let err = claim(
+        &mut app,
+        "alice",
+        [
+            verify_all(&verifying_msg("alice"), vec![verifier1(), verifier2()]),
+            ed25519_verify_all(&verifying_msg("bob"), vec![ed25519_verifier1()]),
+        ]
+        .concat(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::InvalidSignature {}
+    );
+
+    // 2 out of 6 does not pass the 50% threshold
+    let err = claim(
+        &mut app,
+        "alice",
+        [
+            verify_all(&verifying_msg("alice"), vec![verifier1()]),
+            ed25519_verify_all(&verifying_msg("alice"), vec![ed25519_verifier1()]),
+        ]
+        .concat(),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::ValidVerificationIsBelowThreshold {
+            expected_over: Decimal::percent(50),
+            actual: Decimal::from_ratio(2u64, 6u64)
+        }
+    );
+
+    // 3 out of 6 passes with ed25519 signatures verified in batch
+    claim(
+        &mut app,
+        "alice",
+        [
+            verify_all(&verifying_msg("alice"), vec![verifier1()]),
+            ed25519_verify_all(
+                &verifying_msg("alice"),
+                vec![ed25519_verifier1(), ed25519_verifier2()],
+            ),
+        ]
+        .concat(),
+    )
+    .unwrap();
+}
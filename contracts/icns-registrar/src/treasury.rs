@@ -0,0 +1,301 @@
+use cosmos_sdk_proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmos_sdk_proto::cosmos::distribution::v1beta1::MsgFundCommunityPool;
+use cosmos_sdk_proto::traits::{Message, TypeUrl};
+use cosmwasm_std::{
+    Addr, BankMsg, Binary, Coin, CosmosMsg, Decimal, Deps, DepsMut, Env, MessageInfo, Order,
+    Response, StdResult, Storage,
+};
+
+use crate::checks::check_admin;
+use crate::msg::{DenomFeeDistribution, FeeDistributionsResponse, TreasuryResponse};
+use crate::state::{Config, Recipient, TreasuryRecipient, CONFIG, FEE_DISTRIBUTIONS};
+use crate::ContractError;
+
+// execute_set_treasury sets the recipients of the collected fees and whether fees are distributed automatically.
+pub fn execute_set_treasury(
+    deps: DepsMut,
+    info: MessageInfo,
+    recipients: Vec<TreasuryRecipient>,
+    auto_distribute: bool,
+) -> Result<Response, ContractError> {
+    check_admin(deps.as_ref(), &info.sender)?;
+
+    let invalid = |msg: &str| ContractError::InvalidTreasury {
+        msg: msg.to_string(),
+    };
+
+    for treasury_recipient in recipients.iter() {
+        if treasury_recipient.share.is_zero() {
+            return Err(invalid("share must be greater than 0"));
+        }
+        if let Recipient::Address { address } = &treasury_recipient.recipient {
+            deps.api.addr_validate(address)?;
+        }
+    }
+
+    // shares overflowing the sum can not add up to 1 either
+    let total_share = recipients
+        .iter()
+        .try_fold(Decimal::zero(), |total, recipient| {
+            total.checked_add(recipient.share)
+        })
+        .map_err(|_| invalid("shares must add up to 1"))?;
+    if !recipients.is_empty() && total_share != Decimal::one() {
+        return Err(invalid("shares must add up to 1"));
+    }
+
+    if auto_distribute && recipients.is_empty() {
+        return Err(invalid("fees can not be distributed without recipients"));
+    }
+
+    CONFIG.update(deps.storage, |config| -> StdResult<_> {
+        Ok(Config {
+            treasury: recipients,
+            auto_distribute_fees: auto_distribute,
+            ..config
+        })
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_treasury")
+        .add_attribute("auto_distribute", auto_distribute.to_string()))
+}
+
+// execute_distribute_fees distributes the pending collected fees of every denom to the treasury recipients.
+pub fn execute_distribute_fees(deps: DepsMut, env: Env) -> Result<Response, ContractError> {
+    let config = CONFIG.load(deps.storage)?;
+    if config.treasury.is_empty() {
+        return Err(ContractError::NoTreasury {});
+    }
+
+    let denoms = FEE_DISTRIBUTIONS
+        .keys(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut msgs = vec![];
+    for denom in denoms {
+        msgs.extend(distribute_fees(
+            deps.storage,
+            &env.contract.address,
+            &config.treasury,
+            &denom,
+        )?);
+    }
+
+    if msgs.is_empty() {
+        return Err(ContractError::NoPendingFees {});
+    }
+
+    Ok(Response::new()
+        .add_attribute("method", "distribute_fees")
+        .add_messages(msgs))
+}
+
+// collect_fee records the fee as collected for the treasury.
+// If fees are distributed automatically, messages distributing the pending fees of its denom are returned.
+pub fn collect_fee(storage: &mut dyn Storage, env: &Env, fee: &Coin) -> StdResult<Vec<CosmosMsg>> {
+    if fee.amount.is_zero() {
+        return Ok(vec![]);
+    }
+
+    FEE_DISTRIBUTIONS.update(storage, &fee.denom, |distribution| -> StdResult<_> {
+        let mut distribution = distribution.unwrap_or_default();
+        distribution.collected += fee.amount;
+        Ok(distribution)
+    })?;
+
+    let config = CONFIG.load(storage)?;
+    if !config.auto_distribute_fees {
+        return Ok(vec![]);
+    }
+
+    distribute_fees(storage, &env.contract.address, &config.treasury, &fee.denom)
+}
+
+// withdraw_fees records the amount as distributed out of the pending collected fees.
+// Funds that are not collected fees, e.g. escrowed bids and referral rewards, can not be withdrawn.
+pub fn withdraw_fees(storage: &mut dyn Storage, amount: &[Coin]) -> Result<(), ContractError> {
+    for coin in amount {
+        let mut distribution = FEE_DISTRIBUTIONS
+            .may_load(storage, &coin.denom)?
+            .unwrap_or_default();
+        let pending = distribution.collected - distribution.distributed;
+        if coin.amount > pending {
+            return Err(ContractError::InsufficientPendingFees {
+                requested: coin.clone(),
+                pending: Coin {
+                    denom: coin.denom.clone(),
+                    amount: pending,
+                },
+            });
+        }
+
+        distribution.distributed += coin.amount;
+        FEE_DISTRIBUTIONS.save(storage, &coin.denom, &distribution)?;
+    }
+
+    Ok(())
+}
+
+// distribute_fees sends each recipient its share of the pending fees of the denom.
+// Remainder from rounding down is kept pending for the next distribution.
+fn distribute_fees(
+    storage: &mut dyn Storage,
+    contract: &Addr,
+    treasury: &[TreasuryRecipient],
+    denom: &str,
+) -> StdResult<Vec<CosmosMsg>> {
+    let mut distribution = FEE_DISTRIBUTIONS
+        .may_load(storage, denom)?
+        .unwrap_or_default();
+    let pending = distribution.collected - distribution.distributed;
+
+    let mut msgs = vec![];
+    for treasury_recipient in treasury {
+        let amount = pending * treasury_recipient.share;
+        if amount.is_zero() {
+            continue;
+        }
+        distribution.distributed += amount;
+
+        let fee = Coin {
+            denom: denom.to_string(),
+            amount,
+        };
+        msgs.push(match &treasury_recipient.recipient {
+            Recipient::Address { address } => BankMsg::Send {
+                to_address: address.clone(),
+                amount: vec![fee],
+            }
+            .into(),
+            Recipient::CommunityPool {} => fund_community_pool_msg(contract, fee),
+        });
+    }
+
+    FEE_DISTRIBUTIONS.save(storage, denom, &distribution)?;
+
+    Ok(msgs)
+}
+
+// fund_community_pool_msg returns the message funding the community pool with the fee from the depositor.
+// `DistributionMsg::FundCommunityPool` is only available from cosmwasm-std 1.3,
+// so `MsgFundCommunityPool` is sent as a stargate message instead.
+fn fund_community_pool_msg(depositor: &Addr, fee: Coin) -> CosmosMsg {
+    let msg = MsgFundCommunityPool {
+        amount: vec![ProtoCoin {
+            denom: fee.denom,
+            amount: fee.amount.to_string(),
+        }],
+        depositor: depositor.to_string(),
+    };
+
+    CosmosMsg::Stargate {
+        type_url: MsgFundCommunityPool::TYPE_URL.to_string(),
+        value: Binary(msg.encode_to_vec()),
+    }
+}
+
+pub fn query_treasury(deps: Deps) -> StdResult<TreasuryResponse> {
+    let config = CONFIG.load(deps.storage)?;
+    Ok(TreasuryResponse {
+        recipients: config.treasury,
+        auto_distribute: config.auto_distribute_fees,
+    })
+}
+
+pub fn query_fee_distributions(deps: Deps) -> StdResult<FeeDistributionsResponse> {
+    let distributions = FEE_DISTRIBUTIONS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| {
+            let (denom, distribution) = item?;
+            Ok(DenomFeeDistribution {
+                denom,
+                collected: distribution.collected,
+                distributed: distribution.distributed,
+            })
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(FeeDistributionsResponse { distributions })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::state::ClaimRateLimit;
+    use cosmwasm_std::testing::{mock_dependencies, mock_env};
+    use cosmwasm_std::Uint128;
+
+    #[test]
+    fn test_collect_fee_distributes_to_community_pool() {
+        let mut deps = mock_dependencies();
+        let env = mock_env();
+
+        CONFIG
+            .save(
+                &mut deps.storage,
+                &Config {
+                    name_nft: Addr::unchecked("namenftaddr"),
+                    fees: vec![],
+                    registration_years: None,
+                    length_tier_fees: vec![],
+                    referral_reward_share: Decimal::zero(),
+                    commitment_period: None,
+                    treasury: vec![
+                        TreasuryRecipient {
+                            recipient: Recipient::Address {
+                                address: "treasury".to_string(),
+                            },
+                            share: Decimal::percent(70),
+                        },
+                        TreasuryRecipient {
+                            recipient: Recipient::CommunityPool {},
+                            share: Decimal::percent(30),
+                        },
+                    ],
+                    auto_distribute_fees: true,
+                    claim_rate_limit: ClaimRateLimit::default(),
+                    paused: false,
+                },
+            )
+            .unwrap();
+
+        let msgs = collect_fee(&mut deps.storage, &env, &Coin::new(101, "uosmo")).unwrap();
+
+        assert_eq!(
+            msgs,
+            vec![
+                BankMsg::Send {
+                    to_address: "treasury".to_string(),
+                    amount: vec![Coin::new(70, "uosmo")],
+                }
+                .into(),
+                CosmosMsg::Stargate {
+                    type_url: "/cosmos.distribution.v1beta1.MsgFundCommunityPool".to_string(),
+                    value: Binary(
+                        MsgFundCommunityPool {
+                            amount: vec![ProtoCoin {
+                                denom: "uosmo".to_string(),
+                                amount: "30".to_string(),
+                            }],
+                            depositor: env.contract.address.to_string(),
+                        }
+                        .encode_to_vec()
+                    ),
+                },
+            ]
+        );
+
+        // remainder is kept pending
+        assert_eq!(
+            query_fee_distributions(deps.as_ref()).unwrap(),
+            FeeDistributionsResponse {
+                distributions: vec![DenomFeeDistribution {
+                    denom: "uosmo".to_string(),
+                    collected: Uint128::new(101),
+                    distributed: Uint128::new(100),
+                }]
+            }
+        );
+    }
+}
@@ -4,7 +4,7 @@ use cosmwasm_std::Order::Ascending;
 
 use cosmwasm_std::{
     to_binary, Binary, Deps, DepsMut, Env, MessageInfo, QueryRequest, Response, StdError,
-    StdResult, Uint128, WasmQuery,
+    StdResult, Storage, Uint128, WasmQuery,
 };
 use cw2::set_contract_version;
 use cw_storage_plus::KeyDeserialize;
@@ -19,9 +19,11 @@ use crate::msg::{
     Bech32Address, ExecuteMsg, IcnsNamesResponse, InstantiateMsg, MigrateMsg, NamesResponse,
     PrimaryNameResponse, QueryMsg,
 };
-use crate::state::{records, Config, CONFIG, PRIMARY_NAME, SIGNATURE};
+use crate::state::{records, Config, CONFIG, PRIMARY_NAME, RECORD_OWNERS, SIGNATURE};
 use cw721::OwnerOfResponse;
-use icns_name_nft::msg::{AdminResponse, QueryMsg as QueryMsgName};
+use icns_name_nft::msg::{
+    AdminResponse, IsGuardianResponse, NftInfoResponse, QueryMsg as QueryMsgName,
+};
 
 // version info for migration info
 const CONTRACT_NAME: &str = env!("CARGO_PKG_NAME");
@@ -38,7 +40,10 @@ pub fn instantiate(
 
     let name_address = deps.api.addr_validate(&msg.name_address)?;
 
-    let cfg = Config { name_address };
+    let cfg = Config {
+        name_address,
+        paused: false,
+    };
     CONFIG.save(deps.storage, &cfg)?;
 
     Ok(Response::default())
@@ -65,6 +70,10 @@ pub fn execute(
             name,
             bech32_address,
         } => execute_remove_record(deps, info, name, bech32_address),
+        ExecuteMsg::SetPaused { paused } => execute_set_paused(deps, info, paused),
+        ExecuteMsg::NameOwnerChangedHook(hook) => {
+            execute_name_owner_changed(deps, info, hook.name, hook.new_owner)
+        }
     }
 }
 
@@ -76,6 +85,8 @@ pub fn execute_set_record(
     bech32_prefix: String,
     adr36_info: Adr36Info,
 ) -> Result<Response, ContractError> {
+    check_not_paused(deps.as_ref())?;
+
     // check if the msg sender is a registrar or admin. If not, return err
     let is_admin = is_admin(deps.as_ref(), info.sender.to_string())?;
     let is_owner_nft = is_owner(deps.as_ref(), name.clone(), info.sender.to_string())?;
@@ -160,12 +171,23 @@ pub fn execute_set_record(
             // upon skipping adr36 verification because sender and message signer is same,
             // require signature_salt and signature to be empty to differentiate and verify
             // this was intentional.
-            if adr36_info.signature.len() != 0 || adr36_info.signature_salt != Uint128::new(0) {
+            if !adr36_info.signature.is_empty() || adr36_info.signature_salt != Uint128::new(0) {
                 return Err(ContractError::SignatureShouldBeEmpty {});
             }
         }
     }
 
+    // records set by a previous owner of the name are cleared
+    let owner = name_owner(deps.as_ref(), &name)?;
+    if let Some(record_owner) = RECORD_OWNERS.may_load(deps.storage, &name)? {
+        if owner.as_ref() != Some(&record_owner) {
+            clear_records(deps.storage, &name)?;
+        }
+    }
+    if let Some(owner) = owner {
+        RECORD_OWNERS.save(deps.storage, &name, &owner)?;
+    }
+
     // save record
     records().save(
         deps.storage,
@@ -177,7 +199,7 @@ pub fn execute_set_record(
     PRIMARY_NAME.save(deps.storage, adr36_info.signer_bech32_address, &name)?;
 
     // save signature to prevent replay attack only when signatures were not empty
-    if adr36_info.signature.len() != 0 {
+    if !adr36_info.signature.is_empty() {
         SIGNATURE.save(deps.storage, adr36_info.signature.as_slice(), &true)?;
     }
 
@@ -190,6 +212,8 @@ fn execute_set_primary(
     name: String,
     bech32_address: String,
 ) -> Result<Response, ContractError> {
+    check_not_paused(deps.as_ref())?;
+
     // check if the msg sender is a registrar or admin. If not, return err
     let is_admin = is_admin(deps.as_ref(), info.sender.to_string())?;
     let is_owner_nft = is_owner(deps.as_ref(), name.clone(), info.sender.to_string())?;
@@ -205,9 +229,13 @@ fn execute_set_primary(
         .0;
 
     // bech32 address needs to be already set in the records(for adr36 veficiation)
-    // check in state if this is already set
-    let bech32_address_stored =
-        records().may_load(deps.storage, (&name, &bech32_prefix_decoded))?;
+    // check in state if this is already set. records set by a previous owner of the name
+    // are not set for the current owner, who has not verified the addresses in them.
+    let bech32_address_stored = if is_stale(deps.as_ref(), &name)? {
+        None
+    } else {
+        records().may_load(deps.storage, (&name, &bech32_prefix_decoded))?
+    };
 
     if bech32_address_stored.as_ref() != Some(&bech32_address) {
         return Err(ContractError::Bech32AddressNotSet {
@@ -229,6 +257,8 @@ fn execute_remove_record(
     name: String,
     bech32_address: String,
 ) -> Result<Response, ContractError> {
+    check_not_paused(deps.as_ref())?;
+
     // check if the msg sender is the owner of the name or an admin. If not, return err
     if !is_owner(deps.as_ref(), name.clone(), info.sender.to_string())?
         && !is_admin(deps.as_ref(), info.sender.to_string())?
@@ -287,6 +317,115 @@ fn execute_remove_record(
         .add_attribute("name", name))
 }
 
+// execute_set_paused pauses or unpauses the contract.
+// Guardians can only pause, while admins can also unpause.
+fn execute_set_paused(
+    deps: DepsMut,
+    info: MessageInfo,
+    paused: bool,
+) -> Result<Response, ContractError> {
+    // guardians can only pause the contract
+    let can_pause = paused && is_guardian(deps.as_ref(), info.sender.to_string())?;
+    if !can_pause && !is_admin(deps.as_ref(), info.sender.to_string())? {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    CONFIG.update(deps.storage, |cfg| -> StdResult<_> {
+        Ok(Config { paused, ..cfg })
+    })?;
+
+    Ok(Response::new()
+        .add_attribute("method", "set_paused")
+        .add_attribute("paused", paused.to_string()))
+}
+
+// execute_name_owner_changed clears the records of the name set by an owner other than the new owner.
+// Only the name nft contract can call this.
+fn execute_name_owner_changed(
+    deps: DepsMut,
+    info: MessageInfo,
+    name: String,
+    new_owner: String,
+) -> Result<Response, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+    if info.sender != cfg.name_address {
+        return Err(ContractError::Unauthorized {});
+    }
+
+    let cleared = match RECORD_OWNERS.may_load(deps.storage, &name)? {
+        Some(record_owner) if record_owner != new_owner => {
+            clear_records(deps.storage, &name)?;
+            RECORD_OWNERS.remove(deps.storage, &name);
+            true
+        }
+        _ => false,
+    };
+
+    Ok(Response::new()
+        .add_attribute("method", "name_owner_changed")
+        .add_attribute("name", name)
+        .add_attribute("records_cleared", cleared.to_string()))
+}
+
+// check_not_paused returns error if the contract is paused.
+pub fn check_not_paused(deps: Deps) -> Result<(), ContractError> {
+    if CONFIG.load(deps.storage)?.paused {
+        return Err(ContractError::Paused {});
+    }
+
+    Ok(())
+}
+
+pub fn is_guardian(deps: Deps, address: String) -> Result<bool, ContractError> {
+    let cfg = CONFIG.load(deps.storage)?;
+
+    // query guardian from icns-name-nft contract
+    let query_msg = QueryMsgName::IsGuardian { address };
+    let res: IsGuardianResponse = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+        contract_addr: cfg.name_address.to_string(),
+        msg: to_binary(&query_msg)?,
+    }))?;
+
+    Ok(res.is_guardian)
+}
+
+// clear_records removes the records of the name along with the primary names set to the name.
+// Addresses still recorded for other names fall back to one of them as their primary name.
+fn clear_records(storage: &mut dyn Storage, name: &str) -> StdResult<()> {
+    let name_records = records()
+        .prefix(name)
+        .range(storage, None, None, Ascending)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    for (bech32_prefix, address) in name_records {
+        records().remove(storage, (name, &bech32_prefix))?;
+
+        if PRIMARY_NAME.may_load(storage, address.clone())?.as_deref() != Some(name) {
+            continue;
+        }
+
+        let other_name = records()
+            .idx
+            .address
+            .prefix(address.clone())
+            .keys(storage, None, None, Ascending)
+            .next()
+            .transpose()?
+            .map(|key| -> StdResult<_> {
+                let (name, _) = <(String, String)>::from_slice(key.as_bytes())?;
+                Ok(name)
+            })
+            .transpose()?;
+
+        match other_name {
+            Some(other_name) => PRIMARY_NAME.save(storage, address, &other_name)?,
+            None => PRIMARY_NAME.remove(storage, address),
+        }
+    }
+
+    Ok(())
+}
+
 pub fn is_admin(deps: Deps, address: String) -> Result<bool, ContractError> {
     let cfg = CONFIG.load(deps.storage)?;
     let name_address = cfg.name_address;
@@ -330,6 +469,54 @@ pub fn is_owner(deps: Deps, username: String, sender: String) -> Result<bool, Co
     }
 }
 
+// name_owner returns the owner of the name nft.
+// returns None if the name does not exist.
+pub fn name_owner(deps: Deps, name: &str) -> StdResult<Option<String>> {
+    let response: StdResult<OwnerOfResponse> =
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: CONFIG.load(deps.storage)?.name_address.to_string(),
+            msg: to_binary(&QueryMsgName::OwnerOf {
+                token_id: name.to_string(),
+                include_expired: None,
+            })?,
+        }));
+
+    Ok(response.ok().map(|OwnerOfResponse { owner, .. }| owner))
+}
+
+// is_stale checks if the records of the name were set by a previous owner of the name.
+// records set before their owners were recorded are not considered stale.
+pub fn is_stale(deps: Deps, name: &str) -> StdResult<bool> {
+    match RECORD_OWNERS.may_load(deps.storage, name)? {
+        Some(record_owner) => Ok(name_owner(deps, name)?.as_ref() != Some(&record_owner)),
+        None => Ok(false),
+    }
+}
+
+// is_resolvable checks if the records of the name can be resolved,
+// which requires the name not to be expired and its records not to be stale.
+fn is_resolvable(deps: Deps, env: &Env, name: &str) -> StdResult<bool> {
+    Ok(!is_expired(deps, env, name.to_string())? && !is_stale(deps, name)?)
+}
+
+// is_expired checks if the registration of the name has expired.
+// returns false if the name does not exist or never expires.
+pub fn is_expired(deps: Deps, env: &Env, name: String) -> StdResult<bool> {
+    let response: StdResult<NftInfoResponse> =
+        deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+            contract_addr: CONFIG.load(deps.storage)?.name_address.to_string(),
+            msg: to_binary(&QueryMsgName::NftInfo { token_id: name })?,
+        }));
+
+    match response {
+        Ok(NftInfoResponse { extension, .. }) => Ok(extension
+            .expiry
+            .map(|expiry| expiry <= env.block.time)
+            .unwrap_or(false)),
+        Err(_) => Ok(false),
+    }
+}
+
 #[cfg_attr(not(feature = "library"), entry_point)]
 pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
     match msg {
@@ -340,41 +527,27 @@ pub fn query(deps: Deps, env: Env, msg: QueryMsg) -> StdResult<Binary> {
             bech32_prefix,
         } => to_binary(&query_address(deps, env, name, bech32_prefix)?),
         QueryMsg::Admin {} => to_binary(&query_admin(deps)?),
-        QueryMsg::PrimaryName { address } => to_binary(&query_primary_name(deps, address)?),
-        QueryMsg::Names { address } => to_binary(&query_names(deps, address)?),
-        QueryMsg::IcnsNames { address } => to_binary(&query_icns_names(deps, address)?),
-        QueryMsg::AddressByIcns { icns } => to_binary(&query_address_by_icns(deps, icns)?),
+        QueryMsg::PrimaryName { address } => to_binary(&query_primary_name(deps, env, address)?),
+        QueryMsg::Names { address } => to_binary(&query_names(deps, env, address)?),
+        QueryMsg::IcnsNames { address } => to_binary(&query_icns_names(deps, env, address)?),
+        QueryMsg::AddressByIcns { icns } => to_binary(&query_address_by_icns(deps, env, icns)?),
     }
 }
 
-fn query_names(deps: Deps, address: String) -> StdResult<NamesResponse> {
-    let mut primary_name = PRIMARY_NAME.may_load(deps.storage, address.clone())?;
-    if primary_name.is_none() {
-        primary_name = Some("".to_string());
-    }
+fn query_names(deps: Deps, env: Env, address: String) -> StdResult<NamesResponse> {
+    // expired name or name with stale records is treated as unresolved
+    let primary_name = match PRIMARY_NAME.may_load(deps.storage, address.clone())? {
+        Some(name) if is_resolvable(deps, &env, &name)? => name,
+        _ => "".to_string(),
+    };
 
     Ok(NamesResponse {
-        names: records()
-            .idx
-            .address
-            .prefix(address)
-            .keys(deps.storage, None, None, Ascending)
-            // get name out of StdResult<(name, bech32_prefix)
-            .map(|result| {
-                result
-                    .iter()
-                    .map(|key| {
-                        let (name, _) = <(String, String)>::from_slice(key.as_bytes())?;
-                        Ok(name)
-                    })
-                    .collect::<StdResult<String>>()
-            })
-            .collect::<StdResult<_>>()?,
-        primary_name: primary_name.unwrap(),
+        names: resolvable_names(deps, &env, address)?,
+        primary_name,
     })
 }
 
-fn query_icns_names(deps: Deps, address: String) -> StdResult<IcnsNamesResponse> {
+fn query_icns_names(deps: Deps, env: Env, address: String) -> StdResult<IcnsNamesResponse> {
     let primary_name = PRIMARY_NAME.may_load(deps.storage, address.clone())?;
 
     let bech32_prefix = bech32::decode(address.clone())
@@ -383,49 +556,76 @@ fn query_icns_names(deps: Deps, address: String) -> StdResult<IcnsNamesResponse>
         })?
         .0;
 
-    let primary_name_with_prefix = if primary_name.is_none() {
-        "".to_string()
-    } else {
-        format!("{}.{}", primary_name.unwrap(), bech32_prefix)
+    // expired name or name with stale records is treated as unresolved
+    let primary_name_with_prefix = match primary_name {
+        Some(primary_name) if is_resolvable(deps, &env, &primary_name)? => {
+            format!("{}.{}", primary_name, bech32_prefix)
+        }
+        _ => "".to_string(),
     };
 
     Ok(IcnsNamesResponse {
-        names: records()
-            .idx
-            .address
-            .prefix(address)
-            .keys(deps.storage, None, None, Ascending)
-            // get name out of StdResult<(name, bech32_prefix)
-            .map(|result| {
-                result
-                    .iter()
-                    .map(|key| {
-                        let (name, _) = <(String, String)>::from_slice(key.as_bytes())?;
-                        Ok(name)
-                    })
-                    .collect::<StdResult<String>>()
-                    // and then append bech32 prefix
-                    .map(|name| format!("{}.{}", name, bech32_prefix))
-            })
-            .collect::<StdResult<_>>()?,
+        names: resolvable_names(deps, &env, address)?
+            .into_iter()
+            // append bech32 prefix
+            .map(|name| format!("{}.{}", name, bech32_prefix))
+            .collect(),
         primary_name: primary_name_with_prefix,
     })
 }
 
-fn query_primary_name(deps: Deps, address: String) -> StdResult<PrimaryNameResponse> {
+// resolvable_names returns the names with records of the address,
+// excluding expired names and names with stale records.
+fn resolvable_names(deps: Deps, env: &Env, address: String) -> StdResult<Vec<String>> {
+    let names = records()
+        .idx
+        .address
+        .prefix(address)
+        .keys(deps.storage, None, None, Ascending)
+        // get name out of StdResult<(name, bech32_prefix)
+        .map(|result| {
+            result
+                .iter()
+                .map(|key| {
+                    let (name, _) = <(String, String)>::from_slice(key.as_bytes())?;
+                    Ok(name)
+                })
+                .collect::<StdResult<String>>()
+        })
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut resolvable = vec![];
+    for name in names {
+        if is_resolvable(deps, env, &name)? {
+            resolvable.push(name);
+        }
+    }
+
+    Ok(resolvable)
+}
+
+fn query_primary_name(deps: Deps, env: Env, address: String) -> StdResult<PrimaryNameResponse> {
     let primary_name = PRIMARY_NAME.may_load(deps.storage, address)?;
     match primary_name {
-        Some(name) => Ok(PrimaryNameResponse { name }),
-        None => Ok(PrimaryNameResponse {
+        // expired name or name with stale records is treated as unresolved
+        Some(name) if is_resolvable(deps, &env, &name)? => Ok(PrimaryNameResponse { name }),
+        _ => Ok(PrimaryNameResponse {
             name: "".to_string(),
         }),
     }
 }
 
-fn query_addresses(deps: Deps, _env: Env, name: String) -> StdResult<AddressesResponse> {
+fn query_addresses(deps: Deps, env: Env, name: String) -> StdResult<AddressesResponse> {
     // create array of Bech32Address
     let mut bech32_addresses = vec![];
 
+    // expired name or name with stale records is treated as unresolved
+    if !is_resolvable(deps, &env, &name)? {
+        return Ok(AddressesResponse {
+            addresses: bech32_addresses,
+        });
+    }
+
     let records = records()
         .prefix(&name)
         .range(deps.storage, None, None, Ascending)
@@ -448,14 +648,15 @@ fn query_addresses(deps: Deps, _env: Env, name: String) -> StdResult<AddressesRe
 
 fn query_address(
     deps: Deps,
-    _env: Env,
+    env: Env,
     name: String,
     bech32_prefix: String,
 ) -> StdResult<AddressResponse> {
     let address = records().may_load(deps.storage, (&name, &bech32_prefix))?;
     match address {
-        Some(address) => Ok(AddressResponse { address }),
-        None => Ok(AddressResponse {
+        // expired name or name with stale records is treated as unresolved
+        Some(address) if is_resolvable(deps, &env, &name)? => Ok(AddressResponse { address }),
+        _ => Ok(AddressResponse {
             address: "".to_string(),
         }),
     }
@@ -472,7 +673,7 @@ fn query_admin(deps: Deps) -> StdResult<AdminResponse> {
     }
 }
 
-fn query_address_by_icns(deps: Deps, icns: String) -> StdResult<AddressByIcnsResponse> {
+fn query_address_by_icns(deps: Deps, env: Env, icns: String) -> StdResult<AddressByIcnsResponse> {
     let split: Vec<&str> = icns.split('.').collect();
 
     // check if split length is 2
@@ -485,15 +686,34 @@ fn query_address_by_icns(deps: Deps, icns: String) -> StdResult<AddressByIcnsRes
 
     let bech32_address = records().may_load(deps.storage, (name, bech32_prefix))?;
     match bech32_address {
-        Some(bech32_address) => Ok(AddressByIcnsResponse { bech32_address }),
-        None => Ok(AddressByIcnsResponse {
+        // expired name or name with stale records is treated as unresolved
+        Some(bech32_address) if is_resolvable(deps, &env, name)? => {
+            Ok(AddressByIcnsResponse { bech32_address })
+        }
+        _ => Ok(AddressByIcnsResponse {
             bech32_address: "".to_string(),
         }),
     }
 }
 
 #[cfg_attr(not(feature = "library"), entry_point)]
-pub fn migrate(_deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
-    // No state migrations performed, just returned a Response
+pub fn migrate(deps: DepsMut, _env: Env, _msg: MigrateMsg) -> Result<Response, ContractError> {
+    // record the current owners of the names with records set before the owners are recorded,
+    // so that their records become stale once the names change owner.
+    let mut names = records()
+        .keys(deps.storage, None, None, Ascending)
+        .map(|key| key.map(|(name, _)| name))
+        .collect::<StdResult<Vec<_>>>()?;
+    names.dedup();
+
+    for name in names {
+        if RECORD_OWNERS.has(deps.storage, &name) {
+            continue;
+        }
+        if let Some(owner) = name_owner(deps.as_ref(), &name)? {
+            RECORD_OWNERS.save(deps.storage, &name, &owner)?;
+        }
+    }
+
     Ok(Response::default())
 }
@@ -44,4 +44,7 @@ pub enum ContractError {
 
     #[error("Invalid ICNS")]
     InvalidICNS {},
+
+    #[error("Contract is paused")]
+    Paused {},
 }
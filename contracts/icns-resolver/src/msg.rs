@@ -1,5 +1,6 @@
 use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::{Binary, Uint128};
+use icns_name_nft::msg::NameOwnerChangedHook;
 
 use crate::state::Config;
 #[cw_serde]
@@ -22,6 +23,12 @@ pub enum ExecuteMsg {
         name: String,
         bech32_address: String,
     },
+    /// Pause or unpause setting and removing records and primary names.
+    /// Admins can pause and unpause, while guardians of the name nft contract can only pause.
+    SetPaused { paused: bool },
+    /// Hook called by the name nft contract when a name changes its owner.
+    /// Records set by the previous owner are cleared right away.
+    NameOwnerChangedHook(NameOwnerChangedHook),
 }
 
 #[cw_serde]
@@ -43,7 +50,7 @@ pub enum AddressHash {
 #[derive(QueryResponses)]
 pub enum QueryMsg {
     /// Get Config of the Resolver contract.
-    /// This includes the Name-nft contract address.
+    /// This includes the Name-nft contract address and whether the contract is paused.
     #[returns(Config)]
     Config {},
 
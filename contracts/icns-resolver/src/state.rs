@@ -6,6 +6,9 @@ pub static CONFIG_KEY: &[u8] = b"config";
 #[cw_serde]
 pub struct Config {
     pub name_address: Addr,
+    // paused flag. If true, records and primary names can not be changed.
+    #[serde(default)]
+    pub paused: bool,
 }
 
 pub const CONFIG: Item<Config> = Item::new("config");
@@ -33,6 +36,10 @@ pub fn records<'a>() -> IndexedMap<'a, (&'a str, &'a str), String, RecordIndexes
     IndexedMap::new("records", indexes)
 }
 
+// map of user name -> owner of the name nft at the time its records were set.
+// records are considered stale once the name nft changes owner.
+pub const RECORD_OWNERS: Map<&str, String> = Map::new("record_owners");
+
 // map of bech32 address -> user name
 pub const PRIMARY_NAME: Map<String, String> = Map::new("primary_name");
 
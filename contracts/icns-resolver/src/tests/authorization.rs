@@ -92,7 +92,10 @@ fn only_owner_can_set_record() {
             token_id: "tony".to_string(),
             owner: "tony".to_string(),
             token_uri: None,
-            extension: Metadata { referral: None },
+            extension: Metadata {
+                referral: None,
+                ..Default::default()
+            },
         }),
         &[],
     )
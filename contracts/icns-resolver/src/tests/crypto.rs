@@ -143,7 +143,6 @@ fn create_valid_adr36_message() {
     let message_prefix = "{\"account_number\":\"0\",\"chain_id\":\"\",\"fee\":{\"amount\":[],\"gas\":\"0\"},\"memo\":\"\",\"msgs\":[{\"type\":\"sign/MsgSignData\",\"value\":{\"data\":\"";
     let data = "VGhlIGZvbGxvd2luZyBpcyB0aGUgaW5mb3JtYXRpb24gZm9yIElDTlMgcmVnaXN0cmF0aW9uIGZvciBhbGljZS5jb3Ntb3MuCgpDaGFpbiBpZDogY29zbW9zLXRlc3RuZXQtMTQwMDIKQ29udHJhY3QgQWRkcmVzczogY29udHJhY3QxCk93bmVyOiBvc21vMWQya2gyeGFlbjdjMHp2M2g3cW5tZ2hod2hzbW1hc3NxaHFzNjk3ClNhbHQ6IDEyMzEz".to_string();
     let signer_prefix = "\",\"signer\":\"";
-    let signer = signer;
     let message_suffix = "\"}}],\"sequence\":\"0\"}";
 
     let expected_message = format!(
@@ -86,7 +86,10 @@ pub fn default_setting(admins: Vec<String>, registrar: String) -> (Addr, Addr, A
             token_id: "alice".to_string(),
             owner: "alice".to_string(),
             token_uri: None,
-            extension: Metadata { referral: None },
+            extension: Metadata {
+                referral: None,
+                ..Default::default()
+            },
         }),
         &[],
     )
@@ -214,7 +217,10 @@ pub fn mint_and_set_record(
             token_id: name.to_string(),
             owner: addr.to_string(),
             token_uri: None,
-            extension: Metadata { referral: None },
+            extension: Metadata {
+                referral: None,
+                ..Default::default()
+            },
         }),
         &[],
     )
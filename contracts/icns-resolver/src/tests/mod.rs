@@ -11,3 +11,7 @@ mod set_record;
 mod primary_name;
 
 mod reverse_resolver;
+
+mod pause;
+
+mod transfer;
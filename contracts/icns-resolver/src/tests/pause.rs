@@ -0,0 +1,136 @@
+#![cfg(test)]
+
+use crate::{
+    crypto::cosmos_pubkey_to_bech32_address,
+    msg::{Bech32Address, ExecuteMsg, QueryMsg},
+    state::Config,
+    tests::helpers::{
+        addresses, instantiate_name_nft, instantiate_resolver_with_name_nft, mint_and_set_record,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::Addr;
+use cw_multi_test::{BasicApp, Executor};
+use icns_name_nft::msg::ICNSNameExecuteMsg;
+
+use super::helpers::{signer1, ToBinary};
+
+#[test]
+fn records_can_not_be_changed_while_paused() {
+    let admin1 = String::from("admin1");
+    let admin2 = String::from("admin2");
+    let admins = vec![admin1.clone(), admin2];
+    let registrar = String::from("default-registrar");
+    let guardian = Addr::unchecked("guardian");
+
+    let (name_nft_contract, mut app) = instantiate_name_nft(admins, registrar.clone());
+    let resolver_contract_addr =
+        instantiate_resolver_with_name_nft(&mut app, name_nft_contract.clone());
+
+    // guardians are kept by the name nft contract
+    app.execute_contract(
+        Addr::unchecked(admin1.clone()),
+        name_nft_contract.clone(),
+        &icns_name_nft::ExecuteMsg::Extension {
+            msg: ICNSNameExecuteMsg::AddGuardian {
+                guardian_address: guardian.to_string(),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    let addr1 = cosmos_pubkey_to_bech32_address(signer1().to_binary(), "osmo".to_string());
+    let signer_bech32_address = "cosmos1cyyzpxplxdzkeea7kwsydadg87357qnalx9dqz".to_string();
+    mint_and_set_record(
+        &mut app,
+        "isabel",
+        signer_bech32_address.clone(),
+        &signer1(),
+        registrar,
+        name_nft_contract,
+        resolver_contract_addr.clone(),
+    );
+
+    let set_paused = |app: &mut BasicApp, sender: &str, paused: bool| {
+        app.execute_contract(
+            Addr::unchecked(sender),
+            resolver_contract_addr.clone(),
+            &ExecuteMsg::SetPaused { paused },
+            &[],
+        )
+    };
+
+    let remove_record = |app: &mut BasicApp| {
+        app.execute_contract(
+            Addr::unchecked(addr1.clone()),
+            resolver_contract_addr.clone(),
+            &ExecuteMsg::RemoveRecord {
+                name: "isabel".to_string(),
+                bech32_address: signer_bech32_address.clone(),
+            },
+            &[],
+        )
+    };
+
+    // non guardian can not pause
+    let err = set_paused(&mut app, &addr1, true).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Unauthorized {}
+    );
+
+    // guardian can pause
+    set_paused(&mut app, guardian.as_str(), true).unwrap();
+    let Config { paused, .. } = app
+        .wrap()
+        .query_wasm_smart(resolver_contract_addr.clone(), &QueryMsg::Config {})
+        .unwrap();
+    assert!(paused);
+
+    let err = remove_record(&mut app).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Paused {}
+    );
+    let err = app
+        .execute_contract(
+            Addr::unchecked(addr1.clone()),
+            resolver_contract_addr.clone(),
+            &ExecuteMsg::SetPrimary {
+                name: "isabel".to_string(),
+                bech32_address: signer_bech32_address.clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Paused {}
+    );
+
+    // queries keep working
+    assert_eq!(
+        addresses(&app, "isabel".to_string(), resolver_contract_addr.clone()).unwrap(),
+        vec![Bech32Address {
+            bech32_prefix: "cosmos".to_string(),
+            address: signer_bech32_address.clone(),
+        }]
+    );
+
+    // guardian can not unpause
+    let err = set_paused(&mut app, guardian.as_str(), false).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Unauthorized {}
+    );
+
+    // admin can unpause
+    set_paused(&mut app, &admin1, false).unwrap();
+    remove_record(&mut app).unwrap();
+    assert_eq!(
+        addresses(&app, "isabel".to_string(), resolver_contract_addr).unwrap(),
+        Vec::<Bech32Address>::new()
+    );
+}
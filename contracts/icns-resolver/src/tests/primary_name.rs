@@ -151,7 +151,10 @@ fn set_primary() {
             token_id: "others_name".to_string(),
             owner: "someone_else".to_string(),
             token_uri: None,
-            extension: Metadata { referral: None },
+            extension: Metadata {
+                referral: None,
+                ..Default::default()
+            },
         }),
         &[],
     )
@@ -7,8 +7,11 @@ use crate::{
         AddressByIcnsResponse, AddressResponse, AddressesResponse, Bech32Address,
         IcnsNamesResponse, NamesResponse, QueryMsg,
     },
-    tests::helpers::{mint_and_set_record, signer2, ToBinary},
+    tests::helpers::{addresses, mint_and_set_record, primary_name, signer2, ToBinary},
 };
+use cosmwasm_std::Addr;
+use cw_multi_test::{App, Executor};
+use icns_name_nft::msg::{ExecuteMsg as NameExecuteMsg, ICNSNameExecuteMsg};
 
 use super::helpers::{
     default_setting, instantiate_name_nft, instantiate_resolver_with_name_nft, signer1,
@@ -399,3 +402,85 @@ fn query_address_by_icns() {
         .unwrap();
     assert_eq!(bech32_address, addr2);
 }
+
+#[test]
+fn expired_name_is_unresolved() {
+    let admins = vec![String::from("admin1"), String::from("admin2")];
+    let registrar = String::from("default-registrar");
+
+    let (name_nft_contract, resolver_contract_addr, mut app) =
+        default_setting(admins, registrar.clone());
+
+    let addr1 = cosmos_pubkey_to_bech32_address(signer1().to_binary(), "cosmos".to_string());
+
+    // set alice to expire in 10 seconds
+    let expiry = app.block_info().time.plus_seconds(10);
+    app.execute_contract(
+        Addr::unchecked(registrar),
+        name_nft_contract,
+        &NameExecuteMsg::Extension {
+            msg: ICNSNameExecuteMsg::SetExpiry {
+                token_id: "alice".to_string(),
+                expiry: Some(expiry),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+
+    let address_by_icns = |app: &App| -> String {
+        let AddressByIcnsResponse { bech32_address } = app
+            .wrap()
+            .query_wasm_smart(
+                resolver_contract_addr.clone(),
+                &QueryMsg::AddressByIcns {
+                    icns: "alice.cosmos".to_string(),
+                },
+            )
+            .unwrap();
+        bech32_address
+    };
+
+    let address = |app: &App| -> String {
+        let AddressResponse { address } = app
+            .wrap()
+            .query_wasm_smart(
+                resolver_contract_addr.clone(),
+                &QueryMsg::Address {
+                    name: "alice".to_string(),
+                    bech32_prefix: "cosmos".to_string(),
+                },
+            )
+            .unwrap();
+        address
+    };
+
+    // name is resolved before expiry
+    assert_eq!(address_by_icns(&app), addr1);
+    assert_eq!(address(&app), addr1);
+    assert!(
+        addresses(&app, "alice".to_string(), resolver_contract_addr.clone())
+            .unwrap()
+            .contains(&Bech32Address {
+                bech32_prefix: "cosmos".to_string(),
+                address: addr1.clone(),
+            })
+    );
+    assert_eq!(
+        primary_name(&app, addr1.clone(), resolver_contract_addr.clone()).unwrap(),
+        "alice"
+    );
+
+    // name is unresolved once expired
+    app.update_block(|block| block.time = expiry);
+    assert_eq!(address_by_icns(&app), "");
+    assert_eq!(address(&app), "");
+    assert_eq!(
+        addresses(&app, "alice".to_string(), resolver_contract_addr.clone()).unwrap(),
+        vec![]
+    );
+    assert_eq!(
+        primary_name(&app, addr1, resolver_contract_addr.clone()).unwrap(),
+        ""
+    );
+}
@@ -41,7 +41,10 @@ fn reverse_resolver() {
                 token_id: "bob".to_string(),
                 owner: "bob".to_string(),
                 token_uri: None,
-                extension: Metadata { referral: None },
+                extension: Metadata {
+                    referral: None,
+                    ..Default::default()
+                },
             }),
             &[],
         )
@@ -166,7 +166,10 @@ fn bech32_verification() {
             token_id: "alice".to_string(),
             owner: addr1.to_string(),
             token_uri: None,
-            extension: Metadata { referral: None },
+            extension: Metadata {
+                referral: None,
+                ..Default::default()
+            },
         }),
         &[],
     )
@@ -273,7 +276,10 @@ fn eth_address_set_record() {
             token_id: "alice".to_string(),
             owner: addr.to_string(),
             token_uri: None,
-            extension: Metadata { referral: None },
+            extension: Metadata {
+                referral: None,
+                ..Default::default()
+            },
         }),
         &[],
     )
@@ -330,7 +336,10 @@ fn adr36_verification_bypass() {
             token_id: "alice".to_string(),
             owner: addr.to_string(),
             token_uri: None,
-            extension: Metadata { referral: None },
+            extension: Metadata {
+                referral: None,
+                ..Default::default()
+            },
         }),
         &[],
     )
@@ -403,7 +412,10 @@ fn same_pubkey_invalid_bech_32() {
             token_id: "bob".to_string(),
             owner: addr.to_string(),
             token_uri: None,
-            extension: Metadata { referral: None },
+            extension: Metadata {
+                referral: None,
+                ..Default::default()
+            },
         }),
         &[],
     )
@@ -497,7 +509,10 @@ fn set_record_with_different_signer_and_signature_owner_wrong_signature() {
             token_id: "carol".to_string(),
             owner: "osmo1d2kh2xaen7c0zv3h7qnmghhwhsmmassqhqs697".to_string(),
             token_uri: None,
-            extension: Metadata { referral: None },
+            extension: Metadata {
+                referral: None,
+                ..Default::default()
+            },
         }),
         &[],
     )
@@ -555,7 +570,10 @@ fn set_record_with_different_signer_and_signature_owner() {
             token_id: "carol".to_string(),
             owner: "osmo1d2kh2xaen7c0zv3h7qnmghhwhsmmassqhqs697".to_string(),
             token_uri: None,
-            extension: Metadata { referral: None },
+            extension: Metadata {
+                referral: None,
+                ..Default::default()
+            },
         }),
         &[],
     )
@@ -0,0 +1,362 @@
+#![cfg(test)]
+
+use crate::{
+    crypto::cosmos_pubkey_to_bech32_address,
+    msg::{
+        AddressByIcnsResponse, AddressHash, Adr36Info, Bech32Address, ExecuteMsg,
+        IcnsNamesResponse, NamesResponse, QueryMsg,
+    },
+    tests::helpers::{
+        addresses, instantiate_name_nft, instantiate_resolver_with_name_nft, mint_and_set_record,
+        primary_name, signer1, signer2, ToBinary,
+    },
+    ContractError,
+};
+
+use cosmwasm_std::{Addr, Binary, Uint128};
+use cw_multi_test::{BasicApp, Executor};
+use icns_name_nft::msg::{ExecuteMsg as NameExecuteMsg, ICNSNameExecuteMsg, NameOwnerChangedHook};
+
+#[test]
+fn records_of_previous_owner_are_not_resolved() {
+    let admin1 = String::from("admin1");
+    let admin2 = String::from("admin2");
+    let admins = vec![admin1.clone(), admin2];
+    let registrar = String::from("default-registrar");
+
+    let (name_nft_contract, mut app) = instantiate_name_nft(admins, registrar.clone());
+    let resolver_contract_addr =
+        instantiate_resolver_with_name_nft(&mut app, name_nft_contract.clone());
+
+    let addr1 = cosmos_pubkey_to_bech32_address(signer1().to_binary(), "osmo".to_string());
+    let addr2 = cosmos_pubkey_to_bech32_address(signer2().to_binary(), "osmo".to_string());
+    let signer_bech32_address = "cosmos1cyyzpxplxdzkeea7kwsydadg87357qnalx9dqz".to_string();
+
+    for name in ["isabel", "isabel2"] {
+        mint_and_set_record(
+            &mut app,
+            name,
+            signer_bech32_address.clone(),
+            &signer1(),
+            registrar.clone(),
+            name_nft_contract.clone(),
+            resolver_contract_addr.clone(),
+        );
+    }
+    app.execute_contract(
+        Addr::unchecked(addr1.clone()),
+        resolver_contract_addr.clone(),
+        &ExecuteMsg::SetPrimary {
+            name: "isabel".to_string(),
+            bech32_address: signer_bech32_address.clone(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    let address_by_icns = |app: &BasicApp, icns: &str| {
+        let AddressByIcnsResponse { bech32_address } = app
+            .wrap()
+            .query_wasm_smart(
+                resolver_contract_addr.clone(),
+                &QueryMsg::AddressByIcns {
+                    icns: icns.to_string(),
+                },
+            )
+            .unwrap();
+        bech32_address
+    };
+    let names = |app: &BasicApp, address: &str| {
+        let res: NamesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                resolver_contract_addr.clone(),
+                &QueryMsg::Names {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        res
+    };
+    let icns_names = |app: &BasicApp, address: &str| {
+        let res: IcnsNamesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                resolver_contract_addr.clone(),
+                &QueryMsg::IcnsNames {
+                    address: address.to_string(),
+                },
+            )
+            .unwrap();
+        res
+    };
+
+    // transfer isabel to addr2
+    app.execute_contract(
+        Addr::unchecked(admin1),
+        name_nft_contract.clone(),
+        &NameExecuteMsg::Extension {
+            msg: ICNSNameExecuteMsg::SetTransferrable {
+                transferrable: true,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(addr1),
+        name_nft_contract,
+        &NameExecuteMsg::TransferNft {
+            recipient: addr2.clone(),
+            token_id: "isabel".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // records set by the previous owner are no longer resolved
+    assert_eq!(
+        addresses(&app, "isabel".to_string(), resolver_contract_addr.clone()).unwrap(),
+        vec![]
+    );
+    assert_eq!(address_by_icns(&app, "isabel.cosmos"), "");
+    assert_eq!(
+        primary_name(
+            &app,
+            signer_bech32_address.clone(),
+            resolver_contract_addr.clone()
+        )
+        .unwrap(),
+        ""
+    );
+    assert_eq!(
+        names(&app, &signer_bech32_address),
+        NamesResponse {
+            names: vec!["isabel2".to_string()],
+            primary_name: "".to_string(),
+        }
+    );
+    assert_eq!(
+        icns_names(&app, &signer_bech32_address),
+        IcnsNamesResponse {
+            names: vec!["isabel2.cosmos".to_string()],
+            primary_name: "".to_string(),
+        }
+    );
+
+    // records of the names not transferred are still resolved
+    assert_eq!(
+        address_by_icns(&app, "isabel2.cosmos"),
+        signer_bech32_address
+    );
+
+    // setting a record by the new owner clears the records of the previous owner
+    app.execute_contract(
+        Addr::unchecked(addr2.clone()),
+        resolver_contract_addr.clone(),
+        &ExecuteMsg::SetRecord {
+            name: "isabel".to_string(),
+            bech32_prefix: "osmo".to_string(),
+            adr36_info: Adr36Info {
+                signer_bech32_address: addr2.clone(),
+                address_hash: AddressHash::Cosmos,
+                pub_key: signer2().to_binary(),
+                signature: Binary::default(),
+                signature_salt: Uint128::zero(),
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    assert_eq!(
+        addresses(&app, "isabel".to_string(), resolver_contract_addr.clone()).unwrap(),
+        vec![Bech32Address {
+            bech32_prefix: "osmo".to_string(),
+            address: addr2.clone(),
+        }]
+    );
+    assert_eq!(address_by_icns(&app, "isabel.cosmos"), "");
+    assert_eq!(
+        primary_name(&app, addr2, resolver_contract_addr.clone()).unwrap(),
+        "isabel"
+    );
+
+    // primary name of the previous owner falls back to another name of the address
+    assert_eq!(
+        primary_name(&app, signer_bech32_address, resolver_contract_addr).unwrap(),
+        "isabel2"
+    );
+}
+
+#[test]
+fn primary_name_can_not_be_set_from_records_of_previous_owner() {
+    let admin1 = String::from("admin1");
+    let admin2 = String::from("admin2");
+    let admins = vec![admin1.clone(), admin2];
+    let registrar = String::from("default-registrar");
+
+    let (name_nft_contract, mut app) = instantiate_name_nft(admins, registrar.clone());
+    let resolver_contract_addr =
+        instantiate_resolver_with_name_nft(&mut app, name_nft_contract.clone());
+
+    let addr1 = cosmos_pubkey_to_bech32_address(signer1().to_binary(), "osmo".to_string());
+    let addr2 = cosmos_pubkey_to_bech32_address(signer2().to_binary(), "osmo".to_string());
+    let signer_bech32_address = "cosmos1cyyzpxplxdzkeea7kwsydadg87357qnalx9dqz".to_string();
+
+    // primary name of the address is set to the last name with its record
+    for name in ["isabel", "isabel2"] {
+        mint_and_set_record(
+            &mut app,
+            name,
+            signer_bech32_address.clone(),
+            &signer1(),
+            registrar.clone(),
+            name_nft_contract.clone(),
+            resolver_contract_addr.clone(),
+        );
+    }
+
+    // transfer isabel to addr2
+    app.execute_contract(
+        Addr::unchecked(admin1),
+        name_nft_contract.clone(),
+        &NameExecuteMsg::Extension {
+            msg: ICNSNameExecuteMsg::SetTransferrable {
+                transferrable: true,
+            },
+        },
+        &[],
+    )
+    .unwrap();
+    app.execute_contract(
+        Addr::unchecked(addr1),
+        name_nft_contract,
+        &NameExecuteMsg::TransferNft {
+            recipient: addr2.clone(),
+            token_id: "isabel".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // new owner can not take over the primary name of an address in the previous owner's records
+    let err = app
+        .execute_contract(
+            Addr::unchecked(addr2),
+            resolver_contract_addr.clone(),
+            &ExecuteMsg::SetPrimary {
+                name: "isabel".to_string(),
+                bech32_address: signer_bech32_address.clone(),
+            },
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Bech32AddressNotSet {
+            name: "isabel".to_string(),
+            address: signer_bech32_address.clone(),
+        }
+    );
+    assert_eq!(
+        primary_name(&app, signer_bech32_address, resolver_contract_addr).unwrap(),
+        "isabel2"
+    );
+}
+
+#[test]
+fn records_are_cleared_by_name_owner_changed_hook() {
+    let admin1 = String::from("admin1");
+    let admin2 = String::from("admin2");
+    let admins = vec![admin1.clone(), admin2];
+    let registrar = String::from("default-registrar");
+
+    let (name_nft_contract, mut app) = instantiate_name_nft(admins, registrar.clone());
+    let resolver_contract_addr =
+        instantiate_resolver_with_name_nft(&mut app, name_nft_contract.clone());
+
+    let addr1 = cosmos_pubkey_to_bech32_address(signer1().to_binary(), "osmo".to_string());
+    let addr2 = cosmos_pubkey_to_bech32_address(signer2().to_binary(), "osmo".to_string());
+    let signer_bech32_address = "cosmos1cyyzpxplxdzkeea7kwsydadg87357qnalx9dqz".to_string();
+
+    for name in ["isabel", "isabel2"] {
+        mint_and_set_record(
+            &mut app,
+            name,
+            signer_bech32_address.clone(),
+            &signer1(),
+            registrar.clone(),
+            name_nft_contract.clone(),
+            resolver_contract_addr.clone(),
+        );
+    }
+
+    // only the name nft contract can call the hook
+    let err = app
+        .execute_contract(
+            Addr::unchecked(addr1.clone()),
+            resolver_contract_addr.clone(),
+            &ExecuteMsg::NameOwnerChangedHook(NameOwnerChangedHook {
+                name: "isabel".to_string(),
+                old_owner: Some(addr1.clone()),
+                new_owner: addr2.clone(),
+            }),
+            &[],
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<ContractError>().unwrap(),
+        &ContractError::Unauthorized {}
+    );
+
+    // register resolver as a hook of the name nft contract
+    for msg in [
+        ICNSNameExecuteMsg::SetTransferrable {
+            transferrable: true,
+        },
+        ICNSNameExecuteMsg::AddHook {
+            hook_address: resolver_contract_addr.to_string(),
+        },
+    ] {
+        app.execute_contract(
+            Addr::unchecked(admin1.clone()),
+            name_nft_contract.clone(),
+            &NameExecuteMsg::Extension { msg },
+            &[],
+        )
+        .unwrap();
+    }
+
+    app.execute_contract(
+        Addr::unchecked(addr1),
+        name_nft_contract,
+        &NameExecuteMsg::TransferNft {
+            recipient: addr2,
+            token_id: "isabel".to_string(),
+        },
+        &[],
+    )
+    .unwrap();
+
+    // records of the previous owner are cleared on transfer
+    assert_eq!(
+        addresses(&app, "isabel".to_string(), resolver_contract_addr.clone()).unwrap(),
+        vec![]
+    );
+    assert_eq!(
+        primary_name(
+            &app,
+            signer_bech32_address.clone(),
+            resolver_contract_addr.clone()
+        )
+        .unwrap(),
+        "isabel2"
+    );
+    assert_eq!(
+        addresses(&app, "isabel2".to_string(), resolver_contract_addr).unwrap(),
+        vec![Bech32Address {
+            bech32_prefix: "cosmos".to_string(),
+            address: signer_bech32_address,
+        }]
+    );
+}
@@ -68,9 +68,8 @@ echo
 read -r -d '' MSG <<- EOF || true
 {
     "name_nft_addr": "$NAME_NFT_CONTRACT_ADDR",
-    "verifier_pubkeys": $(param "verifier_pubkeys"),
-    "verification_threshold": $(param "verification_threshold"),
-    "fee": $(param "fee")
+    "identity_providers": $(param "identity_providers"),
+    "fees": $(param "fees")
 }
 EOF
 echo "$MSG" | jq
@@ -95,4 +94,23 @@ read -r -d '' MSG <<- EOF || true
 EOF
 echo "$MSG" | jq
 
+beaker wasm execute icns-name-nft --signer-account "$SIGNER" --raw "$MSG" 1> /dev/null
+
+
+echo ">>> Register resolver as name-nft hook ..."
+echo
+
+read -r -d '' MSG <<- EOF || true
+{
+    "extension": {
+        "msg": {
+            "add_hook": {
+                "hook_address": "$RESOLVER_CONTRACT_ADDR"
+            }
+        }
+    }
+}
+EOF
+echo "$MSG" | jq
+
 beaker wasm execute icns-name-nft --signer-account "$SIGNER" --raw "$MSG" 1> /dev/null
\ No newline at end of file
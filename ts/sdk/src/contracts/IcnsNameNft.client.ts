@@ -6,7 +6,7 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { Coin, StdFee } from "@cosmjs/amino";
-import { InstantiateMsg, ExecuteMsg, Binary, Expiration, Timestamp, Uint64, ICNSNameExecuteMsg, MintMsgForMetadata, Metadata, QueryMsg, MigrateMsg, AdminResponse, AllNftInfoResponseForMetadata, OwnerOfResponse, Approval, NftInfoResponseForMetadata, OperatorsResponse, TokensResponse, ApprovalResponse, ApprovalsResponse, ContractInfoResponse, IsAdminResponse, MinterResponse, NumTokensResponse, TransferrableResponse } from "./IcnsNameNft.types";
+import { InstantiateMsg, ExecuteMsg, Binary, Expiration, Timestamp, Uint64, ICNSNameExecuteMsg, NameCharset, MintMsgForMetadata, Metadata, IdentityRef, Profile, NamePolicy, QueryMsg, MigrateMsg, AdminResponse, AllNftInfoResponseForMetadata, OwnerOfResponse, Approval, NftInfoResponseForMetadata, OperatorsResponse, TokensResponse, ApprovalResponse, ApprovalsResponse, ContractInfoResponse, GuardiansResponse, HooksResponse, IsAdminResponse, IsGuardianResponse, MinterResponse, NamePolicyResponse, NormalizedNameResponse, NumTokensResponse, PausedResponse, TransferrableResponse, NameViolation, ValidateNameResponse } from "./IcnsNameNft.types";
 export interface IcnsNameNftReadOnlyInterface {
   contractAddress: string;
   admin: () => Promise<AdminResponse>;
@@ -16,6 +16,25 @@ export interface IcnsNameNftReadOnlyInterface {
     address: string;
   }) => Promise<IsAdminResponse>;
   transferrable: () => Promise<TransferrableResponse>;
+  guardians: () => Promise<GuardiansResponse>;
+  isGuardian: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<IsGuardianResponse>;
+  paused: () => Promise<PausedResponse>;
+  hooks: () => Promise<HooksResponse>;
+  namePolicy: () => Promise<NamePolicyResponse>;
+  validateName: ({
+    name
+  }: {
+    name: string;
+  }) => Promise<ValidateNameResponse>;
+  normalizedName: ({
+    name
+  }: {
+    name: string;
+  }) => Promise<NormalizedNameResponse>;
   ownerOf: ({
     includeExpired,
     tokenId
@@ -92,6 +111,13 @@ export class IcnsNameNftQueryClient implements IcnsNameNftReadOnlyInterface {
     this.admin = this.admin.bind(this);
     this.isAdmin = this.isAdmin.bind(this);
     this.transferrable = this.transferrable.bind(this);
+    this.guardians = this.guardians.bind(this);
+    this.isGuardian = this.isGuardian.bind(this);
+    this.paused = this.paused.bind(this);
+    this.hooks = this.hooks.bind(this);
+    this.namePolicy = this.namePolicy.bind(this);
+    this.validateName = this.validateName.bind(this);
+    this.normalizedName = this.normalizedName.bind(this);
     this.ownerOf = this.ownerOf.bind(this);
     this.approval = this.approval.bind(this);
     this.approvals = this.approvals.bind(this);
@@ -126,6 +152,59 @@ export class IcnsNameNftQueryClient implements IcnsNameNftReadOnlyInterface {
       transferrable: {}
     });
   };
+  guardians = async (): Promise<GuardiansResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      guardians: {}
+    });
+  };
+  isGuardian = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<IsGuardianResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      is_guardian: {
+        address
+      }
+    });
+  };
+  paused = async (): Promise<PausedResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      paused: {}
+    });
+  };
+  hooks = async (): Promise<HooksResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      hooks: {}
+    });
+  };
+  namePolicy = async (): Promise<NamePolicyResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      name_policy: {}
+    });
+  };
+  validateName = async ({
+    name
+  }: {
+    name: string;
+  }): Promise<ValidateNameResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      validate_name: {
+        name
+      }
+    });
+  };
+  normalizedName = async ({
+    name
+  }: {
+    name: string;
+  }): Promise<NormalizedNameResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      normalized_name: {
+        name
+      }
+    });
+  };
   ownerOf = async ({
     includeExpired,
     tokenId
@@ -76,6 +76,45 @@ export type ICNSNameExecuteMsg = {
   set_minter: {
     minter_address: string;
   };
+} | {
+  set_expiry: {
+    expiry?: Timestamp | null;
+    token_id: string;
+  };
+} | {
+  add_guardian: {
+    guardian_address: string;
+  };
+} | {
+  remove_guardian: {
+    guardian_address: string;
+  };
+} | {
+  set_paused: {
+    paused: boolean;
+  };
+} | {
+  add_hook: {
+    hook_address: string;
+  };
+} | {
+  remove_hook: {
+    hook_address: string;
+  };
+} | {
+  set_name_policy: {
+    policy: NamePolicy;
+  };
+} | {
+  update_metadata: {
+    profile: Profile;
+    token_id: string;
+  };
+};
+export type NameCharset = "any" | "lowercase_alphanumeric" | {
+  custom: {
+    chars: string;
+  };
 };
 export interface MintMsgForMetadata {
   extension: Metadata;
@@ -84,8 +123,29 @@ export interface MintMsgForMetadata {
   token_uri?: string | null;
 }
 export interface Metadata {
+  expiry?: Timestamp | null;
+  identity?: IdentityRef | null;
+  minted_at?: Timestamp | null;
+  original_claimer?: string | null;
+  profile?: Profile;
   referral?: string | null;
 }
+export interface IdentityRef {
+  provider: string;
+  subject_id: string;
+}
+export interface Profile {
+  avatar_uri?: string | null;
+  description?: string | null;
+  display_name?: string | null;
+}
+export interface NamePolicy {
+  allow_leading_hyphen: boolean;
+  allow_trailing_hyphen: boolean;
+  charset: NameCharset;
+  max_length: number;
+  min_length: number;
+}
 export type QueryMsg = {
   admin: {};
 } | {
@@ -94,6 +154,26 @@ export type QueryMsg = {
   };
 } | {
   transferrable: {};
+} | {
+  guardians: {};
+} | {
+  is_guardian: {
+    address: string;
+  };
+} | {
+  paused: {};
+} | {
+  hooks: {};
+} | {
+  name_policy: {};
+} | {
+  validate_name: {
+    name: string;
+  };
+} | {
+  normalized_name: {
+    name: string;
+  };
 } | {
   owner_of: {
     include_expired?: boolean | null;
@@ -180,15 +260,59 @@ export interface ContractInfoResponse {
   name: string;
   symbol: string;
 }
+export interface GuardiansResponse {
+  guardians: string[];
+}
+export interface HooksResponse {
+  hooks: string[];
+}
 export interface IsAdminResponse {
   is_admin: boolean;
 }
+export interface IsGuardianResponse {
+  is_guardian: boolean;
+}
 export interface MinterResponse {
   minter: string;
 }
+export interface NamePolicyResponse {
+  policy: NamePolicy;
+}
+export interface NormalizedNameResponse {
+  confusable_with?: string | null;
+  normalized: string;
+  skeleton: string;
+}
 export interface NumTokensResponse {
   count: number;
 }
+export interface PausedResponse {
+  paused: boolean;
+}
 export interface TransferrableResponse {
   transferrable: boolean;
+}
+export type NameViolation = "contains_dot" | "leading_hyphen" | "trailing_hyphen" | {
+  too_short: {
+    min_length: number;
+  };
+} | {
+  too_long: {
+    max_length: number;
+  };
+} | {
+  invalid_character: {
+    character: string;
+  };
+} | {
+  not_normalized: {
+    normalized: string;
+  };
+} | {
+  confusable: {
+    name: string;
+  };
+};
+export interface ValidateNameResponse {
+  violation?: NameViolation | null;
 }
\ No newline at end of file
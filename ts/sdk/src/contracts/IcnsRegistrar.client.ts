@@ -6,23 +6,144 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { StdFee } from "@cosmjs/amino";
-import { Uint128, Decimal, Binary, InstantiateMsg, Coin, ExecuteMsg, Verification, QueryMsg, MigrateMsg, FeeResponse, NameByTwitterIdResponse, NameNftAddressResponse, ReferralCountResponse, VerificationThresholdResponse, VerifierPubKeysResponse } from "./IcnsRegistrar.types";
+import { Uint128, Decimal, KeyType, Binary, InstantiateMsg, Coin, IdentityProviderInfo, VerifierPubKey, ExecuteMsg, Recipient, Verification, CommitmentPeriod, LengthTierFee, NameFee, ReservedName, AdminClaimEntry, Identity, ClaimRateLimit, BlockWindowLimit, TreasuryRecipient, QueryMsg, MigrateMsg, Timestamp, Uint64, Addr, AuctionResponse, Auction, RevealedBid, BidResponse, SealedBid, ClaimQuotaResponse, ClaimRateLimitResponse, CommitmentResponse, CommitmentPeriodResponse, ExpiryResponse, FeeResponse, FeeDistributionsResponse, DenomFeeDistribution, IdentityByNameResponse, IdentityProvidersResponse, IsReservedResponse, NameByIdentityResponse, NameByTwitterIdResponse, NameNftAddressResponse, PausedResponse, PriceResponse, ReferralCountResponse, ReferralLeaderboardResponse, ReferralCount, ReferralRewardShareResponse, ReferralRewardsResponse, ReferredNamesResponse, RegistrationPeriodResponse, ReservedNamesResponse, ClaimCheck, SimulateClaimResponse, ClaimCheckFailure, TreasuryResponse, TwitterIdByNameResponse, VerificationThresholdResponse, VerifierPubKeysResponse } from "./IcnsRegistrar.types";
 export interface IcnsRegistrarReadOnlyInterface {
   contractAddress: string;
-  verifierPubKeys: () => Promise<VerifierPubKeysResponse>;
-  verificationThreshold: () => Promise<VerificationThresholdResponse>;
+  identityProviders: () => Promise<IdentityProvidersResponse>;
+  verifierPubKeys: ({
+    provider
+  }: {
+    provider: string;
+  }) => Promise<VerifierPubKeysResponse>;
+  verificationThreshold: ({
+    provider
+  }: {
+    provider: string;
+  }) => Promise<VerificationThresholdResponse>;
   nameNftAddress: () => Promise<NameNftAddressResponse>;
   referralCount: ({
     name
   }: {
     name: string;
   }) => Promise<ReferralCountResponse>;
+  referralLeaderboard: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ReferralLeaderboardResponse>;
+  referredNames: ({
+    limit,
+    referrer,
+    startAfter
+  }: {
+    limit?: number;
+    referrer: string;
+    startAfter?: string;
+  }) => Promise<ReferredNamesResponse>;
+  referralRewardShare: () => Promise<ReferralRewardShareResponse>;
+  referralRewards: ({
+    name
+  }: {
+    name: string;
+  }) => Promise<ReferralRewardsResponse>;
+  commitmentPeriod: () => Promise<CommitmentPeriodResponse>;
+  commitment: ({
+    commitment,
+    committer
+  }: {
+    commitment: Binary;
+    committer: string;
+  }) => Promise<CommitmentResponse>;
+  isReserved: ({
+    name
+  }: {
+    name: string;
+  }) => Promise<IsReservedResponse>;
+  reservedNames: ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }) => Promise<ReservedNamesResponse>;
+  auction: ({
+    name
+  }: {
+    name: string;
+  }) => Promise<AuctionResponse>;
+  bid: ({
+    bidder,
+    name
+  }: {
+    bidder: string;
+    name: string;
+  }) => Promise<BidResponse>;
+  simulateClaim: ({
+    claimer,
+    funds,
+    name,
+    owner,
+    referral,
+    secret,
+    verifications,
+    verifyingMsg
+  }: {
+    claimer: string;
+    funds: Coin[];
+    name: string;
+    owner?: string;
+    referral?: string;
+    secret?: Binary;
+    verifications: Verification[];
+    verifyingMsg: string;
+  }) => Promise<SimulateClaimResponse>;
   fee: () => Promise<FeeResponse>;
+  paused: () => Promise<PausedResponse>;
+  claimRateLimit: () => Promise<ClaimRateLimitResponse>;
+  claimQuota: ({
+    address
+  }: {
+    address: string;
+  }) => Promise<ClaimQuotaResponse>;
+  treasury: () => Promise<TreasuryResponse>;
+  feeDistributions: () => Promise<FeeDistributionsResponse>;
+  price: ({
+    name
+  }: {
+    name: string;
+  }) => Promise<PriceResponse>;
+  nameByIdentity: ({
+    id,
+    provider
+  }: {
+    id: string;
+    provider: string;
+  }) => Promise<NameByIdentityResponse>;
   nameByTwitterId: ({
     twitterId
   }: {
     twitterId: string;
   }) => Promise<NameByTwitterIdResponse>;
+  identityByName: ({
+    name,
+    provider
+  }: {
+    name: string;
+    provider: string;
+  }) => Promise<IdentityByNameResponse>;
+  twitterIdByName: ({
+    name
+  }: {
+    name: string;
+  }) => Promise<TwitterIdByNameResponse>;
+  expiry: ({
+    name
+  }: {
+    name: string;
+  }) => Promise<ExpiryResponse>;
+  registrationPeriod: () => Promise<RegistrationPeriodResponse>;
 }
 export class IcnsRegistrarQueryClient implements IcnsRegistrarReadOnlyInterface {
   client: CosmWasmClient;
@@ -31,22 +152,62 @@ export class IcnsRegistrarQueryClient implements IcnsRegistrarReadOnlyInterface
   constructor(client: CosmWasmClient, contractAddress: string) {
     this.client = client;
     this.contractAddress = contractAddress;
+    this.identityProviders = this.identityProviders.bind(this);
     this.verifierPubKeys = this.verifierPubKeys.bind(this);
     this.verificationThreshold = this.verificationThreshold.bind(this);
     this.nameNftAddress = this.nameNftAddress.bind(this);
     this.referralCount = this.referralCount.bind(this);
+    this.referralLeaderboard = this.referralLeaderboard.bind(this);
+    this.referredNames = this.referredNames.bind(this);
+    this.referralRewardShare = this.referralRewardShare.bind(this);
+    this.referralRewards = this.referralRewards.bind(this);
+    this.commitmentPeriod = this.commitmentPeriod.bind(this);
+    this.commitment = this.commitment.bind(this);
+    this.isReserved = this.isReserved.bind(this);
+    this.reservedNames = this.reservedNames.bind(this);
+    this.auction = this.auction.bind(this);
+    this.bid = this.bid.bind(this);
+    this.simulateClaim = this.simulateClaim.bind(this);
     this.fee = this.fee.bind(this);
+    this.paused = this.paused.bind(this);
+    this.claimRateLimit = this.claimRateLimit.bind(this);
+    this.claimQuota = this.claimQuota.bind(this);
+    this.treasury = this.treasury.bind(this);
+    this.feeDistributions = this.feeDistributions.bind(this);
+    this.price = this.price.bind(this);
+    this.nameByIdentity = this.nameByIdentity.bind(this);
     this.nameByTwitterId = this.nameByTwitterId.bind(this);
+    this.identityByName = this.identityByName.bind(this);
+    this.twitterIdByName = this.twitterIdByName.bind(this);
+    this.expiry = this.expiry.bind(this);
+    this.registrationPeriod = this.registrationPeriod.bind(this);
   }
 
-  verifierPubKeys = async (): Promise<VerifierPubKeysResponse> => {
+  identityProviders = async (): Promise<IdentityProvidersResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      identity_providers: {}
+    });
+  };
+  verifierPubKeys = async ({
+    provider
+  }: {
+    provider: string;
+  }): Promise<VerifierPubKeysResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      verifier_pub_keys: {}
+      verifier_pub_keys: {
+        provider
+      }
     });
   };
-  verificationThreshold = async (): Promise<VerificationThresholdResponse> => {
+  verificationThreshold = async ({
+    provider
+  }: {
+    provider: string;
+  }): Promise<VerificationThresholdResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
-      verification_threshold: {}
+      verification_threshold: {
+        provider
+      }
     });
   };
   nameNftAddress = async (): Promise<NameNftAddressResponse> => {
@@ -65,11 +226,215 @@ export class IcnsRegistrarQueryClient implements IcnsRegistrarReadOnlyInterface
       }
     });
   };
+  referralLeaderboard = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ReferralLeaderboardResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      referral_leaderboard: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  referredNames = async ({
+    limit,
+    referrer,
+    startAfter
+  }: {
+    limit?: number;
+    referrer: string;
+    startAfter?: string;
+  }): Promise<ReferredNamesResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      referred_names: {
+        limit,
+        referrer,
+        start_after: startAfter
+      }
+    });
+  };
+  referralRewardShare = async (): Promise<ReferralRewardShareResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      referral_reward_share: {}
+    });
+  };
+  referralRewards = async ({
+    name
+  }: {
+    name: string;
+  }): Promise<ReferralRewardsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      referral_rewards: {
+        name
+      }
+    });
+  };
+  commitmentPeriod = async (): Promise<CommitmentPeriodResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      commitment_period: {}
+    });
+  };
+  commitment = async ({
+    commitment,
+    committer
+  }: {
+    commitment: Binary;
+    committer: string;
+  }): Promise<CommitmentResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      commitment: {
+        commitment,
+        committer
+      }
+    });
+  };
+  isReserved = async ({
+    name
+  }: {
+    name: string;
+  }): Promise<IsReservedResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      is_reserved: {
+        name
+      }
+    });
+  };
+  reservedNames = async ({
+    limit,
+    startAfter
+  }: {
+    limit?: number;
+    startAfter?: string;
+  }): Promise<ReservedNamesResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      reserved_names: {
+        limit,
+        start_after: startAfter
+      }
+    });
+  };
+  auction = async ({
+    name
+  }: {
+    name: string;
+  }): Promise<AuctionResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      auction: {
+        name
+      }
+    });
+  };
+  bid = async ({
+    bidder,
+    name
+  }: {
+    bidder: string;
+    name: string;
+  }): Promise<BidResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      bid: {
+        bidder,
+        name
+      }
+    });
+  };
+  simulateClaim = async ({
+    claimer,
+    funds,
+    name,
+    owner,
+    referral,
+    secret,
+    verifications,
+    verifyingMsg
+  }: {
+    claimer: string;
+    funds: Coin[];
+    name: string;
+    owner?: string;
+    referral?: string;
+    secret?: Binary;
+    verifications: Verification[];
+    verifyingMsg: string;
+  }): Promise<SimulateClaimResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      simulate_claim: {
+        claimer,
+        funds,
+        name,
+        owner,
+        referral,
+        secret,
+        verifications,
+        verifying_msg: verifyingMsg
+      }
+    });
+  };
   fee = async (): Promise<FeeResponse> => {
     return this.client.queryContractSmart(this.contractAddress, {
       fee: {}
     });
   };
+  paused = async (): Promise<PausedResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      paused: {}
+    });
+  };
+  claimRateLimit = async (): Promise<ClaimRateLimitResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      claim_rate_limit: {}
+    });
+  };
+  claimQuota = async ({
+    address
+  }: {
+    address: string;
+  }): Promise<ClaimQuotaResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      claim_quota: {
+        address
+      }
+    });
+  };
+  treasury = async (): Promise<TreasuryResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      treasury: {}
+    });
+  };
+  feeDistributions = async (): Promise<FeeDistributionsResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      fee_distributions: {}
+    });
+  };
+  price = async ({
+    name
+  }: {
+    name: string;
+  }): Promise<PriceResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      price: {
+        name
+      }
+    });
+  };
+  nameByIdentity = async ({
+    id,
+    provider
+  }: {
+    id: string;
+    provider: string;
+  }): Promise<NameByIdentityResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      name_by_identity: {
+        id,
+        provider
+      }
+    });
+  };
   nameByTwitterId = async ({
     twitterId
   }: {
@@ -81,42 +446,245 @@ export class IcnsRegistrarQueryClient implements IcnsRegistrarReadOnlyInterface
       }
     });
   };
+  identityByName = async ({
+    name,
+    provider
+  }: {
+    name: string;
+    provider: string;
+  }): Promise<IdentityByNameResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      identity_by_name: {
+        name,
+        provider
+      }
+    });
+  };
+  twitterIdByName = async ({
+    name
+  }: {
+    name: string;
+  }): Promise<TwitterIdByNameResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      twitter_id_by_name: {
+        name
+      }
+    });
+  };
+  expiry = async ({
+    name
+  }: {
+    name: string;
+  }): Promise<ExpiryResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      expiry: {
+        name
+      }
+    });
+  };
+  registrationPeriod = async (): Promise<RegistrationPeriodResponse> => {
+    return this.client.queryContractSmart(this.contractAddress, {
+      registration_period: {}
+    });
+  };
 }
 export interface IcnsRegistrarInterface extends IcnsRegistrarReadOnlyInterface {
   contractAddress: string;
   sender: string;
   claim: ({
     name,
+    owner,
     referral,
+    secret,
     verifications,
     verifyingMsg
   }: {
     name: string;
+    owner?: string;
     referral?: string;
+    secret?: Binary;
     verifications: Verification[];
     verifyingMsg: string;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  commitClaim: ({
+    commitment
+  }: {
+    commitment: Binary;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setCommitmentPeriod: ({
+    period
+  }: {
+    period?: CommitmentPeriod;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  addIdentityProvider: ({
+    identityProvider
+  }: {
+    identityProvider: IdentityProviderInfo;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  removeIdentityProvider: ({
+    provider
+  }: {
+    provider: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   updateVerifierPubkeys: ({
     add,
+    provider,
     remove
   }: {
-    add: Binary[];
+    add: VerifierPubKey[];
+    provider: string;
     remove: Binary[];
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   setVerificationThreshold: ({
+    provider,
     threshold
   }: {
+    provider: string;
     threshold: Decimal;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setAbsoluteVerificationThreshold: ({
+    provider,
+    threshold
+  }: {
+    provider: string;
+    threshold?: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   setNameNftAddress: ({
     nameNftAddress
   }: {
     nameNftAddress: string;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
-  setMintingFee: ({
-    mintingFee
+  updateAcceptedFees: ({
+    add,
+    remove
+  }: {
+    add: Coin[];
+    remove: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setLengthTierFees: ({
+    tiers
+  }: {
+    tiers: LengthTierFee[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setNameFees: ({
+    fees
+  }: {
+    fees: NameFee[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  renew: ({
+    name,
+    years
   }: {
-    mintingFee?: Coin;
+    name: string;
+    years: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setRegistrationPeriod: ({
+    years
+  }: {
+    years?: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setReferralRewardShare: ({
+    share
+  }: {
+    share: Decimal;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  claimReferralRewards: ({
+    name
+  }: {
+    name: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  reserveNames: ({
+    names
+  }: {
+    names: ReservedName[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  unreserveNames: ({
+    names
+  }: {
+    names: string[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  adminBatchClaim: ({
+    entries
+  }: {
+    entries: AdminClaimEntry[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  startAuction: ({
+    bidDuration,
+    denom,
+    minBid,
+    name,
+    revealDuration
+  }: {
+    bidDuration: number;
+    denom: string;
+    minBid: Uint128;
+    name: string;
+    revealDuration: number;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  placeBid: ({
+    name,
+    sealedBid
+  }: {
+    name: string;
+    sealedBid: Binary;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  revealBid: ({
+    amount,
+    name,
+    salt
+  }: {
+    amount: Uint128;
+    name: string;
+    salt: Binary;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  settleAuction: ({
+    name
+  }: {
+    name: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  withdrawBid: ({
+    name
+  }: {
+    name: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  cancelAuction: ({
+    name
+  }: {
+    name: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setPaused: ({
+    paused
+  }: {
+    paused: boolean;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setClaimRateLimit: ({
+    rateLimit
+  }: {
+    rateLimit: ClaimRateLimit;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setTreasury: ({
+    autoDistribute,
+    recipients
+  }: {
+    autoDistribute: boolean;
+    recipients: TreasuryRecipient[];
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  distributeFees: (fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  releaseIdentity: ({
+    name,
+    provider
+  }: {
+    name: string;
+    provider: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  rebindIdentity: ({
+    name,
+    verifications,
+    verifyingMsg
+  }: {
+    name: string;
+    verifications: Verification[];
+    verifyingMsg: string;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
   withdrawFunds: ({
     amount,
@@ -137,54 +705,150 @@ export class IcnsRegistrarClient extends IcnsRegistrarQueryClient implements Icn
     this.sender = sender;
     this.contractAddress = contractAddress;
     this.claim = this.claim.bind(this);
+    this.commitClaim = this.commitClaim.bind(this);
+    this.setCommitmentPeriod = this.setCommitmentPeriod.bind(this);
+    this.addIdentityProvider = this.addIdentityProvider.bind(this);
+    this.removeIdentityProvider = this.removeIdentityProvider.bind(this);
     this.updateVerifierPubkeys = this.updateVerifierPubkeys.bind(this);
     this.setVerificationThreshold = this.setVerificationThreshold.bind(this);
+    this.setAbsoluteVerificationThreshold = this.setAbsoluteVerificationThreshold.bind(this);
     this.setNameNftAddress = this.setNameNftAddress.bind(this);
-    this.setMintingFee = this.setMintingFee.bind(this);
+    this.updateAcceptedFees = this.updateAcceptedFees.bind(this);
+    this.setLengthTierFees = this.setLengthTierFees.bind(this);
+    this.setNameFees = this.setNameFees.bind(this);
+    this.renew = this.renew.bind(this);
+    this.setRegistrationPeriod = this.setRegistrationPeriod.bind(this);
+    this.setReferralRewardShare = this.setReferralRewardShare.bind(this);
+    this.claimReferralRewards = this.claimReferralRewards.bind(this);
+    this.reserveNames = this.reserveNames.bind(this);
+    this.unreserveNames = this.unreserveNames.bind(this);
+    this.adminBatchClaim = this.adminBatchClaim.bind(this);
+    this.startAuction = this.startAuction.bind(this);
+    this.placeBid = this.placeBid.bind(this);
+    this.revealBid = this.revealBid.bind(this);
+    this.settleAuction = this.settleAuction.bind(this);
+    this.withdrawBid = this.withdrawBid.bind(this);
+    this.cancelAuction = this.cancelAuction.bind(this);
+    this.setPaused = this.setPaused.bind(this);
+    this.setClaimRateLimit = this.setClaimRateLimit.bind(this);
+    this.setTreasury = this.setTreasury.bind(this);
+    this.distributeFees = this.distributeFees.bind(this);
+    this.releaseIdentity = this.releaseIdentity.bind(this);
+    this.rebindIdentity = this.rebindIdentity.bind(this);
     this.withdrawFunds = this.withdrawFunds.bind(this);
   }
 
   claim = async ({
     name,
+    owner,
     referral,
+    secret,
     verifications,
     verifyingMsg
   }: {
     name: string;
+    owner?: string;
     referral?: string;
+    secret?: Binary;
     verifications: Verification[];
     verifyingMsg: string;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       claim: {
         name,
+        owner,
         referral,
+        secret,
         verifications,
         verifying_msg: verifyingMsg
       }
     }, fee, memo, funds);
   };
+  commitClaim = async ({
+    commitment
+  }: {
+    commitment: Binary;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      commit_claim: {
+        commitment
+      }
+    }, fee, memo, funds);
+  };
+  setCommitmentPeriod = async ({
+    period
+  }: {
+    period?: CommitmentPeriod;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_commitment_period: {
+        period
+      }
+    }, fee, memo, funds);
+  };
+  addIdentityProvider = async ({
+    identityProvider
+  }: {
+    identityProvider: IdentityProviderInfo;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      add_identity_provider: {
+        identity_provider: identityProvider
+      }
+    }, fee, memo, funds);
+  };
+  removeIdentityProvider = async ({
+    provider
+  }: {
+    provider: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      remove_identity_provider: {
+        provider
+      }
+    }, fee, memo, funds);
+  };
   updateVerifierPubkeys = async ({
     add,
+    provider,
     remove
   }: {
-    add: Binary[];
+    add: VerifierPubKey[];
+    provider: string;
     remove: Binary[];
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       update_verifier_pubkeys: {
         add,
+        provider,
         remove
       }
     }, fee, memo, funds);
   };
   setVerificationThreshold = async ({
+    provider,
     threshold
   }: {
+    provider: string;
     threshold: Decimal;
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
       set_verification_threshold: {
+        provider,
+        threshold
+      }
+    }, fee, memo, funds);
+  };
+  setAbsoluteVerificationThreshold = async ({
+    provider,
+    threshold
+  }: {
+    provider: string;
+    threshold?: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_absolute_verification_threshold: {
+        provider,
         threshold
       }
     }, fee, memo, funds);
@@ -200,14 +864,278 @@ export class IcnsRegistrarClient extends IcnsRegistrarQueryClient implements Icn
       }
     }, fee, memo, funds);
   };
-  setMintingFee = async ({
-    mintingFee
+  updateAcceptedFees = async ({
+    add,
+    remove
+  }: {
+    add: Coin[];
+    remove: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      update_accepted_fees: {
+        add,
+        remove
+      }
+    }, fee, memo, funds);
+  };
+  setLengthTierFees = async ({
+    tiers
+  }: {
+    tiers: LengthTierFee[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_length_tier_fees: {
+        tiers
+      }
+    }, fee, memo, funds);
+  };
+  setNameFees = async ({
+    fees
+  }: {
+    fees: NameFee[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_name_fees: {
+        fees
+      }
+    }, fee, memo, funds);
+  };
+  renew = async ({
+    name,
+    years
+  }: {
+    name: string;
+    years: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      renew: {
+        name,
+        years
+      }
+    }, fee, memo, funds);
+  };
+  setRegistrationPeriod = async ({
+    years
+  }: {
+    years?: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_registration_period: {
+        years
+      }
+    }, fee, memo, funds);
+  };
+  setReferralRewardShare = async ({
+    share
+  }: {
+    share: Decimal;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_referral_reward_share: {
+        share
+      }
+    }, fee, memo, funds);
+  };
+  claimReferralRewards = async ({
+    name
+  }: {
+    name: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      claim_referral_rewards: {
+        name
+      }
+    }, fee, memo, funds);
+  };
+  reserveNames = async ({
+    names
   }: {
-    mintingFee?: Coin;
+    names: ReservedName[];
   }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
     return await this.client.execute(this.sender, this.contractAddress, {
-      set_minting_fee: {
-        minting_fee: mintingFee
+      reserve_names: {
+        names
+      }
+    }, fee, memo, funds);
+  };
+  unreserveNames = async ({
+    names
+  }: {
+    names: string[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      unreserve_names: {
+        names
+      }
+    }, fee, memo, funds);
+  };
+  adminBatchClaim = async ({
+    entries
+  }: {
+    entries: AdminClaimEntry[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      admin_batch_claim: {
+        entries
+      }
+    }, fee, memo, funds);
+  };
+  startAuction = async ({
+    bidDuration,
+    denom,
+    minBid,
+    name,
+    revealDuration
+  }: {
+    bidDuration: number;
+    denom: string;
+    minBid: Uint128;
+    name: string;
+    revealDuration: number;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      start_auction: {
+        bid_duration: bidDuration,
+        denom,
+        min_bid: minBid,
+        name,
+        reveal_duration: revealDuration
+      }
+    }, fee, memo, funds);
+  };
+  placeBid = async ({
+    name,
+    sealedBid
+  }: {
+    name: string;
+    sealedBid: Binary;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      place_bid: {
+        name,
+        sealed_bid: sealedBid
+      }
+    }, fee, memo, funds);
+  };
+  revealBid = async ({
+    amount,
+    name,
+    salt
+  }: {
+    amount: Uint128;
+    name: string;
+    salt: Binary;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      reveal_bid: {
+        amount,
+        name,
+        salt
+      }
+    }, fee, memo, funds);
+  };
+  settleAuction = async ({
+    name
+  }: {
+    name: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      settle_auction: {
+        name
+      }
+    }, fee, memo, funds);
+  };
+  withdrawBid = async ({
+    name
+  }: {
+    name: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      withdraw_bid: {
+        name
+      }
+    }, fee, memo, funds);
+  };
+  cancelAuction = async ({
+    name
+  }: {
+    name: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      cancel_auction: {
+        name
+      }
+    }, fee, memo, funds);
+  };
+  setPaused = async ({
+    paused
+  }: {
+    paused: boolean;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_paused: {
+        paused
+      }
+    }, fee, memo, funds);
+  };
+  setClaimRateLimit = async ({
+    rateLimit
+  }: {
+    rateLimit: ClaimRateLimit;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_claim_rate_limit: {
+        rate_limit: rateLimit
+      }
+    }, fee, memo, funds);
+  };
+  setTreasury = async ({
+    autoDistribute,
+    recipients
+  }: {
+    autoDistribute: boolean;
+    recipients: TreasuryRecipient[];
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_treasury: {
+        auto_distribute: autoDistribute,
+        recipients
+      }
+    }, fee, memo, funds);
+  };
+  distributeFees = async (fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      distribute_fees: {}
+    }, fee, memo, funds);
+  };
+  releaseIdentity = async ({
+    name,
+    provider
+  }: {
+    name: string;
+    provider: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      release_identity: {
+        name,
+        provider
+      }
+    }, fee, memo, funds);
+  };
+  rebindIdentity = async ({
+    name,
+    verifications,
+    verifyingMsg
+  }: {
+    name: string;
+    verifications: Verification[];
+    verifyingMsg: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      rebind_identity: {
+        name,
+        verifications,
+        verifying_msg: verifyingMsg
       }
     }, fee, memo, funds);
   };
@@ -6,41 +6,171 @@
 
 export type Uint128 = string;
 export type Decimal = string;
+export type KeyType = "secp256k1" | "ed25519";
 export type Binary = string;
 export interface InstantiateMsg {
-  fee?: Coin | null;
+  fees: Coin[];
+  identity_providers: IdentityProviderInfo[];
   name_nft_addr: string;
-  verification_threshold: Decimal;
-  verifier_pubkeys: Binary[];
 }
 export interface Coin {
   amount: Uint128;
   denom: string;
   [k: string]: unknown;
 }
+export interface IdentityProviderInfo {
+  provider: string;
+  verification_threshold: Decimal;
+  verifier_pubkeys: VerifierPubKey[];
+}
+export interface VerifierPubKey {
+  key_type?: KeyType;
+  pubkey: Binary;
+  weight: number;
+}
 export type ExecuteMsg = {
   claim: {
     name: string;
+    owner?: string | null;
     referral?: string | null;
+    secret?: Binary | null;
     verifications: Verification[];
     verifying_msg: string;
   };
+} | {
+  commit_claim: {
+    commitment: Binary;
+  };
+} | {
+  set_commitment_period: {
+    period?: CommitmentPeriod | null;
+  };
+} | {
+  add_identity_provider: {
+    identity_provider: IdentityProviderInfo;
+  };
+} | {
+  remove_identity_provider: {
+    provider: string;
+  };
 } | {
   update_verifier_pubkeys: {
-    add: Binary[];
+    add: VerifierPubKey[];
+    provider: string;
     remove: Binary[];
   };
 } | {
   set_verification_threshold: {
+    provider: string;
     threshold: Decimal;
   };
+} | {
+  set_absolute_verification_threshold: {
+    provider: string;
+    threshold?: number | null;
+  };
 } | {
   set_name_nft_address: {
     name_nft_address: string;
   };
 } | {
-  set_minting_fee: {
-    minting_fee?: Coin | null;
+  update_accepted_fees: {
+    add: Coin[];
+    remove: string[];
+  };
+} | {
+  set_length_tier_fees: {
+    tiers: LengthTierFee[];
+  };
+} | {
+  set_name_fees: {
+    fees: NameFee[];
+  };
+} | {
+  renew: {
+    name: string;
+    years: number;
+  };
+} | {
+  set_registration_period: {
+    years?: number | null;
+  };
+} | {
+  set_referral_reward_share: {
+    share: Decimal;
+  };
+} | {
+  claim_referral_rewards: {
+    name: string;
+  };
+} | {
+  reserve_names: {
+    names: ReservedName[];
+  };
+} | {
+  unreserve_names: {
+    names: string[];
+  };
+} | {
+  admin_batch_claim: {
+    entries: AdminClaimEntry[];
+  };
+} | {
+  start_auction: {
+    bid_duration: number;
+    denom: string;
+    min_bid: Uint128;
+    name: string;
+    reveal_duration: number;
+  };
+} | {
+  place_bid: {
+    name: string;
+    sealed_bid: Binary;
+  };
+} | {
+  reveal_bid: {
+    amount: Uint128;
+    name: string;
+    salt: Binary;
+  };
+} | {
+  settle_auction: {
+    name: string;
+  };
+} | {
+  withdraw_bid: {
+    name: string;
+  };
+} | {
+  cancel_auction: {
+    name: string;
+  };
+} | {
+  set_paused: {
+    paused: boolean;
+  };
+} | {
+  set_claim_rate_limit: {
+    rate_limit: ClaimRateLimit;
+  };
+} | {
+  set_treasury: {
+    auto_distribute: boolean;
+    recipients: TreasuryRecipient[];
+  };
+} | {
+  distribute_fees: {};
+} | {
+  release_identity: {
+    name: string;
+    provider: string;
+  };
+} | {
+  rebind_identity: {
+    name: string;
+    verifications: Verification[];
+    verifying_msg: string;
   };
 } | {
   withdraw_funds: {
@@ -48,30 +178,233 @@ export type ExecuteMsg = {
     to_address: string;
   };
 };
+export type Recipient = {
+  address: {
+    address: string;
+  };
+} | {
+  community_pool: {};
+};
 export interface Verification {
-  public_key: Binary;
+  public_key?: Binary | null;
   signature: Binary;
 }
+export interface CommitmentPeriod {
+  max_blocks: number;
+  min_blocks: number;
+}
+export interface LengthTierFee {
+  fees: Coin[];
+  max_length: number;
+}
+export interface NameFee {
+  fees?: Coin[] | null;
+  name: string;
+}
+export interface ReservedName {
+  assignee?: string | null;
+  name: string;
+}
+export interface AdminClaimEntry {
+  identity?: Identity | null;
+  name: string;
+  owner: string;
+}
+export interface Identity {
+  id: string;
+  provider: string;
+}
+export interface ClaimRateLimit {
+  max_claims_per_address?: number | null;
+  max_claims_per_window?: BlockWindowLimit | null;
+}
+export interface BlockWindowLimit {
+  blocks: number;
+  max_claims: number;
+}
+export interface TreasuryRecipient {
+  recipient: Recipient;
+  share: Decimal;
+}
 export type QueryMsg = {
-  verifier_pub_keys: {};
+  identity_providers: {};
 } | {
-  verification_threshold: {};
+  verifier_pub_keys: {
+    provider: string;
+  };
+} | {
+  verification_threshold: {
+    provider: string;
+  };
 } | {
   name_nft_address: {};
 } | {
   referral_count: {
     name: string;
   };
+} | {
+  referral_leaderboard: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  referred_names: {
+    limit?: number | null;
+    referrer: string;
+    start_after?: string | null;
+  };
+} | {
+  referral_reward_share: {};
+} | {
+  referral_rewards: {
+    name: string;
+  };
+} | {
+  commitment_period: {};
+} | {
+  commitment: {
+    commitment: Binary;
+    committer: string;
+  };
+} | {
+  is_reserved: {
+    name: string;
+  };
+} | {
+  reserved_names: {
+    limit?: number | null;
+    start_after?: string | null;
+  };
+} | {
+  auction: {
+    name: string;
+  };
+} | {
+  bid: {
+    bidder: string;
+    name: string;
+  };
+} | {
+  simulate_claim: {
+    claimer: string;
+    funds: Coin[];
+    name: string;
+    owner?: string | null;
+    referral?: string | null;
+    secret?: Binary | null;
+    verifications: Verification[];
+    verifying_msg: string;
+  };
 } | {
   fee: {};
+} | {
+  paused: {};
+} | {
+  claim_rate_limit: {};
+} | {
+  claim_quota: {
+    address: string;
+  };
+} | {
+  treasury: {};
+} | {
+  fee_distributions: {};
+} | {
+  price: {
+    name: string;
+  };
+} | {
+  name_by_identity: {
+    id: string;
+    provider: string;
+  };
 } | {
   name_by_twitter_id: {
     twitter_id: string;
   };
+} | {
+  identity_by_name: {
+    name: string;
+    provider: string;
+  };
+} | {
+  twitter_id_by_name: {
+    name: string;
+  };
+} | {
+  expiry: {
+    name: string;
+  };
+} | {
+  registration_period: {};
 };
 export interface MigrateMsg {}
+export type Timestamp = Uint64;
+export type Uint64 = string;
+export type Addr = string;
+export interface AuctionResponse {
+  auction?: Auction | null;
+}
+export interface Auction {
+  bid_end: Timestamp;
+  denom: string;
+  highest_bid?: RevealedBid | null;
+  min_bid: Uint128;
+  reveal_end: Timestamp;
+}
+export interface RevealedBid {
+  amount: Uint128;
+  bidder: Addr;
+}
+export interface BidResponse {
+  bid?: SealedBid | null;
+}
+export interface SealedBid {
+  deposit: Uint128;
+  sealed_bid: Binary;
+}
+export interface ClaimQuotaResponse {
+  claimed: number;
+  next_window_height?: number | null;
+  remaining_for_address?: number | null;
+  remaining_in_window?: number | null;
+}
+export interface ClaimRateLimitResponse {
+  rate_limit: ClaimRateLimit;
+}
+export interface CommitmentResponse {
+  height?: number | null;
+}
+export interface CommitmentPeriodResponse {
+  period?: CommitmentPeriod | null;
+}
+export interface ExpiryResponse {
+  expired: boolean;
+  expiry?: Timestamp | null;
+}
 export interface FeeResponse {
-  fee?: Coin | null;
+  fees: Coin[];
+}
+export interface FeeDistributionsResponse {
+  distributions: DenomFeeDistribution[];
+}
+export interface DenomFeeDistribution {
+  collected: Uint128;
+  denom: string;
+  distributed: Uint128;
+}
+export interface IdentityByNameResponse {
+  id: string;
+}
+export interface IdentityProvidersResponse {
+  providers: string[];
+}
+export interface IsReservedResponse {
+  assignee?: string | null;
+  reserved: boolean;
+}
+export interface NameByIdentityResponse {
+  name: string;
 }
 export interface NameByTwitterIdResponse {
   name: string;
@@ -79,12 +412,57 @@ export interface NameByTwitterIdResponse {
 export interface NameNftAddressResponse {
   name_nft_address: string;
 }
+export interface PausedResponse {
+  paused: boolean;
+}
+export interface PriceResponse {
+  price: Coin[];
+}
 export interface ReferralCountResponse {
   count: number;
 }
+export interface ReferralLeaderboardResponse {
+  referrals: ReferralCount[];
+}
+export interface ReferralCount {
+  count: number;
+  name: string;
+}
+export interface ReferralRewardShareResponse {
+  share: Decimal;
+}
+export interface ReferralRewardsResponse {
+  paid: Coin[];
+  pending: Coin[];
+}
+export interface ReferredNamesResponse {
+  names: string[];
+}
+export interface RegistrationPeriodResponse {
+  years?: number | null;
+}
+export interface ReservedNamesResponse {
+  names: ReservedName[];
+}
+export type ClaimCheck = "paused" | "name" | "availability" | "commitment" | "auction" | "reservation" | "verifying_msg" | "verifications" | "referral" | "fee" | "rate_limit";
+export interface SimulateClaimResponse {
+  failures: ClaimCheckFailure[];
+}
+export interface ClaimCheckFailure {
+  check: ClaimCheck;
+  error: string;
+}
+export interface TreasuryResponse {
+  auto_distribute: boolean;
+  recipients: TreasuryRecipient[];
+}
+export interface TwitterIdByNameResponse {
+  twitter_id: string;
+}
 export interface VerificationThresholdResponse {
+  absolute_verification_threshold?: number | null;
   verification_threshold_percentage: Decimal;
 }
 export interface VerifierPubKeysResponse {
-  verifier_pubkeys: Binary[];
+  verifier_pubkeys: VerifierPubKey[];
 }
\ No newline at end of file
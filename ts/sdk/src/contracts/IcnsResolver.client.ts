@@ -6,7 +6,7 @@
 
 import { CosmWasmClient, SigningCosmWasmClient, ExecuteResult } from "@cosmjs/cosmwasm-stargate";
 import { Coin, StdFee } from "@cosmjs/amino";
-import { InstantiateMsg, ExecuteMsg, AddressHash, Binary, Uint128, Adr36Info, QueryMsg, MigrateMsg, AddressResponse, AddressByIcnsResponse, AddressesResponse, Bech32Address, AdminResponse, Addr, Config, IcnsNamesResponse, NamesResponse, PrimaryNameResponse } from "./IcnsResolver.types";
+import { InstantiateMsg, ExecuteMsg, AddressHash, Binary, Uint128, Adr36Info, NameOwnerChangedHook, QueryMsg, MigrateMsg, AddressResponse, AddressByIcnsResponse, AddressesResponse, Bech32Address, AdminResponse, Addr, Config, IcnsNamesResponse, NamesResponse, PrimaryNameResponse } from "./IcnsResolver.types";
 export interface IcnsResolverReadOnlyInterface {
   contractAddress: string;
   config: () => Promise<Config>;
@@ -167,6 +167,20 @@ export interface IcnsResolverInterface extends IcnsResolverReadOnlyInterface {
     bech32Address: string;
     name: string;
   }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  setPaused: ({
+    paused
+  }: {
+    paused: boolean;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
+  nameOwnerChangedHook: ({
+    name,
+    newOwner,
+    oldOwner
+  }: {
+    name: string;
+    newOwner: string;
+    oldOwner?: string;
+  }, fee?: number | StdFee | "auto", memo?: string, funds?: Coin[]) => Promise<ExecuteResult>;
 }
 export class IcnsResolverClient extends IcnsResolverQueryClient implements IcnsResolverInterface {
   client: SigningCosmWasmClient;
@@ -181,6 +195,8 @@ export class IcnsResolverClient extends IcnsResolverQueryClient implements IcnsR
     this.setRecord = this.setRecord.bind(this);
     this.setPrimary = this.setPrimary.bind(this);
     this.removeRecord = this.removeRecord.bind(this);
+    this.setPaused = this.setPaused.bind(this);
+    this.nameOwnerChangedHook = this.nameOwnerChangedHook.bind(this);
   }
 
   setRecord = async ({
@@ -228,4 +244,32 @@ export class IcnsResolverClient extends IcnsResolverQueryClient implements IcnsR
       }
     }, fee, memo, funds);
   };
+  setPaused = async ({
+    paused
+  }: {
+    paused: boolean;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      set_paused: {
+        paused
+      }
+    }, fee, memo, funds);
+  };
+  nameOwnerChangedHook = async ({
+    name,
+    newOwner,
+    oldOwner
+  }: {
+    name: string;
+    newOwner: string;
+    oldOwner?: string;
+  }, fee: number | StdFee | "auto" = "auto", memo?: string, funds?: Coin[]): Promise<ExecuteResult> => {
+    return await this.client.execute(this.sender, this.contractAddress, {
+      name_owner_changed_hook: {
+        name,
+        new_owner: newOwner,
+        old_owner: oldOwner
+      }
+    }, fee, memo, funds);
+  };
 }
\ No newline at end of file
@@ -23,6 +23,12 @@ export type ExecuteMsg = {
     bech32_address: string;
     name: string;
   };
+} | {
+  set_paused: {
+    paused: boolean;
+  };
+} | {
+  name_owner_changed_hook: NameOwnerChangedHook;
 };
 export type AddressHash = "cosmos" | "ethereum";
 export type Binary = string;
@@ -34,6 +40,11 @@ export interface Adr36Info {
   signature_salt: Uint128;
   signer_bech32_address: string;
 }
+export interface NameOwnerChangedHook {
+  name: string;
+  new_owner: string;
+  old_owner?: string | null;
+}
 export type QueryMsg = {
   config: {};
 } | {
@@ -84,6 +95,7 @@ export interface AdminResponse {
 export type Addr = string;
 export interface Config {
   name_address: Addr;
+  paused?: boolean;
 }
 export interface IcnsNamesResponse {
   names: string[];